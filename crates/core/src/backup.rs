@@ -81,6 +81,45 @@ pub async fn backup_folders(
 	Ok(as_of)
 }
 
+/// Archives a wallet's top level directory into the dest folder, then removes the
+/// original directory. Returns the path of the new archive.
+pub async fn archive_wallet_folder(
+	tld: PathBuf,
+	mut dest: PathBuf,
+	compression: CompressionFormat,
+	zstd_level: i32,
+) -> Result<PathBuf, FilesystemError> {
+	if !tld.is_dir() {
+		return Err(FilesystemError::FileDoesntExist { path: tld });
+	}
+
+	let now = Local::now();
+	let dir_name = tld
+		.file_name()
+		.map(|n| n.to_string_lossy().into_owned())
+		.unwrap_or_default();
+
+	dest.push(format!(
+		"grin_gui_wallet_{}_{}.{}",
+		dir_name,
+		now.format("%Y-%m-%d_%H-%M-%S"),
+		compression.file_ext(),
+	));
+
+	// Keep the directory name itself in the archive
+	let prefix = tld.parent().map(|p| p.to_owned()).unwrap_or_default();
+	let src_folders = vec![BackupFolder::new(&tld, prefix)];
+
+	match compression {
+		CompressionFormat::Zip => ZipBackup::new(src_folders, &dest).backup()?,
+		CompressionFormat::Zstd => ZstdBackup::new(src_folders, &dest, zstd_level).backup()?,
+	}
+
+	std::fs::remove_dir_all(&tld)?;
+
+	Ok(dest)
+}
+
 /// Finds the latest archive in the supplied backup folder and returns
 /// the datetime it was saved
 pub async fn latest_backup(backup_dir: PathBuf) -> Option<NaiveDateTime> {
@@ -149,3 +188,32 @@ impl TryFrom<PathBuf> for Archive {
 		Ok(Archive { as_of })
 	}
 }
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn test_archive_wallet_folder() {
+		let root = tempfile::tempdir().unwrap();
+		let tld = root.path().join("my_wallet");
+		std::fs::create_dir_all(tld.join("wallet_data")).unwrap();
+		std::fs::write(tld.join("grin-wallet.toml"), "chain_type = \"Mainnet\"").unwrap();
+
+		let archive = futures::executor::block_on(archive_wallet_folder(
+			tld.clone(),
+			root.path().to_owned(),
+			CompressionFormat::Zip,
+			0,
+		))
+		.unwrap();
+
+		assert!(!tld.exists());
+		assert!(archive.is_file());
+		assert!(archive
+			.file_name()
+			.unwrap()
+			.to_string_lossy()
+			.starts_with("grin_gui_wallet_my_wallet_"));
+	}
+}
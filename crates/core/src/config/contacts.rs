@@ -0,0 +1,207 @@
+use crate::error::ContactError;
+use crate::fs::PersistentData;
+use grin_core::global::ChainTypes;
+use grin_wallet_libwallet::SlatepackAddress;
+use serde::{Deserialize, Serialize};
+use std::convert::TryFrom;
+use std::path::PathBuf;
+
+/// Most suggestions offered while typing a recipient
+const MAX_SUGGESTIONS: usize = 5;
+
+/// Named slatepack address
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
+pub struct Contact {
+	pub name: String,
+	pub address: String,
+	#[serde(default)]
+	pub notes: String,
+}
+
+/// Address book, kept next to `grin-gui.yml`
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Default)]
+pub struct Contacts {
+	#[serde(default)]
+	pub contacts: Vec<Contact>,
+}
+
+impl PersistentData for Contacts {
+	fn relative_path() -> PathBuf {
+		PathBuf::from("contacts.yml")
+	}
+}
+
+/// Human-readable part slatepack addresses have on the given chain
+fn address_hrp(chain_type: ChainTypes) -> &'static str {
+	match chain_type {
+		ChainTypes::Mainnet => "grin",
+		_ => "tgrin",
+	}
+}
+
+/// Check that an address is a well-formed slatepack address for the given chain
+pub fn validate_address(
+	address: &str,
+	chain_type: ChainTypes,
+) -> Result<SlatepackAddress, ContactError> {
+	let parsed =
+		SlatepackAddress::try_from(address.trim()).map_err(|_| ContactError::InvalidAddress {
+			address: address.to_owned(),
+		})?;
+	if parsed.hrp != address_hrp(chain_type) {
+		return Err(ContactError::WrongChain {
+			address: address.to_owned(),
+			chain_type,
+		});
+	}
+	Ok(parsed)
+}
+
+impl Contacts {
+	pub fn find(&self, name: &str) -> Option<&Contact> {
+		self.contacts
+			.iter()
+			.find(|c| c.name.eq_ignore_ascii_case(name.trim()))
+	}
+
+	/// Add a contact, replacing any existing one with the same name
+	pub fn upsert(&mut self, contact: Contact, chain_type: ChainTypes) -> Result<(), ContactError> {
+		let name = contact.name.trim().to_owned();
+		if name.is_empty() {
+			return Err(ContactError::EmptyName);
+		}
+		let address = validate_address(&contact.address, chain_type)?.to_string();
+		let contact = Contact {
+			name,
+			address,
+			notes: contact.notes,
+		};
+		match self
+			.contacts
+			.iter_mut()
+			.find(|c| c.name.eq_ignore_ascii_case(&contact.name))
+		{
+			Some(existing) => *existing = contact,
+			None => self.contacts.push(contact),
+		}
+		self.contacts
+			.sort_by(|a, b| a.name.to_lowercase().cmp(&b.name.to_lowercase()));
+		Ok(())
+	}
+
+	pub fn remove(&mut self, name: &str) -> Option<Contact> {
+		let index = self
+			.contacts
+			.iter()
+			.position(|c| c.name.eq_ignore_ascii_case(name.trim()))?;
+		Some(self.contacts.remove(index))
+	}
+
+	/// Contacts on the given chain whose name or address starts with what's been typed so far
+	pub fn suggestions(&self, typed: &str, chain_type: ChainTypes) -> Vec<&Contact> {
+		let typed = typed.trim().to_lowercase();
+		if typed.is_empty() {
+			return vec![];
+		}
+		let prefix = format!("{}1", address_hrp(chain_type));
+		self.contacts
+			.iter()
+			.filter(|c| c.address.starts_with(&prefix) && c.address != typed)
+			.filter(|c| c.name.to_lowercase().starts_with(&typed) || c.address.starts_with(&typed))
+			.take(MAX_SUGGESTIONS)
+			.collect()
+	}
+
+	pub fn to_json(&self) -> Result<String, ContactError> {
+		Ok(serde_json::to_string_pretty(&self.contacts)?)
+	}
+
+	/// Merge contacts exported with `to_json`, returning how many were added or updated and how
+	/// many were skipped as invalid for the given chain
+	pub fn import_json(
+		&mut self,
+		json: &str,
+		chain_type: ChainTypes,
+	) -> Result<(usize, usize), ContactError> {
+		let imported: Vec<Contact> = serde_json::from_str(json)?;
+		let mut added = 0;
+		let mut skipped = 0;
+		for contact in imported {
+			match self.upsert(contact, chain_type) {
+				Ok(_) => added += 1,
+				Err(_) => skipped += 1,
+			}
+		}
+		Ok((added, skipped))
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	const MAINNET_ADDRESS: &str = "grin1tpnxvenxvenxvenxvenxvenxvenxvenxvenxvenxvenxvenxvenqnr5dak";
+	const TESTNET_ADDRESS: &str =
+		"tgrin1tpnxvenxvenxvenxvenxvenxvenxvenxvenxvenxvenxvenxvenqvkez6m";
+
+	fn contact(name: &str, address: &str) -> Contact {
+		Contact {
+			name: name.to_owned(),
+			address: address.to_owned(),
+			notes: String::new(),
+		}
+	}
+
+	#[test]
+	fn test_contacts_validation_suggestions_and_import() {
+		let mut contacts = Contacts::default();
+		contacts
+			.upsert(contact(" Alice ", MAINNET_ADDRESS), ChainTypes::Mainnet)
+			.unwrap();
+		assert!(matches!(
+			contacts.upsert(contact("Bob", TESTNET_ADDRESS), ChainTypes::Mainnet),
+			Err(ContactError::WrongChain { .. })
+		));
+		assert!(matches!(
+			contacts.upsert(contact("Bob", "grin1nope"), ChainTypes::Mainnet),
+			Err(ContactError::InvalidAddress { .. })
+		));
+		assert!(matches!(
+			contacts.upsert(contact("", MAINNET_ADDRESS), ChainTypes::Mainnet),
+			Err(ContactError::EmptyName)
+		));
+
+		// Same name replaces the existing contact
+		let mut alice = contact("alice", MAINNET_ADDRESS);
+		alice.notes = "coffee".to_owned();
+		contacts.upsert(alice, ChainTypes::Mainnet).unwrap();
+		assert_eq!(contacts.contacts.len(), 1);
+		assert_eq!(contacts.find("ALICE").unwrap().notes, "coffee");
+
+		assert_eq!(contacts.suggestions("al", ChainTypes::Mainnet).len(), 1);
+		assert_eq!(
+			contacts.suggestions("grin1tp", ChainTypes::Mainnet).len(),
+			1
+		);
+		assert!(contacts.suggestions("al", ChainTypes::Testnet).is_empty());
+		assert!(contacts
+			.suggestions(MAINNET_ADDRESS, ChainTypes::Mainnet)
+			.is_empty());
+
+		let json = serde_json::to_string(&vec![
+			contact("Carol", MAINNET_ADDRESS),
+			contact("Dave", TESTNET_ADDRESS),
+		])
+		.unwrap();
+		assert_eq!(
+			contacts.import_json(&json, ChainTypes::Mainnet).unwrap(),
+			(1, 1)
+		);
+		let mut round_trip = Contacts::default();
+		round_trip
+			.import_json(&contacts.to_json().unwrap(), ChainTypes::Mainnet)
+			.unwrap();
+		assert_eq!(round_trip, contacts);
+		assert_eq!(contacts.remove("carol").unwrap().name, "Carol");
+	}
+}
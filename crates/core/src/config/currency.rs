@@ -0,0 +1,328 @@
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+
+/// Currency prices of grin are shown in: grin itself, bitcoin or a fiat currency
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Currency {
+	#[default]
+	GRIN,
+	BTC,
+	Fiat(FiatCode),
+}
+
+/// ISO 4217 code of a fiat currency, e.g. `EUR`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct FiatCode([u8; 3]);
+
+impl FiatCode {
+	/// Code from three letters in any case
+	pub fn new(code: &str) -> Option<Self> {
+		let bytes = code.as_bytes();
+		if bytes.len() != 3 || !bytes.iter().all(u8::is_ascii_alphabetic) {
+			return None;
+		}
+		Some(FiatCode([
+			bytes[0].to_ascii_uppercase(),
+			bytes[1].to_ascii_uppercase(),
+			bytes[2].to_ascii_uppercase(),
+		]))
+	}
+
+	pub fn as_str(&self) -> &str {
+		// only ever made of ASCII letters
+		std::str::from_utf8(&self.0).unwrap()
+	}
+}
+
+/// A fiat currency grin is quoted in
+struct FiatInfo {
+	code: &'static str,
+	name: &'static str,
+	symbol: &'static str,
+	/// Decimals of the currency's minor unit, as in ISO 4217
+	minor_units: usize,
+}
+
+const fn fiat(
+	code: &'static str,
+	name: &'static str,
+	symbol: &'static str,
+	minor_units: usize,
+) -> FiatInfo {
+	FiatInfo {
+		code,
+		name,
+		symbol,
+		minor_units,
+	}
+}
+
+/// Fiat currencies CoinGecko quotes grin in. Symbols that other currencies share carry a prefix
+/// or are the code itself, so `$` is always the US dollar.
+const FIAT_CURRENCIES: [FiatInfo; 45] = [
+	fiat("AED", "UAE Dirham", "AED", 2),
+	fiat("ARS", "Argentine Peso", "ARS", 2),
+	fiat("AUD", "Australian Dollar", "A$", 2),
+	fiat("BDT", "Bangladeshi Taka", "BDT", 2),
+	fiat("BHD", "Bahraini Dinar", "BHD", 3),
+	fiat("BMD", "Bermudan Dollar", "BMD", 2),
+	fiat("BRL", "Brazilian Real", "R$", 2),
+	fiat("CAD", "Canadian Dollar", "CA$", 2),
+	fiat("CHF", "Swiss Franc", "CHF", 2),
+	fiat("CLP", "Chilean Peso", "CLP", 0),
+	fiat("CNY", "Chinese Yuan", "CN¥", 2),
+	fiat("CZK", "Czech Koruna", "CZK", 2),
+	fiat("DKK", "Danish Krone", "DKK", 2),
+	fiat("EUR", "Euro", "€", 2),
+	fiat("GBP", "British Pound", "£", 2),
+	fiat("GEL", "Georgian Lari", "GEL", 2),
+	fiat("HKD", "Hong Kong Dollar", "HK$", 2),
+	fiat("HUF", "Hungarian Forint", "HUF", 2),
+	fiat("IDR", "Indonesian Rupiah", "IDR", 2),
+	fiat("ILS", "Israeli New Shekel", "₪", 2),
+	fiat("INR", "Indian Rupee", "₹", 2),
+	fiat("JPY", "Japanese Yen", "¥", 0),
+	fiat("KRW", "South Korean Won", "₩", 0),
+	fiat("KWD", "Kuwaiti Dinar", "KWD", 3),
+	fiat("LKR", "Sri Lankan Rupee", "LKR", 2),
+	fiat("MMK", "Myanmar Kyat", "MMK", 2),
+	fiat("MXN", "Mexican Peso", "MX$", 2),
+	fiat("MYR", "Malaysian Ringgit", "MYR", 2),
+	fiat("NGN", "Nigerian Naira", "NGN", 2),
+	fiat("NOK", "Norwegian Krone", "NOK", 2),
+	fiat("NZD", "New Zealand Dollar", "NZ$", 2),
+	fiat("PHP", "Philippine Peso", "₱", 2),
+	fiat("PKR", "Pakistani Rupee", "PKR", 2),
+	fiat("PLN", "Polish Zloty", "PLN", 2),
+	fiat("RUB", "Russian Ruble", "RUB", 2),
+	fiat("SAR", "Saudi Riyal", "SAR", 2),
+	fiat("SEK", "Swedish Krona", "SEK", 2),
+	fiat("SGD", "Singapore Dollar", "SGD", 2),
+	fiat("THB", "Thai Baht", "THB", 2),
+	fiat("TRY", "Turkish Lira", "TRY", 2),
+	fiat("TWD", "New Taiwan Dollar", "NT$", 2),
+	fiat("UAH", "Ukrainian Hryvnia", "UAH", 2),
+	fiat("USD", "US Dollar", "$", 2),
+	fiat("VND", "Vietnamese Dong", "₫", 0),
+	fiat("ZAR", "South African Rand", "ZAR", 2),
+];
+
+/// Decimals shown beyond a fiat currency's minor unit, since one grin is worth a fraction of it
+const EXTRA_FIAT_PRECISION: usize = 2;
+
+impl fmt::Display for Currency {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Currency::GRIN => write!(f, "Grin"),
+			Currency::BTC => write!(f, "Bitcoin"),
+			Currency::Fiat(code) => match self.fiat_info() {
+				Some(info) => write!(f, "{} ({})", info.name, code.as_str()),
+				None => write!(f, "{}", code.as_str()),
+			},
+		}
+	}
+}
+
+impl Currency {
+	pub const USD: Currency = Currency::Fiat(FiatCode(*b"USD"));
+
+	/// Every currency that can be chosen, sorted by name (@see `impl Display`) after grin and
+	/// bitcoin
+	pub fn all() -> Vec<Currency> {
+		let mut fiat: Vec<Currency> = FIAT_CURRENCIES
+			.iter()
+			.filter_map(|f| FiatCode::new(f.code).map(Currency::Fiat))
+			.collect();
+		fiat.sort_by_key(|c| c.to_string());
+		let mut all = vec![Currency::GRIN, Currency::BTC];
+		all.extend(fiat);
+		all
+	}
+
+	/// Currency by its code in any case, e.g. `eur`. Fiat currencies don't need to be known, as
+	/// long as the price provider quotes them.
+	pub fn from_code(code: &str) -> Option<Currency> {
+		match code.trim().to_ascii_uppercase().as_str() {
+			"GRIN" => Some(Currency::GRIN),
+			"BTC" => Some(Currency::BTC),
+			c => FiatCode::new(c).map(Currency::Fiat),
+		}
+	}
+
+	fn fiat_info(&self) -> Option<&'static FiatInfo> {
+		match self {
+			Currency::Fiat(code) => FIAT_CURRENCIES.iter().find(|f| f.code == code.as_str()),
+			_ => None,
+		}
+	}
+
+	pub fn shortname(&self) -> String {
+		self.code().to_lowercase()
+	}
+
+	/// Code shown next to amounts and written to exports, e.g. `USD`
+	pub fn code(&self) -> String {
+		match self {
+			Currency::GRIN => "GRIN".to_owned(),
+			Currency::BTC => "BTC".to_owned(),
+			Currency::Fiat(code) => code.as_str().to_owned(),
+		}
+	}
+
+	pub fn symbol(&self) -> String {
+		match self {
+			Currency::GRIN => "".to_owned(),
+			Currency::BTC => "₿".to_owned(),
+			Currency::Fiat(code) => self
+				.fiat_info()
+				.map_or(code.as_str(), |info| info.symbol)
+				.to_owned(),
+		}
+	}
+
+	pub fn precision(&self) -> usize {
+		match self {
+			Currency::GRIN => 9,
+			Currency::BTC => 8,
+			Currency::Fiat(_) => {
+				self.fiat_info().map_or(2, |info| info.minor_units) + EXTRA_FIAT_PRECISION
+			}
+		}
+	}
+
+	/// `value` with the currency's symbol and precision, e.g. `$1,234.5000` or `1.234,5000 €`
+	pub fn format_value(&self, value: f64, format: &NumberFormat) -> String {
+		let number = format.format(value.abs(), self.precision());
+		// no sign for values rounded to zero
+		let sign = if value < 0.0 && number.chars().any(|c| c.is_ascii_digit() && c != '0') {
+			"-"
+		} else {
+			""
+		};
+		let symbol = self.symbol();
+		if symbol.is_empty() {
+			format!("{}{}", sign, number)
+		} else if format.symbol_after {
+			format!("{}{} {}", sign, number, symbol)
+		} else if symbol.ends_with(|c: char| c.is_alphabetic()) {
+			format!("{}{} {}", sign, symbol, number)
+		} else {
+			format!("{}{}{}", sign, symbol, number)
+		}
+	}
+}
+
+impl Serialize for Currency {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		serializer.serialize_str(&self.code())
+	}
+}
+
+/// Read from its code. Earlier versions wrote the variants of a `GRIN`, `BTC` and `USD` enum,
+/// which are codes as well.
+impl<'de> Deserialize<'de> for Currency {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		let code = String::deserialize(deserializer)?;
+		Currency::from_code(&code)
+			.ok_or_else(|| de::Error::custom(format!("unknown currency {:?}", code)))
+	}
+}
+
+/// Read a configured currency, falling back to grin for one that can't be read rather than
+/// failing to load the whole config
+pub(super) fn deserialize_or_default<'de, D: Deserializer<'de>>(
+	deserializer: D,
+) -> Result<Currency, D::Error> {
+	let value = serde_yaml::Value::deserialize(deserializer)?;
+	Ok(serde_yaml::from_value(value.clone()).unwrap_or_else(|e| {
+		log::warn!("Ignoring configured currency {:?}: {}", value, e);
+		Currency::default()
+	}))
+}
+
+/// How a language writes numbers
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NumberFormat {
+	pub decimal_separator: char,
+	/// Separator between groups of thousands, if they're grouped
+	pub group_separator: Option<char>,
+	/// Whether currency symbols follow amounts, as in `1,50 €`
+	pub symbol_after: bool,
+}
+
+impl Default for NumberFormat {
+	fn default() -> Self {
+		NumberFormat {
+			decimal_separator: '.',
+			group_separator: Some(','),
+			symbol_after: false,
+		}
+	}
+}
+
+impl NumberFormat {
+	/// `value` with `precision` decimals
+	pub fn format(&self, value: f64, precision: usize) -> String {
+		let number = format!("{:.*}", precision, value);
+		let (sign, number) = match number.strip_prefix('-') {
+			Some(n) => ("-", n),
+			None => ("", number.as_str()),
+		};
+		let (int, frac) = match number.split_once('.') {
+			Some((int, frac)) => (int, Some(frac)),
+			None => (number, None),
+		};
+
+		let mut grouped = String::new();
+		for (i, digit) in int.chars().enumerate() {
+			if i > 0 && (int.len() - i) % 3 == 0 {
+				if let Some(sep) = self.group_separator {
+					grouped.push(sep);
+				}
+			}
+			grouped.push(digit);
+		}
+		match frac {
+			Some(frac) => format!("{}{}{}{}", sign, grouped, self.decimal_separator, frac),
+			None => format!("{}{}", sign, grouped),
+		}
+	}
+
+	/// Number as entered, with this format's decimal separator and grouped or not. A `.` is taken
+	/// as the decimal separator too, unless it's the group separator.
+	pub fn parse(&self, value: &str) -> Option<f64> {
+		let value = value.trim();
+		let decimal_separator = match self.group_separator {
+			Some('.') => self.decimal_separator,
+			_ if value.contains(self.decimal_separator) => self.decimal_separator,
+			_ => '.',
+		};
+		let (int, frac) = match value.split_once(decimal_separator) {
+			Some((int, frac)) => (int, Some(frac)),
+			None => (value, None),
+		};
+
+		let int = match self.group_separator {
+			Some(sep) if int.contains(sep) => {
+				// Only whole groups of thousands, so a mistyped decimal separator is rejected
+				// rather than taken for grouping
+				let is_digits = |g: &str| g.chars().all(|c| c.is_ascii_digit());
+				let mut groups = int.split(sep);
+				let first = groups.next()?.trim_start_matches('-');
+				if first.is_empty()
+					|| first.len() > 3
+					|| !is_digits(first)
+					|| !groups.all(|g| g.len() == 3 && is_digits(g))
+				{
+					return None;
+				}
+				int.replace(sep, "")
+			}
+			_ => int.to_owned(),
+		};
+		match frac {
+			Some(frac) => format!("{}.{}", int, frac).parse().ok(),
+			None => int.parse().ok(),
+		}
+	}
+}
@@ -0,0 +1,29 @@
+use crate::fs::PersistentData;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+use std::path::PathBuf;
+use uuid::Uuid;
+
+/// Slates picked up from a watched slatepack folder and then acted on or dismissed, kept next
+/// to `grin-gui.yml` so they aren't offered again after a restart
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Default)]
+pub struct HandledSlatepacks {
+	#[serde(default)]
+	pub slate_ids: BTreeSet<String>,
+}
+
+impl PersistentData for HandledSlatepacks {
+	fn relative_path() -> PathBuf {
+		PathBuf::from("handled-slatepacks.yml")
+	}
+}
+
+impl HandledSlatepacks {
+	pub fn contains(&self, slate_id: &Uuid) -> bool {
+		self.slate_ids.contains(&slate_id.to_string())
+	}
+
+	pub fn insert(&mut self, slate_id: &Uuid) {
+		self.slate_ids.insert(slate_id.to_string());
+	}
+}
@@ -4,11 +4,21 @@ use serde::{Deserialize, Serialize};
 use std::fmt::{self, Display, Formatter};
 use std::path::PathBuf;
 
+mod contacts;
+mod currency;
+mod handled_slatepacks;
+mod tx_notes;
 mod wallet;
 
 use crate::fs::PersistentData;
 
-pub use crate::config::wallet::Wallet;
+pub use crate::config::contacts::{validate_address, Contact, Contacts};
+pub use crate::config::currency::{Currency, FiatCode, NumberFormat};
+pub use crate::config::handled_slatepacks::HandledSlatepacks;
+pub use crate::config::tx_notes::{TxNote, TxNotes};
+pub use crate::config::wallet::{
+	ForeignListenerConfig, SendDefaults, Wallet, FOREIGN_API_SECRET_FILE_NAME,
+};
 
 /// Config struct.
 #[derive(Deserialize, Serialize, Debug, PartialEq, Default, Clone)]
@@ -27,8 +37,14 @@ pub struct Config {
 	pub theme: Option<String>,
 
 	/// User preferred currency
+	#[serde(default, deserialize_with = "currency::deserialize_or_default")]
 	pub currency: Currency,
 
+	/// Where prices are fetched from, in CoinGecko's `market_chart` format. `{currency}` is
+	/// replaced with the currency's short name. CoinGecko itself if not set.
+	#[serde(default)]
+	pub price_endpoint: Option<String>,
+
 	#[serde(default)]
 	pub tx_column_config: ColumnConfig,
 
@@ -82,6 +98,31 @@ impl Config {
 		self.wallets.len() - 1
 	}
 
+	/// Remove a wallet definition, keeping `current_wallet_index` pointing at the same
+	/// wallet (or at none if it was the one removed)
+	pub fn remove_wallet(&mut self, index: usize) -> Option<Wallet> {
+		if index >= self.wallets.len() {
+			return None;
+		}
+		let wallet = self.wallets.remove(index);
+		self.current_wallet_index = match self.current_wallet_index {
+			Some(i) if i == index => None,
+			Some(i) if i > index => Some(i - 1),
+			other => other,
+		};
+		Some(wallet)
+	}
+
+	/// The wallet definition currently selected, if any
+	pub fn current_wallet(&self) -> Option<&Wallet> {
+		self.current_wallet_index.and_then(|i| self.wallets.get(i))
+	}
+
+	pub fn current_wallet_mut(&mut self) -> Option<&mut Wallet> {
+		self.current_wallet_index
+			.and_then(move |i| self.wallets.get_mut(i))
+	}
+
 	pub fn get_wallet_slatepack_dir(&self) -> Option<String> {
 		if let Some(i) = self.current_wallet_index.as_ref() {
 			if let Some(ref tld) = self.wallets[*i].tld {
@@ -196,6 +237,18 @@ impl Language {
 			Language::German => "de_DE",
 		}
 	}
+
+	/// How numbers and amounts of money are written in the language
+	pub fn number_format(self) -> NumberFormat {
+		match self {
+			Language::English => NumberFormat::default(),
+			Language::German => NumberFormat {
+				decimal_separator: ',',
+				group_separator: Some('.'),
+				symbol_after: true,
+			},
+		}
+	}
 }
 
 impl Default for Language {
@@ -234,74 +287,122 @@ impl Default for TxMethod {
 	}
 }
 
-#[derive(
-	Default, Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize, Hash, PartialOrd, Ord,
-)]
-pub enum Currency {
-	#[default]
-	GRIN,
-	BTC,
-	USD,
+/// Returns a Config.
+///
+/// This functions handles the initialization of a Config.
+pub async fn load_config() -> Result<Config, FilesystemError> {
+	log::debug!("loading config");
+
+	Ok(Config::load_or_default()?)
 }
 
-impl std::fmt::Display for Currency {
-	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-		write!(
-			f,
-			"{}",
-			match self {
-				Currency::BTC => "Bitcoin",
-				Currency::USD => "US Dollar",
-				Currency::GRIN => "Grin",
-			}
-		)
-	}
+const fn default_true() -> bool {
+	true
 }
 
-impl Currency {
-	// Alphabetically sorted based on their local name (@see `impl Display`).
-	pub const ALL: [Currency; 3] = [Currency::BTC, Currency::GRIN, Currency::USD];
+#[cfg(test)]
+mod test {
+	use super::*;
+	use grin_core::global::ChainTypes;
 
-	pub fn shortname(&self) -> String {
-		match *self {
-			Currency::BTC => "btc".to_owned(),
-			Currency::GRIN => "grin".to_owned(),
-			Currency::USD => "usd".to_owned(),
+	#[test]
+	fn test_remove_wallet_fixes_current_index() {
+		let mut config = Config::default();
+		for name in ["a", "b", "c"].iter() {
+			config.add_wallet(Wallet::new(None, name.to_string(), ChainTypes::Mainnet));
 		}
+
+		config.current_wallet_index = Some(2);
+		assert_eq!(config.remove_wallet(0).unwrap().display_name, "a");
+		assert_eq!(config.current_wallet_index, Some(1));
+		assert_eq!(config.wallets[1].display_name, "c");
+
+		config.remove_wallet(1);
+		assert_eq!(config.current_wallet_index, None);
+		assert!(config.remove_wallet(5).is_none());
+		assert_eq!(config.wallets.len(), 1);
 	}
 
-	pub fn symbol(&self) -> String {
-		match *self {
-			Currency::BTC => "₿".to_owned(),
-			Currency::GRIN => "".to_owned(),
-			Currency::USD => "$".to_owned(),
+	#[test]
+	fn test_currency_migrated_and_formatted() {
+		// Values written when `Currency` was an enum of grin, bitcoin and US dollars
+		for (yaml, currency) in [
+			("currency: GRIN\n", Currency::GRIN),
+			("currency: BTC\n", Currency::BTC),
+			("currency: USD\n", Currency::USD),
+			("theme: Dark\n", Currency::GRIN),
+			("currency: {}\n", Currency::GRIN),
+		] {
+			let config: Config = serde_yaml::from_str(yaml).unwrap();
+			assert_eq!(config.currency, currency, "{}", yaml);
 		}
+
+		let mut config = Config::default();
+		config.currency = Currency::from_code("eur").unwrap();
+		let yaml = serde_yaml::to_string(&config).unwrap();
+		assert!(yaml.contains("currency: EUR"));
+		assert_eq!(serde_yaml::from_str::<Config>(&yaml).unwrap(), config);
+		let unlisted = serde_yaml::from_str::<Config>("currency: isk\n").unwrap();
+		assert_eq!(unlisted.currency.symbol(), "ISK");
+		assert!(Currency::from_code("EURO").is_none());
+		assert!(Currency::all().contains(&config.currency));
+
+		let en = Language::English.number_format();
+		let de = Language::German.number_format();
+		assert_eq!(Currency::USD.format_value(1234.5, &en), "$1,234.5000");
+		assert_eq!(config.currency.format_value(-1234.5, &de), "-1.234,5000 €");
+		assert_eq!(unlisted.currency.format_value(0.00001, &en), "ISK 0.0000");
+		let yen = Currency::from_code("JPY").unwrap();
+		assert_eq!(yen.format_value(1234567.891, &en), "¥1,234,567.89");
+		assert_eq!(de.parse("5,98"), Some(5.98));
+		assert_eq!(de.parse("1.000"), Some(1000.0));
+		assert_eq!(de.parse("1.234,50"), Some(1234.5));
+		// A `.` is the group separator in German, so it isn't taken as a decimal point
+		assert_eq!(de.parse("5.98"), None);
+		assert_eq!(de.parse("-12.345.678"), Some(-12345678.0));
+		assert_eq!(en.parse("1,234.5"), Some(1234.5));
+		assert_eq!(en.parse("5,98"), None);
+		assert_eq!(en.parse("0.5"), Some(0.5));
 	}
 
-	pub fn precision(&self) -> usize {
-		match *self {
-			Currency::BTC => 8,
-			Currency::GRIN => 9,
-			Currency::USD => 4,
-		}
+	#[test]
+	fn test_wallet_without_send_defaults_loads() {
+		let yaml = "display_name: old\nchain_type: Testnet\nsend_defaults:\n  max_outputs: 50\n";
+		let wallet: Wallet = serde_yaml::from_str(yaml).unwrap();
+		assert_eq!(wallet.send_defaults.max_outputs, 50);
+		assert_eq!(wallet.send_defaults.num_change_outputs, 1);
+
+		let wallet: Wallet = serde_yaml::from_str("display_name: old\n").unwrap();
+		assert_eq!(wallet.send_defaults, SendDefaults::default());
 	}
-}
 
-/// Returns a Config.
-///
-/// This functions handles the initialization of a Config.
-pub async fn load_config() -> Result<Config, FilesystemError> {
-	log::debug!("loading config");
+	#[test]
+	fn test_foreign_listener_secret_kept_out_of_config() {
+		let tld = tempfile::tempdir().unwrap();
+		let mut wallet = Wallet::new(
+			Some(tld.path().to_owned()),
+			"listener".to_owned(),
+			ChainTypes::Testnet,
+		);
+		assert_eq!(wallet.foreign_listener.read_secret().unwrap(), None);
 
-	Ok(Config::load_or_default()?)
-}
+		wallet.set_foreign_listener_secret(Some("hunter2")).unwrap();
+		let path = tld.path().join(FOREIGN_API_SECRET_FILE_NAME);
+		assert_eq!(wallet.foreign_listener.secret_path.as_ref(), Some(&path));
+		assert_eq!(
+			wallet.foreign_listener.read_secret().unwrap().as_deref(),
+			Some("hunter2")
+		);
+		assert!(!serde_yaml::to_string(&wallet).unwrap().contains("hunter2"));
 
-const fn default_true() -> bool {
-	true
-}
+		// Clearing stops using the file without removing it
+		wallet.set_foreign_listener_secret(None).unwrap();
+		assert_eq!(wallet.foreign_listener.read_secret().unwrap(), None);
+		assert!(path.exists());
 
-#[cfg(test)]
-mod test {
+		let mut no_tld = Wallet::new(None, "remote".to_owned(), ChainTypes::Testnet);
+		assert!(no_tld.set_foreign_listener_secret(Some("hunter2")).is_err());
+	}
 
 	/// This method will take a relative path and make a case insentitive pattern
 	// For some reason the case insensitive pattern doesn't work
@@ -0,0 +1,90 @@
+use crate::fs::PersistentData;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use uuid::Uuid;
+
+/// User's own label and note for a transaction
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Default)]
+pub struct TxNote {
+	#[serde(default)]
+	pub label: String,
+	#[serde(default)]
+	pub note: String,
+}
+
+impl TxNote {
+	pub fn is_empty(&self) -> bool {
+		self.label.is_empty() && self.note.is_empty()
+	}
+}
+
+/// Local transaction annotations keyed by slate id, kept next to `grin-gui.yml`. These never
+/// leave this machine and aren't part of the wallet data.
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Default)]
+pub struct TxNotes {
+	#[serde(default)]
+	pub notes: BTreeMap<String, TxNote>,
+}
+
+impl PersistentData for TxNotes {
+	fn relative_path() -> PathBuf {
+		PathBuf::from("tx-notes.yml")
+	}
+}
+
+impl TxNotes {
+	pub fn get(&self, slate_id: &Uuid) -> Option<&TxNote> {
+		self.notes.get(&slate_id.to_string())
+	}
+
+	/// Label of a transaction, if it has one
+	pub fn label(&self, slate_id: Option<&Uuid>) -> Option<&str> {
+		slate_id
+			.and_then(|id| self.get(id))
+			.map(|n| n.label.as_str())
+			.filter(|l| !l.is_empty())
+	}
+
+	/// Set the label and note of a transaction, forgetting it when both are empty
+	pub fn set(&mut self, slate_id: &Uuid, note: TxNote) {
+		let note = TxNote {
+			label: note.label.trim().to_owned(),
+			note: note.note.trim().to_owned(),
+		};
+		if note.is_empty() {
+			self.notes.remove(&slate_id.to_string());
+		} else {
+			self.notes.insert(slate_id.to_string(), note);
+		}
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn test_tx_notes_set_and_clear() {
+		let mut notes = TxNotes::default();
+		let id = Uuid::from_u128(1);
+		notes.set(
+			&id,
+			TxNote {
+				label: " Rent ".to_owned(),
+				note: "March, paid to Alice".to_owned(),
+			},
+		);
+		assert_eq!(notes.label(Some(&id)), Some("Rent"));
+		assert_eq!(notes.get(&id).unwrap().note, "March, paid to Alice");
+		assert_eq!(notes.label(Some(&Uuid::from_u128(2))), None);
+		assert_eq!(notes.label(None), None);
+
+		let yaml = serde_yaml::to_string(&notes).unwrap();
+		assert_eq!(serde_yaml::from_str::<TxNotes>(&yaml).unwrap(), notes);
+
+		// Clearing both fields forgets the transaction
+		notes.set(&id, TxNote::default());
+		assert!(notes.notes.is_empty());
+	}
+}
@@ -1,7 +1,14 @@
 use grin_core::global::ChainTypes;
 use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io;
+use std::net::{IpAddr, SocketAddr};
 use std::path::PathBuf;
 
+/// Name of the foreign listener's secret file in the wallet's top level directory, as used by
+/// grin-wallet
+pub const FOREIGN_API_SECRET_FILE_NAME: &str = ".foreign_api_secret";
+
 /// Struct for settings related to World of Warcraft.
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 #[serde(default)]
@@ -16,6 +23,112 @@ pub struct Wallet {
 	pub use_embedded_node: bool,
 	/// Chain type of wallet
 	pub chain_type: ChainTypes,
+	/// Owner API endpoint, if the wallet is served by a remote grin-wallet rather than opened locally
+	pub owner_api_url: Option<String>,
+	/// Location of the remote wallet's `.owner_api_secret`
+	pub owner_api_secret_path: Option<PathBuf>,
+	/// Foreign API endpoint of the remote wallet, needed to receive
+	pub foreign_api_url: Option<String>,
+	/// Parameters new transactions start out with
+	#[serde(default)]
+	pub send_defaults: SendDefaults,
+	/// Cancel outstanding transactions once their TTL has passed, unlocking their outputs
+	#[serde(default)]
+	pub auto_cancel_expired: bool,
+	/// Look for incoming slatepacks in the wallet's slatepack directory and the inbox folder
+	#[serde(default)]
+	pub watch_slatepacks: bool,
+	/// Additional folder watched for incoming slatepacks
+	#[serde(default)]
+	pub slatepack_inbox: Option<PathBuf>,
+	/// Foreign API HTTP listener settings
+	#[serde(default)]
+	pub foreign_listener: ForeignListenerConfig,
+}
+
+/// Transaction building parameters offered under "Advanced" on the send form
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
+#[serde(default)]
+pub struct SendDefaults {
+	/// Confirmations an output needs before it can be spent
+	pub minimum_confirmations: u64,
+	/// Maximum number of inputs the transaction may spend
+	pub max_outputs: u32,
+	/// Number of outputs the change is split into
+	pub num_change_outputs: u32,
+	/// Spend all spendable outputs rather than the smallest set that covers the amount
+	pub selection_strategy_is_use_all: bool,
+	/// Only select and lock inputs when the transaction is finalized
+	pub late_lock: bool,
+	/// Blocks after which an unfinished transaction expires, if any
+	pub ttl_blocks: Option<u64>,
+}
+
+impl Default for SendDefaults {
+	fn default() -> Self {
+		SendDefaults {
+			minimum_confirmations: 2,
+			max_outputs: 500,
+			num_change_outputs: 1,
+			selection_strategy_is_use_all: false,
+			late_lock: false,
+			ttl_blocks: None,
+		}
+	}
+}
+
+/// Foreign API HTTP listener, letting senders transact with this wallet synchronously
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
+#[serde(default)]
+pub struct ForeignListenerConfig {
+	/// Start the listener whenever the wallet is opened
+	pub enabled: bool,
+	/// Interface to listen on
+	pub bind_address: String,
+	pub port: u16,
+	/// File holding the basic auth secret senders must present, if any
+	pub secret_path: Option<PathBuf>,
+}
+
+impl ForeignListenerConfig {
+	/// Listener on grin-wallet's default port for the chain
+	pub fn for_chain(chain_type: ChainTypes) -> Self {
+		let port = match chain_type {
+			ChainTypes::Mainnet => 3415,
+			_ => 13415,
+		};
+		ForeignListenerConfig {
+			port,
+			..Default::default()
+		}
+	}
+
+	/// Address to bind to, if the configured one is valid
+	pub fn socket_addr(&self) -> Option<SocketAddr> {
+		let ip: IpAddr = self.bind_address.trim().parse().ok()?;
+		Some(SocketAddr::new(ip, self.port))
+	}
+
+	/// Basic auth secret senders must present, if one is set
+	pub fn read_secret(&self) -> io::Result<Option<String>> {
+		let path = match &self.secret_path {
+			Some(p) => p,
+			None => return Ok(None),
+		};
+		let secret = fs::read_to_string(path)?;
+		Ok(Some(secret.trim().to_owned()).filter(|s| !s.is_empty()))
+	}
+}
+
+impl Default for ForeignListenerConfig {
+	fn default() -> Self {
+		ForeignListenerConfig {
+			enabled: false,
+			bind_address: "127.0.0.1".to_owned(),
+			port: 3415,
+			secret_path: None,
+		}
+	}
 }
 
 impl Wallet {
@@ -25,8 +138,45 @@ impl Wallet {
 			display_name,
 			use_embedded_node: true,
 			chain_type,
+			owner_api_url: None,
+			owner_api_secret_path: None,
+			foreign_api_url: None,
+			send_defaults: SendDefaults::default(),
+			auto_cancel_expired: false,
+			watch_slatepacks: false,
+			slatepack_inbox: None,
+			foreign_listener: ForeignListenerConfig::for_chain(chain_type),
 		}
 	}
+
+	/// Whether this wallet is accessed through a remote owner API
+	pub fn is_remote(&self) -> bool {
+		self.owner_api_url.is_some()
+	}
+
+	/// Write the foreign listener's secret next to the wallet data rather than into this config.
+	/// Clearing it only stops using the file, which grin-wallet may share.
+	pub fn set_foreign_listener_secret(&mut self, secret: Option<&str>) -> io::Result<()> {
+		let secret = match secret {
+			Some(s) => s,
+			None => {
+				self.foreign_listener.secret_path = None;
+				return Ok(());
+			}
+		};
+		let path = match &self.tld {
+			Some(tld) => tld.join(FOREIGN_API_SECRET_FILE_NAME),
+			None => {
+				return Err(io::Error::new(
+					io::ErrorKind::NotFound,
+					"wallet has no top level directory",
+				))
+			}
+		};
+		fs::write(&path, secret)?;
+		self.foreign_listener.secret_path = Some(path);
+		Ok(())
+	}
 }
 
 impl Default for Wallet {
@@ -36,6 +186,14 @@ impl Default for Wallet {
 			display_name: "Default".to_owned(),
 			use_embedded_node: true,
 			chain_type: ChainTypes::Mainnet,
+			owner_api_url: None,
+			owner_api_secret_path: None,
+			foreign_api_url: None,
+			send_defaults: SendDefaults::default(),
+			auto_cancel_expired: false,
+			watch_slatepacks: false,
+			slatepack_inbox: None,
+			foreign_listener: ForeignListenerConfig::default(),
 		}
 	}
 }
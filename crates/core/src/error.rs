@@ -16,14 +16,69 @@ pub enum GrinWalletInterfaceError {
 	InvalidSlatepackAddress,
 	#[error("Can't load slatepack file at {file}")]
 	InvalidSlatepackFile { file: String },
+	#[error("Slatepack file at {file} is {size} bytes, more than the {max} byte limit")]
+	SlatepackFileTooLarge { file: String, size: u64, max: u64 },
+	#[error("Can't access slatepack file at {file}")]
+	SlatepackFileIo {
+		file: String,
+		#[source]
+		source: std::io::Error,
+	},
+	#[error("Can't encode QR code")]
+	QrEncode(#[from] qrcode::types::QrError),
+	#[error("No QR code found")]
+	QrNotFound,
+	#[error("Only {found} of {total} QR code parts found")]
+	QrPartsMissing { found: usize, total: usize },
+	#[error("QR code parts are from different sequences")]
+	QrPartsMismatch,
+	#[error("Invalid foreign listener address {addr}")]
+	InvalidListenerAddress { addr: String },
+	#[error("Foreign listener is already running on {addr}")]
+	ForeignListenerRunning { addr: String },
+	#[error("Can't start foreign listener on {addr}: {message}")]
+	ForeignListenerStart { addr: String, message: String },
+	#[error("Can't serialize transaction export")]
+	TxExportSerialize(#[source] serde_json::Error),
+	#[error("Can't write transaction export to {file}")]
+	TxExportIo {
+		file: String,
+		#[source]
+		source: std::io::Error,
+	},
 	#[error("Invalid Tx Log State")]
 	InvalidTxLogState,
 	#[error("Invalid Invoice Proof")]
 	InvalidInvoiceProof,
 	#[error("Invalid Recovery Phrase")]
 	InvalidRecoveryPhrase,
+	#[error("Active account not found")]
+	ActiveAccountNotFound,
 	#[error("Can't read wallet config file at {file}")]
 	ConfigReadError { file: String },
+	#[error("Remote Owner API not connected")]
+	RemoteOwnerAPINotConnected,
+	#[error("Remote Owner API error: {message}")]
+	RemoteOwnerAPI { message: String },
+	#[error("{method} is not supported over the remote Owner API")]
+	RemoteOwnerAPIUnsupported { method: String },
+	#[error(transparent)]
+	Download(#[from] DownloadError),
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum ContactError {
+	#[error("Contact name can't be empty")]
+	EmptyName,
+	#[error("{address} is not a valid slatepack address")]
+	InvalidAddress { address: String },
+	#[error("{address} is not an address on {chain_type:?}")]
+	WrongChain {
+		address: String,
+		chain_type: grin_core::global::ChainTypes,
+	},
+	#[error(transparent)]
+	SerdeJson(#[from] serde_json::Error),
 }
 
 #[derive(thiserror::Error, Debug)]
@@ -91,6 +146,18 @@ impl From<std::io::Error> for DownloadError {
 	}
 }
 
+#[derive(thiserror::Error, Debug)]
+pub enum PriceError {
+	#[error("Price provider is rate limiting requests")]
+	RateLimited { retry_after: Option<u64> },
+	#[error("No prices available in {currency}")]
+	Unavailable { currency: String },
+	#[error(transparent)]
+	Download(#[from] DownloadError),
+	#[error(transparent)]
+	SerdeJson(#[from] serde_json::Error),
+}
+
 #[derive(thiserror::Error, Debug)]
 pub enum RepositoryError {
 	#[error("No repository set for addon")]
@@ -9,6 +9,7 @@ pub mod fs;
 pub mod logger;
 pub mod network;
 pub mod node;
+pub mod price;
 pub mod theme;
 #[cfg(feature = "wgpu")]
 pub mod utility;
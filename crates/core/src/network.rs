@@ -47,7 +47,7 @@ pub async fn request_async<T: ToString>(
 }
 
 // Generic function for posting Json data
-pub(crate) async fn _post_json_async<T: ToString, D: Serialize>(
+pub(crate) async fn post_json_async<T: ToString, D: Serialize>(
 	url: T,
 	data: D,
 	headers: Vec<(&str, &str)>,
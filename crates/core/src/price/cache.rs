@@ -0,0 +1,49 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use super::PriceHistory;
+use crate::config::Currency;
+use crate::fs::PersistentData;
+
+/// Prices in one currency as last fetched
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
+pub struct CachedPrices {
+	pub fetched: DateTime<Utc>,
+	/// Oldest first
+	pub prices: Vec<(DateTime<Utc>, f64)>,
+}
+
+/// Last fetched prices per currency, kept next to `grin-gui.yml`
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Default)]
+pub struct PriceCache {
+	#[serde(default)]
+	pub histories: BTreeMap<String, CachedPrices>,
+}
+
+impl PersistentData for PriceCache {
+	fn relative_path() -> PathBuf {
+		PathBuf::from("price-cache.yml")
+	}
+}
+
+impl PriceCache {
+	pub fn get(&self, currency: Currency) -> Option<&CachedPrices> {
+		self.histories.get(&currency.shortname())
+	}
+
+	/// Cached prices in `currency`, empty if there are none
+	pub fn history(&self, currency: Currency) -> PriceHistory {
+		self.get(currency)
+			.map(|c| c.prices.iter().copied().collect())
+			.unwrap_or_default()
+	}
+
+	pub fn insert(&mut self, currency: Currency, history: &PriceHistory, fetched: DateTime<Utc>) {
+		let mut prices: Vec<(DateTime<Utc>, f64)> = history.iter().map(|(t, p)| (*t, *p)).collect();
+		prices.sort_by(|a, b| a.0.cmp(&b.0));
+		self.histories
+			.insert(currency.shortname(), CachedPrices { fetched, prices });
+	}
+}
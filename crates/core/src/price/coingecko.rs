@@ -0,0 +1,62 @@
+use futures::future::FutureExt;
+use isahc::http::header::RETRY_AFTER;
+use isahc::http::StatusCode;
+use isahc::AsyncReadResponseExt;
+
+use super::{parse_market_chart, PriceFuture, PriceHistory, PriceProvider};
+use crate::config::Currency;
+use crate::error::{DownloadError, PriceError};
+use crate::network::request_async;
+
+/// CoinGecko's daily grin prices. `{currency}` is replaced with the currency's short name.
+pub const DEFAULT_PRICE_ENDPOINT: &str = "https://api.coingecko.com/api/v3/coins/grin/market_chart?vs_currency={currency}&days=11430&interval=daily";
+
+/// Seconds to wait for a response
+const PRICE_REQUEST_TIMEOUT: u64 = 30;
+
+/// Prices from an endpoint answering like CoinGecko's `market_chart`
+pub struct CoinGeckoProvider {
+	endpoint: String,
+}
+
+impl CoinGeckoProvider {
+	/// Provider for `endpoint`, or CoinGecko itself if none is given
+	pub fn new(endpoint: Option<String>) -> Self {
+		CoinGeckoProvider {
+			endpoint: endpoint
+				.filter(|e| !e.trim().is_empty())
+				.unwrap_or_else(|| DEFAULT_PRICE_ENDPOINT.to_owned()),
+		}
+	}
+}
+
+impl PriceProvider for CoinGeckoProvider {
+	fn fetch_history(&self, currency: Currency) -> PriceFuture<PriceHistory> {
+		let url = self.endpoint.replace("{currency}", &currency.shortname());
+		async move {
+			let mut resp = request_async(
+				&url,
+				vec![("ACCEPT", "application/json")],
+				Some(PRICE_REQUEST_TIMEOUT),
+			)
+			.await?;
+
+			let status = resp.status();
+			if status == StatusCode::TOO_MANY_REQUESTS {
+				let retry_after = resp
+					.headers()
+					.get(RETRY_AFTER)
+					.and_then(|v| v.to_str().ok())
+					.and_then(|v| v.trim().parse().ok());
+				return Err(PriceError::RateLimited { retry_after });
+			}
+			if !status.is_success() {
+				return Err(DownloadError::InvalidStatusCode { code: status, url }.into());
+			}
+
+			let body = resp.text().await.map_err(DownloadError::from)?;
+			parse_market_chart(&body)
+		}
+		.boxed()
+	}
+}
@@ -0,0 +1,73 @@
+/// `PriceProvider` answering with canned responses, for exercising price handling without
+/// a network.
+use futures::future::{self, FutureExt};
+use std::collections::HashMap;
+use std::sync::{Mutex, MutexGuard};
+
+use super::{parse_market_chart, PriceFuture, PriceHistory, PriceProvider};
+use crate::config::Currency;
+use crate::error::PriceError;
+
+/// `market_chart` response with USD prices for 1-3 March 2023
+pub const FIXTURE_USD_MARKET_CHART: &str = r#"{
+	"prices": [
+		[1677628800000, 0.0610],
+		[1677715200000, 0.0625],
+		[1677801600000, 0.0598]
+	],
+	"market_caps": [],
+	"total_volumes": []
+}"#;
+
+#[derive(Default)]
+pub struct FixtureState {
+	/// Response bodies per currency, in `market_chart` format
+	pub responses: HashMap<Currency, String>,
+	/// While set, fetches fail as rate limited with this `Retry-After`
+	pub rate_limited: Option<Option<u64>>,
+	pub fetches: usize,
+}
+
+#[derive(Default)]
+pub struct FixturePriceProvider {
+	state: Mutex<FixtureState>,
+}
+
+impl FixturePriceProvider {
+	pub fn new() -> Self {
+		Default::default()
+	}
+
+	pub fn state(&self) -> MutexGuard<FixtureState> {
+		self.state.lock().unwrap()
+	}
+
+	pub fn set_response(&self, currency: Currency, body: &str) {
+		self.state().responses.insert(currency, body.to_owned());
+	}
+
+	/// Rate limit fetches, asking for the given number of seconds in between, or stop doing so
+	pub fn set_rate_limited(&self, retry_after: Option<u64>) {
+		self.state().rate_limited = retry_after.map(Some);
+	}
+
+	/// Fetches made so far
+	pub fn fetches(&self) -> usize {
+		self.state().fetches
+	}
+}
+
+impl PriceProvider for FixturePriceProvider {
+	fn fetch_history(&self, currency: Currency) -> PriceFuture<PriceHistory> {
+		let mut s = self.state();
+		s.fetches += 1;
+		let res = match (s.rate_limited, s.responses.get(&currency)) {
+			(Some(retry_after), _) => Err(PriceError::RateLimited { retry_after }),
+			(None, Some(body)) => parse_market_chart(body),
+			(None, None) => Err(PriceError::Unavailable {
+				currency: currency.shortname(),
+			}),
+		};
+		future::ready(res).boxed()
+	}
+}
@@ -0,0 +1,261 @@
+//! Historical prices of grin in the configured currency
+//!
+//! Prices come from a `PriceProvider`, and are kept in a `PriceCache` on disk so they can be shown
+//! offline and straight after a restart. `PriceSchedule` decides when they're fetched again,
+//! backing off while the provider fails or rate limits.
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use futures::future::BoxFuture;
+use serde::Deserialize;
+use std::collections::HashMap;
+
+use crate::config::{Currency, NumberFormat};
+use crate::error::PriceError;
+use crate::GRIN_BASE;
+
+mod cache;
+mod coingecko;
+#[cfg(any(test, feature = "test-utils"))]
+pub mod fixture;
+
+pub use cache::{CachedPrices, PriceCache};
+pub use coingecko::{CoinGeckoProvider, DEFAULT_PRICE_ENDPOINT};
+
+/// Daily prices of one grin
+pub type PriceHistory = HashMap<DateTime<Utc>, f64>;
+
+pub type PriceFuture<T> = BoxFuture<'static, Result<T, PriceError>>;
+
+/// Source of grin prices
+pub trait PriceProvider: Send + Sync {
+	/// Daily prices of one grin in `currency`
+	fn fetch_history(&self, currency: Currency) -> PriceFuture<PriceHistory>;
+}
+
+/// How long fetched prices are used before they're fetched again
+pub const PRICE_REFRESH_SECS: i64 = 10 * 60;
+
+/// Wait after the first failure in a row, doubled with every further one
+const MIN_BACKOFF_SECS: i64 = 60;
+
+/// Longest wait after failures
+const MAX_BACKOFF_SECS: i64 = 60 * 60;
+
+/// Parse a CoinGecko `market_chart` response, as `{"prices": [[millis, price], ...]}`
+pub fn parse_market_chart(body: &str) -> Result<PriceHistory, PriceError> {
+	#[derive(Deserialize)]
+	struct MarketChart {
+		prices: Vec<(i64, f64)>,
+	}
+
+	let chart = serde_json::from_str::<MarketChart>(body)?;
+	Ok(chart
+		.prices
+		.into_iter()
+		.filter_map(|(millis, price)| {
+			Utc.timestamp_millis_opt(millis)
+				.single()
+				.map(|t| (t, price))
+		})
+		.collect())
+}
+
+/// When prices are due to be fetched
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct PriceSchedule {
+	in_flight: bool,
+	/// Failures in a row
+	failures: u32,
+	/// No fetches before this, after a failure
+	retry_at: Option<DateTime<Utc>>,
+}
+
+impl PriceSchedule {
+	/// Whether prices in `currency` should be fetched now. Grin is never fetched.
+	pub fn is_due(&self, cache: &PriceCache, currency: Currency, now: DateTime<Utc>) -> bool {
+		if self.in_flight || currency == Currency::GRIN {
+			return false;
+		}
+		if self.retry_at.map_or(false, |at| now < at) {
+			return false;
+		}
+		cache.get(currency).map_or(true, |c| {
+			now - c.fetched >= Duration::seconds(PRICE_REFRESH_SECS)
+		})
+	}
+
+	pub fn started(&mut self) {
+		self.in_flight = true;
+	}
+
+	pub fn succeeded(&mut self) {
+		*self = Default::default();
+	}
+
+	/// Back off after a failed fetch, for at least as long as a rate limiting provider asked
+	pub fn failed(&mut self, err: &PriceError, now: DateTime<Utc>) {
+		self.in_flight = false;
+		self.failures += 1;
+		let backoff = MIN_BACKOFF_SECS
+			.saturating_mul(1 << self.failures.min(16).saturating_sub(1))
+			.min(MAX_BACKOFF_SECS);
+		let retry_after = match err {
+			PriceError::RateLimited {
+				retry_after: Some(secs),
+			} => *secs as i64,
+			_ => 0,
+		};
+		self.retry_at = Some(now + Duration::seconds(backoff.max(retry_after)));
+	}
+
+	/// When fetching is allowed again, while backing off
+	pub fn retry_at(&self) -> Option<DateTime<Utc>> {
+		self.retry_at
+	}
+}
+
+/// Price of one grin at a point in time, for showing and entering amounts in another currency
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PriceQuote {
+	pub currency: Currency,
+	pub price: f64,
+	pub time: DateTime<Utc>,
+}
+
+impl PriceQuote {
+	/// Most recent usable price in `history`. There's never a quote for grin itself.
+	pub fn latest(history: &PriceHistory, currency: Currency) -> Option<Self> {
+		if currency == Currency::GRIN {
+			return None;
+		}
+		history
+			.iter()
+			.filter(|(_, price)| price.is_finite() && **price > 0.0)
+			.max_by_key(|(time, _)| **time)
+			.map(|(time, price)| PriceQuote {
+				currency,
+				price: *price,
+				time: *time,
+			})
+	}
+
+	/// Value of `amount` nanogrin
+	pub fn value(&self, amount: u64) -> f64 {
+		amount as f64 / GRIN_BASE as f64 * self.price
+	}
+
+	/// Value of `amount` nanogrin as text, e.g. `$1.2500`
+	pub fn format_value(&self, amount: u64, format: &NumberFormat) -> String {
+		self.currency.format_value(self.value(amount), format)
+	}
+
+	/// Nanogrin worth `value`, or `None` unless that's a positive amount
+	pub fn to_grin(&self, value: f64) -> Option<u64> {
+		let amount = (value / self.price * GRIN_BASE as f64).round();
+		if amount.is_finite() && amount >= 1.0 && amount <= u64::MAX as f64 {
+			Some(amount as u64)
+		} else {
+			None
+		}
+	}
+
+	/// Nanogrin worth a value entered as text in `format`, with or without the currency's symbol
+	pub fn parse(&self, value: &str, format: &NumberFormat) -> Option<u64> {
+		let value = value.trim();
+		let symbol = self.currency.symbol();
+		let value = value
+			.strip_prefix(symbol.as_str())
+			.or_else(|| value.strip_suffix(symbol.as_str()))
+			.unwrap_or(value);
+		format.parse(value).and_then(|v| self.to_grin(v))
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::fixture::{FixturePriceProvider, FIXTURE_USD_MARKET_CHART};
+	use super::*;
+
+	#[test]
+	fn test_prices_cached_and_fetches_backed_off() {
+		let provider = FixturePriceProvider::new();
+		provider.set_response(Currency::USD, FIXTURE_USD_MARKET_CHART);
+		let now = Utc.with_ymd_and_hms(2023, 3, 5, 12, 0, 0).unwrap();
+		let mut cache = PriceCache::default();
+		let mut schedule = PriceSchedule::default();
+
+		assert!(!schedule.is_due(&cache, Currency::GRIN, now));
+		assert!(schedule.is_due(&cache, Currency::USD, now));
+		schedule.started();
+		assert!(!schedule.is_due(&cache, Currency::USD, now));
+
+		let history = futures::executor::block_on(provider.fetch_history(Currency::USD)).unwrap();
+		let day = Utc.with_ymd_and_hms(2023, 3, 2, 0, 0, 0).unwrap();
+		assert_eq!(history.len(), 3);
+		assert_eq!(history.get(&day), Some(&0.0625));
+		schedule.succeeded();
+		cache.insert(Currency::USD, &history, now);
+
+		// Cached prices survive a round trip to disk, and aren't due again until they're stale
+		let yaml = serde_yaml::to_string(&cache).unwrap();
+		let cache = serde_yaml::from_str::<PriceCache>(&yaml).unwrap();
+		assert_eq!(cache.history(Currency::USD), history);
+		assert!(cache.history(Currency::BTC).is_empty());
+		assert!(!schedule.is_due(&cache, Currency::USD, now + Duration::minutes(5)));
+		let stale = now + Duration::seconds(PRICE_REFRESH_SECS);
+		assert!(schedule.is_due(&cache, Currency::USD, stale));
+
+		// Failures back off exponentially, or as long as the provider asks
+		provider.set_rate_limited(Some(300));
+		for wait in [300, 300, 300, 480, 960] {
+			schedule.started();
+			let err =
+				futures::executor::block_on(provider.fetch_history(Currency::USD)).unwrap_err();
+			schedule.failed(&err, stale);
+			assert_eq!(schedule.retry_at(), Some(stale + Duration::seconds(wait)));
+			assert!(!schedule.is_due(&cache, Currency::USD, stale));
+		}
+		provider.set_rate_limited(None);
+		for _ in 0..10 {
+			schedule.failed(
+				&PriceError::Unavailable {
+					currency: "usd".into(),
+				},
+				stale,
+			);
+		}
+		let max_wait = stale + Duration::seconds(MAX_BACKOFF_SECS);
+		assert_eq!(schedule.retry_at(), Some(max_wait));
+		assert!(schedule.is_due(&cache, Currency::USD, max_wait));
+		assert_eq!(provider.fetches(), 6);
+
+		assert!(matches!(
+			futures::executor::block_on(provider.fetch_history(Currency::BTC)),
+			Err(PriceError::Unavailable { .. })
+		));
+	}
+
+	#[test]
+	fn test_latest_quote_converts_both_ways() {
+		let history = parse_market_chart(FIXTURE_USD_MARKET_CHART).unwrap();
+		assert_eq!(PriceQuote::latest(&history, Currency::GRIN), None);
+		assert_eq!(
+			PriceQuote::latest(&PriceHistory::new(), Currency::USD),
+			None
+		);
+
+		let quote = PriceQuote::latest(&history, Currency::USD).unwrap();
+		assert_eq!(
+			quote.time,
+			Utc.with_ymd_and_hms(2023, 3, 3, 0, 0, 0).unwrap()
+		);
+		assert_eq!(quote.price, 0.0598);
+		let format = NumberFormat::default();
+		assert_eq!(quote.format_value(100 * GRIN_BASE, &format), "$5.9800");
+
+		assert_eq!(quote.parse("5.98", &format), Some(100 * GRIN_BASE));
+		assert_eq!(quote.parse(" $5.98 ", &format), Some(100 * GRIN_BASE));
+		for invalid in ["", "abc", "0", "-1", "NaN", "inf"] {
+			assert_eq!(quote.parse(invalid, &format), None, "{}", invalid);
+		}
+	}
+}
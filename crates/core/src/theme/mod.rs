@@ -18,6 +18,7 @@ pub mod checkbox;
 pub mod container;
 pub mod modal;
 pub mod picklist;
+pub mod progress_bar;
 pub mod radio;
 pub mod scrollable;
 pub mod table_header;
@@ -32,6 +33,7 @@ pub use checkbox::CheckboxStyle;
 pub use container::ContainerStyle;
 pub use modal::ModalStyle;
 pub use picklist::PickListStyle;
+pub use progress_bar::ProgressBarStyle;
 pub use radio::RadioStyle;
 pub use scrollable::ScrollableStyle;
 pub use table_header::TableHeaderStyle;
@@ -64,6 +66,7 @@ pub type Button<'a, Message> = iced::widget::Button<'a, Message, Theme, Renderer
 pub type Scrollable<'a, Message> = iced::widget::Scrollable<'a, Message, Theme, Renderer>;
 pub type PickList<'a, T, L, V, Message> =
 	iced::widget::PickList<'a, T, L, V, Message, Theme, Renderer>;
+pub type ProgressBar = iced::widget::ProgressBar<Theme>;
 pub type Radio<Message> = iced::widget::Radio<Message, Theme, Renderer>;
 pub type Card<'a, Message> = iced_aw::Card<'a, Message, Theme, Renderer>;
 pub type Modal<'a, Message, Theme, Renderer> = iced_aw::modal::Modal<'a, Message, Theme, Renderer>;
@@ -0,0 +1,25 @@
+use super::Theme;
+use iced::widget::progress_bar;
+use iced::Background;
+
+#[derive(Debug, Clone, Copy, Default)]
+pub enum ProgressBarStyle {
+	#[default]
+	Default,
+	Normal,
+}
+
+impl progress_bar::StyleSheet for Theme {
+	type Style = ProgressBarStyle;
+
+	fn appearance(&self, style: &Self::Style) -> progress_bar::Appearance {
+		match style {
+			// iced has no default appearance for a progress bar, so both use the palette
+			ProgressBarStyle::Default | ProgressBarStyle::Normal => progress_bar::Appearance {
+				background: Background::Color(self.palette.base.foreground),
+				bar: Background::Color(self.palette.normal.primary),
+				border_radius: 2.0.into(),
+			},
+		}
+	}
+}
@@ -0,0 +1,541 @@
+//! Accounting report valuing confirmed transactions in the configured currency
+//!
+//! Each confirmed transaction is valued at the price of grin on the day it was confirmed, then
+//! summed up per period. Transactions on days without a known price are still counted, but are
+//! left out of the values and reported as unpriced so they can be looked at by hand.
+use chrono::{DateTime, Datelike, Duration, NaiveDate, Utc};
+use grin_wallet_libwallet::{TxLogEntry, TxLogEntryType};
+use serde::Serialize;
+
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::path::Path;
+
+use super::tx_export::{csv_field, TxExportFormat};
+use crate::config::{Currency, TxNotes};
+use crate::error::GrinWalletInterfaceError;
+use crate::node::amount_to_hr_string;
+use crate::GRIN_BASE;
+
+/// Column names of the transaction table of a CSV report
+pub const ACCOUNTING_ENTRY_COLUMNS: [&str; 13] = [
+	"date",
+	"id",
+	"type",
+	"shared_tx_id",
+	"label",
+	"received",
+	"sent",
+	"fee",
+	"currency",
+	"price",
+	"received_value",
+	"sent_value",
+	"fee_value",
+];
+
+/// Column names of the totals table of a CSV report
+pub const ACCOUNTING_TOTAL_COLUMNS: [&str; 10] = [
+	"period",
+	"transactions",
+	"received",
+	"sent",
+	"fees",
+	"currency",
+	"received_value",
+	"sent_value",
+	"fees_value",
+	"unpriced",
+];
+
+/// How many days back a missing daily price may be taken from. Anything older is too far off
+/// to value a transaction with.
+const MAX_PRICE_AGE_DAYS: i64 = 2;
+
+/// Length of the periods transactions are totalled over
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AccountingPeriod {
+	#[default]
+	Month,
+	Quarter,
+	Year,
+}
+
+impl AccountingPeriod {
+	pub const ALL: [AccountingPeriod; 3] = [
+		AccountingPeriod::Month,
+		AccountingPeriod::Quarter,
+		AccountingPeriod::Year,
+	];
+
+	/// Name of the period a date falls in, e.g. `2023-03`, `2023-Q1` or `2023`. Names sort in
+	/// date order.
+	pub fn name(&self, date: NaiveDate) -> String {
+		match self {
+			AccountingPeriod::Month => format!("{}-{:02}", date.year(), date.month()),
+			AccountingPeriod::Quarter => format!("{}-Q{}", date.year(), date.month0() / 3 + 1),
+			AccountingPeriod::Year => date.year().to_string(),
+		}
+	}
+}
+
+/// A confirmed transaction with the grin it moved and the price it's valued at
+#[derive(Debug, Clone, PartialEq)]
+pub struct AccountingEntry {
+	pub id: u32,
+	/// Confirmation date
+	pub date: NaiveDate,
+	pub tx_type: TxLogEntryType,
+	pub shared_tx_id: Option<String>,
+	pub label: Option<String>,
+	pub received: u64,
+	/// Sent amount, without the fee
+	pub sent: u64,
+	/// Fee paid by this wallet
+	pub fee: u64,
+	/// Price of one grin on the confirmation date, if known
+	pub price: Option<f64>,
+}
+
+impl AccountingEntry {
+	/// Value of an amount of this transaction at its price
+	pub fn value(&self, amount: u64) -> Option<f64> {
+		self.price
+			.map(|price| amount as f64 / GRIN_BASE as f64 * price)
+	}
+}
+
+/// Totals of the transactions confirmed within one period
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct AccountingTotals {
+	pub period: String,
+	pub count: usize,
+	pub received: u64,
+	pub sent: u64,
+	pub fees: u64,
+	pub received_value: f64,
+	pub sent_value: f64,
+	pub fees_value: f64,
+	/// Transactions without a price, which aren't included in the values
+	pub unpriced: usize,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AccountingReport {
+	pub currency: Currency,
+	pub period: AccountingPeriod,
+	/// Confirmed transactions, oldest first
+	pub entries: Vec<AccountingEntry>,
+	/// Totals per period, oldest first. Periods without transactions are left out.
+	pub totals: Vec<AccountingTotals>,
+}
+
+impl AccountingReport {
+	/// Value confirmed transactions using daily prices in `currency`. A transaction confirmed on a
+	/// day without a price uses the closest earlier one, if it's at most `MAX_PRICE_AGE_DAYS` old.
+	/// Cancelled and unconfirmed transactions are left out.
+	pub fn new<'a>(
+		txs: impl IntoIterator<Item = &'a TxLogEntry>,
+		prices: &HashMap<DateTime<Utc>, f64>,
+		currency: Currency,
+		period: AccountingPeriod,
+		notes: &TxNotes,
+	) -> Self {
+		let daily_prices: BTreeMap<NaiveDate, f64> = prices
+			.iter()
+			.map(|(time, price)| (time.date_naive(), *price))
+			.collect();
+		let price_on = |date: NaiveDate| {
+			if currency == Currency::GRIN {
+				Some(1.0)
+			} else {
+				let oldest = date - Duration::days(MAX_PRICE_AGE_DAYS);
+				daily_prices
+					.range(oldest..=date)
+					.next_back()
+					.map(|(_, p)| *p)
+			}
+		};
+
+		let mut entries: Vec<AccountingEntry> = txs
+			.into_iter()
+			.filter(|tx| tx.confirmed)
+			.filter_map(|tx| {
+				let net_credit = tx.amount_credited.saturating_sub(tx.amount_debited);
+				let net_debit = tx.amount_debited.saturating_sub(tx.amount_credited);
+				let tx_fee = tx.fee.map(|f| f.fee()).unwrap_or(0);
+				let (received, sent, fee) = match tx.tx_type {
+					TxLogEntryType::ConfirmedCoinbase | TxLogEntryType::TxReceived => {
+						(net_credit, 0, 0)
+					}
+					TxLogEntryType::TxSent => (0, net_debit.saturating_sub(tx_fee), tx_fee),
+					_ => return None,
+				};
+				let date = tx.confirmation_ts.unwrap_or(tx.creation_ts).date_naive();
+				Some(AccountingEntry {
+					id: tx.id,
+					date,
+					tx_type: tx.tx_type.clone(),
+					shared_tx_id: tx.tx_slate_id.map(|id| id.to_string()),
+					label: notes.label(tx.tx_slate_id.as_ref()).map(str::to_owned),
+					received,
+					sent,
+					fee,
+					price: price_on(date),
+				})
+			})
+			.collect();
+		entries.sort_by(|a, b| (a.date, a.id).cmp(&(b.date, b.id)));
+
+		let mut totals: Vec<AccountingTotals> = vec![];
+		for entry in entries.iter() {
+			let name = period.name(entry.date);
+			if totals.last().map_or(true, |t| t.period != name) {
+				totals.push(AccountingTotals {
+					period: name,
+					..Default::default()
+				});
+			}
+			let t = totals.last_mut().unwrap();
+			t.count += 1;
+			t.received += entry.received;
+			t.sent += entry.sent;
+			t.fees += entry.fee;
+			if entry.price.is_some() {
+				t.received_value += entry.value(entry.received).unwrap_or(0.0);
+				t.sent_value += entry.value(entry.sent).unwrap_or(0.0);
+				t.fees_value += entry.value(entry.fee).unwrap_or(0.0);
+			} else {
+				t.unpriced += 1;
+			}
+		}
+
+		AccountingReport {
+			currency,
+			period,
+			entries,
+			totals,
+		}
+	}
+
+	/// Value in the report's currency as written to exports, with the currency's precision
+	pub fn format_value(&self, value: f64) -> String {
+		format!("{:.*}", self.currency.precision(), value)
+	}
+
+	fn entry_record(&self, entry: &AccountingEntry) -> AccountingEntryRecord {
+		let value = |amount: u64| {
+			entry
+				.value(amount)
+				.map(|v| self.format_value(v))
+				.unwrap_or_default()
+		};
+		AccountingEntryRecord {
+			date: entry.date.to_string(),
+			id: entry.id,
+			tx_type: entry.tx_type.to_string().replace('\n', " "),
+			shared_tx_id: entry.shared_tx_id.clone(),
+			label: entry.label.clone(),
+			received: amount_to_hr_string(entry.received, true),
+			sent: amount_to_hr_string(entry.sent, true),
+			fee: amount_to_hr_string(entry.fee, true),
+			currency: self.currency.code(),
+			price: entry.price.map(|p| p.to_string()),
+			received_value: value(entry.received),
+			sent_value: value(entry.sent),
+			fee_value: value(entry.fee),
+		}
+	}
+
+	fn totals_record(&self, totals: &AccountingTotals) -> AccountingTotalsRecord {
+		AccountingTotalsRecord {
+			period: totals.period.clone(),
+			transactions: totals.count,
+			received: amount_to_hr_string(totals.received, true),
+			sent: amount_to_hr_string(totals.sent, true),
+			fees: amount_to_hr_string(totals.fees, true),
+			currency: self.currency.code(),
+			received_value: self.format_value(totals.received_value),
+			sent_value: self.format_value(totals.sent_value),
+			fees_value: self.format_value(totals.fees_value),
+			unpriced: totals.unpriced,
+		}
+	}
+
+	/// Transactions followed by the totals, as two tables separated by an empty line
+	pub fn to_csv(&self) -> String {
+		let mut csv = ACCOUNTING_ENTRY_COLUMNS.join(",");
+		csv.push('\n');
+		for entry in self.entries.iter() {
+			let fields: Vec<String> = self
+				.entry_record(entry)
+				.fields()
+				.iter()
+				.map(|f| csv_field(f))
+				.collect();
+			csv.push_str(&fields.join(","));
+			csv.push('\n');
+		}
+
+		csv.push('\n');
+		csv.push_str(&ACCOUNTING_TOTAL_COLUMNS.join(","));
+		csv.push('\n');
+		for totals in self.totals.iter() {
+			let fields: Vec<String> = self
+				.totals_record(totals)
+				.fields()
+				.iter()
+				.map(|f| csv_field(f))
+				.collect();
+			csv.push_str(&fields.join(","));
+			csv.push('\n');
+		}
+		csv
+	}
+
+	pub fn to_json(&self) -> Result<String, GrinWalletInterfaceError> {
+		let record = AccountingReportRecord {
+			currency: self.currency.code(),
+			period: format!("{:?}", self.period).to_lowercase(),
+			transactions: self.entries.iter().map(|e| self.entry_record(e)).collect(),
+			totals: self.totals.iter().map(|t| self.totals_record(t)).collect(),
+		};
+		serde_json::to_string_pretty(&record).map_err(GrinWalletInterfaceError::TxExportSerialize)
+	}
+
+	/// Write the report to a file in the given format
+	pub fn write(
+		&self,
+		path: &Path,
+		format: TxExportFormat,
+	) -> Result<(), GrinWalletInterfaceError> {
+		let data = match format {
+			TxExportFormat::Csv => self.to_csv(),
+			TxExportFormat::Json => self.to_json()?,
+		};
+		fs::write(path, data).map_err(|source| GrinWalletInterfaceError::TxExportIo {
+			file: path.display().to_string(),
+			source,
+		})
+	}
+}
+
+#[derive(Serialize)]
+struct AccountingReportRecord {
+	currency: String,
+	period: String,
+	transactions: Vec<AccountingEntryRecord>,
+	totals: Vec<AccountingTotalsRecord>,
+}
+
+/// Fields are declared in the order of `ACCOUNTING_ENTRY_COLUMNS`
+#[derive(Serialize)]
+struct AccountingEntryRecord {
+	date: String,
+	id: u32,
+	#[serde(rename = "type")]
+	tx_type: String,
+	shared_tx_id: Option<String>,
+	label: Option<String>,
+	received: String,
+	sent: String,
+	fee: String,
+	currency: String,
+	price: Option<String>,
+	received_value: String,
+	sent_value: String,
+	fee_value: String,
+}
+
+impl AccountingEntryRecord {
+	fn fields(&self) -> [String; 13] {
+		let opt = |o: &Option<String>| o.clone().unwrap_or_default();
+		[
+			self.date.clone(),
+			self.id.to_string(),
+			self.tx_type.clone(),
+			opt(&self.shared_tx_id),
+			opt(&self.label),
+			self.received.clone(),
+			self.sent.clone(),
+			self.fee.clone(),
+			self.currency.clone(),
+			opt(&self.price),
+			self.received_value.clone(),
+			self.sent_value.clone(),
+			self.fee_value.clone(),
+		]
+	}
+}
+
+/// Fields are declared in the order of `ACCOUNTING_TOTAL_COLUMNS`
+#[derive(Serialize)]
+struct AccountingTotalsRecord {
+	period: String,
+	transactions: usize,
+	received: String,
+	sent: String,
+	fees: String,
+	currency: String,
+	received_value: String,
+	sent_value: String,
+	fees_value: String,
+	unpriced: usize,
+}
+
+impl AccountingTotalsRecord {
+	fn fields(&self) -> [String; 10] {
+		[
+			self.period.clone(),
+			self.transactions.to_string(),
+			self.received.clone(),
+			self.sent.clone(),
+			self.fees.clone(),
+			self.currency.clone(),
+			self.received_value.clone(),
+			self.sent_value.clone(),
+			self.fees_value.clone(),
+			self.unpriced.to_string(),
+		]
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use chrono::TimeZone;
+	use grin_core::core::FeeFields;
+	use grin_keychain::Identifier;
+
+	fn tx(id: u32, month: u32, day: u32, tx_type: TxLogEntryType, amount: u64) -> TxLogEntry {
+		let mut tx = TxLogEntry::new(Identifier::zero(), tx_type.clone(), id);
+		tx.creation_ts = Utc.with_ymd_and_hms(2023, month, 1, 12, 0, 0).unwrap();
+		tx.confirmation_ts = Some(Utc.with_ymd_and_hms(2023, month, day, 12, 0, 0).unwrap());
+		tx.confirmed = true;
+		match tx_type {
+			TxLogEntryType::TxSent => {
+				tx.fee = Some(FeeFields::new(0, 100_000_000).unwrap());
+				tx.amount_debited = amount + 100_000_000;
+			}
+			_ => tx.amount_credited = amount,
+		}
+		tx
+	}
+
+	fn price(month: u32, day: u32, price: f64) -> (DateTime<Utc>, f64) {
+		(
+			Utc.with_ymd_and_hms(2023, month, day, 0, 0, 0).unwrap(),
+			price,
+		)
+	}
+
+	#[test]
+	fn test_accounting_report_values_and_totals() {
+		let mut cancelled = tx(5, 3, 12, TxLogEntryType::TxSentCancelled, 1_000_000_000);
+		cancelled.confirmed = false;
+		let txs = vec![
+			tx(1, 3, 10, TxLogEntryType::TxReceived, 2_000_000_000),
+			// no price that day, valued at the price of the day before
+			tx(2, 3, 21, TxLogEntryType::TxSent, 1_000_000_000),
+			tx(3, 4, 2, TxLogEntryType::TxReceived, 1_000_000_000),
+			// before any price is known
+			tx(4, 1, 5, TxLogEntryType::TxReceived, 1_000_000_000),
+			cancelled,
+		];
+		let prices: HashMap<DateTime<Utc>, f64> =
+			vec![price(3, 10, 0.5), price(3, 20, 2.0), price(4, 2, 1.5)]
+				.into_iter()
+				.collect();
+
+		let report = AccountingReport::new(
+			&txs,
+			&prices,
+			Currency::USD,
+			AccountingPeriod::Month,
+			&TxNotes::default(),
+		);
+		assert_eq!(
+			report.entries.iter().map(|e| e.id).collect::<Vec<_>>(),
+			vec![4, 1, 2, 3]
+		);
+		assert_eq!(report.entries[2].price, Some(2.0));
+		assert_eq!(
+			report
+				.totals
+				.iter()
+				.map(|t| t.period.as_str())
+				.collect::<Vec<_>>(),
+			vec!["2023-01", "2023-03", "2023-04"]
+		);
+		assert_eq!(report.totals[0].unpriced, 1);
+		assert_eq!(report.totals[0].received_value, 0.0);
+
+		let march = &report.totals[1];
+		assert_eq!(march.count, 2);
+		assert_eq!(march.received, 2_000_000_000);
+		assert_eq!(march.sent, 1_000_000_000);
+		assert_eq!(march.fees, 100_000_000);
+		assert_eq!(report.format_value(march.received_value), "1.0000");
+		assert_eq!(report.format_value(march.sent_value), "2.0000");
+		assert_eq!(report.format_value(march.fees_value), "0.2000");
+
+		let quarters = AccountingReport::new(
+			&txs,
+			&prices,
+			Currency::USD,
+			AccountingPeriod::Quarter,
+			&TxNotes::default(),
+		);
+		assert_eq!(
+			quarters
+				.totals
+				.iter()
+				.map(|t| (t.period.as_str(), t.count))
+				.collect::<Vec<_>>(),
+			vec![("2023-Q1", 3), ("2023-Q2", 1)]
+		);
+
+		let csv = report.to_csv();
+		let tables: Vec<&str> = csv.split("\n\n").collect();
+		assert_eq!(tables.len(), 2);
+		assert_eq!(tables[0].lines().count(), 5);
+		assert_eq!(
+			tables[1].lines().next(),
+			Some(ACCOUNTING_TOTAL_COLUMNS.join(",").as_str())
+		);
+		assert!(tables[1].contains("2023-03,2,2.0,1.0,0.1,USD,1.0000,2.0000,0.2000,0"));
+
+		let json = report.to_json().unwrap();
+		assert!(json.contains("\"period\": \"month\""));
+		assert!(json.contains("\"received_value\": \"1.0000\""));
+	}
+
+	#[test]
+	fn test_accounting_price_gap() {
+		let txs = vec![
+			tx(1, 3, 12, TxLogEntryType::TxReceived, 1_000_000_000),
+			// in a gap in the price history, too long after the last known price
+			tx(2, 3, 15, TxLogEntryType::TxReceived, 1_000_000_000),
+			tx(3, 3, 20, TxLogEntryType::TxReceived, 1_000_000_000),
+		];
+		let prices: HashMap<DateTime<Utc>, f64> = vec![price(3, 10, 0.5), price(3, 20, 2.0)]
+			.into_iter()
+			.collect();
+
+		let report = AccountingReport::new(
+			&txs,
+			&prices,
+			Currency::USD,
+			AccountingPeriod::Month,
+			&TxNotes::default(),
+		);
+		assert_eq!(
+			report.entries.iter().map(|e| e.price).collect::<Vec<_>>(),
+			vec![Some(0.5), None, Some(2.0)]
+		);
+		let march = &report.totals[0];
+		assert_eq!(march.count, 3);
+		assert_eq!(march.unpriced, 1);
+		assert_eq!(report.format_value(march.received_value), "2.5000");
+	}
+}
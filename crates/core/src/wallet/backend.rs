@@ -0,0 +1,428 @@
+/// Wallet operations used by the GUI, abstracted so that screens don't depend on
+/// how the wallet is reached (local libwallet instance, remote owner API, or a fake in tests)
+use futures::future::BoxFuture;
+use std::path::PathBuf;
+use std::sync::{Arc, RwLock};
+
+use super::{
+	global, AcctPathMapping, ContractNewArgsAPI, ContractRevokeArgsAPI, ContractSetupArgsAPI,
+	FeeEstimate, InitTxArgs, InvoiceProof, IssueInvoiceTxArgs, OutputCommitMapping, PaymentProof,
+	RetrieveTxQueryArgs, ScanSummary, Slate, Slatepack, SlatepackAddress, StatusMessage,
+	TxLogEntry, WalletInfo, WalletInterface, WalletInterfaceHttpNodeClient, ZeroingString,
+};
+use crate::config::ForeignListenerConfig;
+use crate::error::GrinWalletInterfaceError;
+
+/// Future returned by asynchronous backend calls, suitable for `Command::perform`
+pub type WalletFuture<T> = BoxFuture<'static, Result<T, GrinWalletInterfaceError>>;
+
+pub trait WalletBackend: Send + Sync {
+	/// Whether a wallet is currently open
+	fn wallet_is_open(&self) -> bool;
+
+	fn open_wallet(
+		&self,
+		password: String,
+		top_level_directory: PathBuf,
+		chain_type: global::ChainTypes,
+	) -> WalletFuture<()>;
+
+	fn close_wallet(&self) -> WalletFuture<()>;
+
+	/// Change the wallet password, leaving the wallet open under the new one
+	fn change_password(&self, old_password: String, new_password: String) -> WalletFuture<()>;
+
+	/// Recovery phrase of the open wallet, after checking the password again
+	fn get_recovery_phrase(&self, password: String) -> WalletFuture<ZeroingString>;
+
+	/// Latest status message from the wallet's background updater
+	fn get_wallet_updater_status(&self) -> WalletFuture<Vec<StatusMessage>>;
+
+	/// Decode and decrypt a slatepack, along with the matching transaction if there is one
+	fn decrypt_slatepack(
+		&self,
+		slatepack: String,
+	) -> WalletFuture<(Slatepack, Slate, Option<TxLogEntry>)>;
+
+	/// Serve the foreign API over HTTP so senders can transact synchronously, returning the
+	/// address it's listening on
+	fn start_foreign_listener(
+		&self,
+		config: ForeignListenerConfig,
+	) -> Result<String, GrinWalletInterfaceError>;
+
+	fn stop_foreign_listener(&self);
+
+	/// Address the foreign listener is serving on, if it's running
+	fn foreign_listener_addr(&self) -> Option<String>;
+
+	fn get_wallet_info(&self, refresh_from_node: bool) -> WalletFuture<(bool, WalletInfo)>;
+
+	fn get_txs(
+		&self,
+		query_args: Option<RetrieveTxQueryArgs>,
+	) -> WalletFuture<(bool, Vec<TxLogEntry>)>;
+
+	fn get_outputs(
+		&self,
+		include_spent: bool,
+		refresh_from_node: bool,
+	) -> WalletFuture<(bool, Vec<OutputCommitMapping>)>;
+
+	fn get_slatepack_address(&self) -> WalletFuture<(String, SlatepackAddress)>;
+
+	/// Scan the chain to repair wallet contents, returning a before/after summary
+	fn scan(&self, start_height: Option<u64>, delete_unconfirmed: bool)
+		-> WalletFuture<ScanSummary>;
+
+	fn get_accounts(&self) -> WalletFuture<Vec<AcctPathMapping>>;
+
+	/// Label of the account info, transaction and send calls operate on
+	fn get_active_account(&self) -> WalletFuture<String>;
+
+	/// Create a new account, returning its label
+	fn create_account_path(&self, label: String) -> WalletFuture<String>;
+
+	/// Switch the active account, returning its label
+	fn set_active_account(&self, label: String) -> WalletFuture<String>;
+
+	/// Fee and inputs a send would use, without locking anything
+	fn estimate_fee(&self, init_args: InitTxArgs) -> WalletFuture<FeeEstimate>;
+
+	fn create_tx(
+		&self,
+		init_args: InitTxArgs,
+		dest_slatepack_address: String,
+	) -> WalletFuture<(Slate, String)>;
+
+	fn receive_tx_from_s1(
+		&self,
+		slate: Slate,
+		dest_slatepack_address: String,
+	) -> WalletFuture<(Slate, Option<String>)>;
+
+	fn finalize_from_s2(
+		&self,
+		slate: Slate,
+		send_to_chain: bool,
+	) -> WalletFuture<(Slate, Option<String>)>;
+
+	fn post_tx(&self, slate: Slate) -> WalletFuture<(Slate, Option<String>)>;
+
+	/// Invoice (I1) requesting payment from the owner of the destination address
+	fn issue_invoice_tx(
+		&self,
+		args: IssueInvoiceTxArgs,
+		dest_slatepack_address: String,
+	) -> WalletFuture<(Slate, String)>;
+
+	/// Pay an invoice, returning the I2 slate for the issuer
+	fn process_invoice_tx(
+		&self,
+		slate: Slate,
+		init_args: InitTxArgs,
+		dest_slatepack_address: String,
+	) -> WalletFuture<(Slate, Option<String>)>;
+
+	/// Finalize and post a paid invoice
+	fn finalize_invoice_tx(&self, slate: Slate) -> WalletFuture<(Slate, Option<String>)>;
+
+	fn cancel_tx(&self, uuid: uuid::Uuid) -> WalletFuture<uuid::Uuid>;
+
+	fn contract_new(
+		&self,
+		args: ContractNewArgsAPI,
+		dest_slatepack_address: String,
+	) -> WalletFuture<(Slate, String)>;
+
+	fn contract_sign(
+		&self,
+		slate: Slate,
+		args: ContractSetupArgsAPI,
+		dest_slatepack_address: String,
+		send_to_chain_if_ready: bool,
+	) -> WalletFuture<(Slate, Option<String>)>;
+
+	fn contract_self_send(&self, args: ContractNewArgsAPI) -> WalletFuture<(Slate, TxLogEntry)>;
+
+	fn contract_revoke(&self, args: ContractRevokeArgsAPI) -> WalletFuture<Option<Slate>>;
+
+	fn retrieve_payment_proof_invoice(&self, tx_id: Option<u32>) -> WalletFuture<InvoiceProof>;
+
+	fn verify_payment_proof_invoice(
+		&self,
+		recipient_address: String,
+		proof: InvoiceProof,
+	) -> WalletFuture<()>;
+
+	fn retrieve_payment_proof(&self, tx_id: Option<u32>) -> WalletFuture<PaymentProof>;
+
+	/// Whether the proof's sender and recipient addresses belong to this wallet
+	fn verify_payment_proof(&self, proof: PaymentProof) -> WalletFuture<(bool, bool)>;
+}
+
+/// The libwallet-backed interface the GUI runs with
+impl WalletBackend for Arc<RwLock<WalletInterfaceHttpNodeClient>> {
+	fn wallet_is_open(&self) -> bool {
+		self.read().map(|w| w.wallet_is_open()).unwrap_or(false)
+	}
+
+	fn open_wallet(
+		&self,
+		password: String,
+		top_level_directory: PathBuf,
+		chain_type: global::ChainTypes,
+	) -> WalletFuture<()> {
+		Box::pin(WalletInterface::open_wallet(
+			self.clone(),
+			password,
+			top_level_directory,
+			chain_type,
+		))
+	}
+
+	fn close_wallet(&self) -> WalletFuture<()> {
+		Box::pin(WalletInterface::close_wallet(self.clone()))
+	}
+
+	fn change_password(&self, old_password: String, new_password: String) -> WalletFuture<()> {
+		Box::pin(WalletInterface::change_password(
+			self.clone(),
+			old_password,
+			new_password,
+		))
+	}
+
+	fn get_recovery_phrase(&self, password: String) -> WalletFuture<ZeroingString> {
+		Box::pin(WalletInterface::get_recovery_phrase(self.clone(), password))
+	}
+
+	fn get_wallet_updater_status(&self) -> WalletFuture<Vec<StatusMessage>> {
+		Box::pin(WalletInterface::get_wallet_updater_status(self.clone()))
+	}
+
+	fn decrypt_slatepack(
+		&self,
+		slatepack: String,
+	) -> WalletFuture<(Slatepack, Slate, Option<TxLogEntry>)> {
+		Box::pin(WalletInterface::decrypt_slatepack(self.clone(), slatepack))
+	}
+
+	fn start_foreign_listener(
+		&self,
+		config: ForeignListenerConfig,
+	) -> Result<String, GrinWalletInterfaceError> {
+		WalletInterface::start_foreign_listener(self.clone(), config)
+	}
+
+	fn stop_foreign_listener(&self) {
+		WalletInterface::stop_foreign_listener(self.clone())
+	}
+
+	fn foreign_listener_addr(&self) -> Option<String> {
+		WalletInterface::foreign_listener_addr(self.clone())
+	}
+
+	fn get_wallet_info(&self, refresh_from_node: bool) -> WalletFuture<(bool, WalletInfo)> {
+		Box::pin(WalletInterface::get_wallet_info(
+			self.clone(),
+			refresh_from_node,
+		))
+	}
+
+	fn get_txs(
+		&self,
+		query_args: Option<RetrieveTxQueryArgs>,
+	) -> WalletFuture<(bool, Vec<TxLogEntry>)> {
+		Box::pin(WalletInterface::get_txs(self.clone(), query_args))
+	}
+
+	fn get_outputs(
+		&self,
+		include_spent: bool,
+		refresh_from_node: bool,
+	) -> WalletFuture<(bool, Vec<OutputCommitMapping>)> {
+		Box::pin(WalletInterface::get_outputs(
+			self.clone(),
+			include_spent,
+			refresh_from_node,
+		))
+	}
+
+	fn get_slatepack_address(&self) -> WalletFuture<(String, SlatepackAddress)> {
+		Box::pin(WalletInterface::get_slatepack_address(self.clone()))
+	}
+
+	fn scan(
+		&self,
+		start_height: Option<u64>,
+		delete_unconfirmed: bool,
+	) -> WalletFuture<ScanSummary> {
+		Box::pin(WalletInterface::scan(
+			self.clone(),
+			start_height,
+			delete_unconfirmed,
+		))
+	}
+
+	fn get_accounts(&self) -> WalletFuture<Vec<AcctPathMapping>> {
+		Box::pin(WalletInterface::get_accounts(self.clone()))
+	}
+
+	fn get_active_account(&self) -> WalletFuture<String> {
+		Box::pin(WalletInterface::get_active_account(self.clone()))
+	}
+
+	fn create_account_path(&self, label: String) -> WalletFuture<String> {
+		Box::pin(WalletInterface::create_account_path(self.clone(), label))
+	}
+
+	fn set_active_account(&self, label: String) -> WalletFuture<String> {
+		Box::pin(WalletInterface::set_active_account(self.clone(), label))
+	}
+
+	fn estimate_fee(&self, init_args: InitTxArgs) -> WalletFuture<FeeEstimate> {
+		Box::pin(WalletInterface::estimate_fee(self.clone(), init_args))
+	}
+
+	fn create_tx(
+		&self,
+		init_args: InitTxArgs,
+		dest_slatepack_address: String,
+	) -> WalletFuture<(Slate, String)> {
+		Box::pin(WalletInterface::create_tx(
+			self.clone(),
+			init_args,
+			dest_slatepack_address,
+		))
+	}
+
+	fn receive_tx_from_s1(
+		&self,
+		slate: Slate,
+		dest_slatepack_address: String,
+	) -> WalletFuture<(Slate, Option<String>)> {
+		Box::pin(WalletInterface::receive_tx_from_s1(
+			self.clone(),
+			slate,
+			dest_slatepack_address,
+		))
+	}
+
+	fn finalize_from_s2(
+		&self,
+		slate: Slate,
+		send_to_chain: bool,
+	) -> WalletFuture<(Slate, Option<String>)> {
+		Box::pin(WalletInterface::finalize_from_s2(
+			self.clone(),
+			slate,
+			send_to_chain,
+		))
+	}
+
+	fn post_tx(&self, slate: Slate) -> WalletFuture<(Slate, Option<String>)> {
+		Box::pin(WalletInterface::post_tx(self.clone(), slate))
+	}
+
+	fn issue_invoice_tx(
+		&self,
+		args: IssueInvoiceTxArgs,
+		dest_slatepack_address: String,
+	) -> WalletFuture<(Slate, String)> {
+		Box::pin(WalletInterface::issue_invoice_tx(
+			self.clone(),
+			args,
+			dest_slatepack_address,
+		))
+	}
+
+	fn process_invoice_tx(
+		&self,
+		slate: Slate,
+		init_args: InitTxArgs,
+		dest_slatepack_address: String,
+	) -> WalletFuture<(Slate, Option<String>)> {
+		Box::pin(WalletInterface::process_invoice_tx(
+			self.clone(),
+			slate,
+			init_args,
+			dest_slatepack_address,
+		))
+	}
+
+	fn finalize_invoice_tx(&self, slate: Slate) -> WalletFuture<(Slate, Option<String>)> {
+		Box::pin(WalletInterface::finalize_invoice_tx(self.clone(), slate))
+	}
+
+	fn cancel_tx(&self, uuid: uuid::Uuid) -> WalletFuture<uuid::Uuid> {
+		Box::pin(WalletInterface::cancel_tx(self.clone(), uuid))
+	}
+
+	fn contract_new(
+		&self,
+		args: ContractNewArgsAPI,
+		dest_slatepack_address: String,
+	) -> WalletFuture<(Slate, String)> {
+		Box::pin(WalletInterface::contract_new(
+			self.clone(),
+			args,
+			dest_slatepack_address,
+		))
+	}
+
+	fn contract_sign(
+		&self,
+		slate: Slate,
+		args: ContractSetupArgsAPI,
+		dest_slatepack_address: String,
+		send_to_chain_if_ready: bool,
+	) -> WalletFuture<(Slate, Option<String>)> {
+		Box::pin(WalletInterface::contract_sign(
+			self.clone(),
+			slate,
+			args,
+			dest_slatepack_address,
+			send_to_chain_if_ready,
+		))
+	}
+
+	fn contract_self_send(&self, args: ContractNewArgsAPI) -> WalletFuture<(Slate, TxLogEntry)> {
+		Box::pin(WalletInterface::contract_self_send(self.clone(), args))
+	}
+
+	fn contract_revoke(&self, args: ContractRevokeArgsAPI) -> WalletFuture<Option<Slate>> {
+		let w = self.clone();
+		Box::pin(async move { WalletInterface::contract_revoke(w, &args).await })
+	}
+
+	fn retrieve_payment_proof_invoice(&self, tx_id: Option<u32>) -> WalletFuture<InvoiceProof> {
+		Box::pin(WalletInterface::retrieve_payment_proof_invoice(
+			self.clone(),
+			tx_id,
+		))
+	}
+
+	fn verify_payment_proof_invoice(
+		&self,
+		recipient_address: String,
+		proof: InvoiceProof,
+	) -> WalletFuture<()> {
+		let w = self.clone();
+		Box::pin(async move {
+			WalletInterface::verify_payment_proof_invoice(w, &recipient_address, &proof).await
+		})
+	}
+
+	fn retrieve_payment_proof(&self, tx_id: Option<u32>) -> WalletFuture<PaymentProof> {
+		Box::pin(WalletInterface::retrieve_payment_proof(
+			self.clone(),
+			tx_id,
+			None,
+		))
+	}
+
+	fn verify_payment_proof(&self, proof: PaymentProof) -> WalletFuture<(bool, bool)> {
+		Box::pin(WalletInterface::verify_payment_proof(self.clone(), proof))
+	}
+}
@@ -0,0 +1,691 @@
+/// In-memory `WalletBackend` for exercising GUI handlers without a wallet or node.
+/// Transactions move through the usual slate states but nothing is signed or posted,
+/// and "slatepacks" are plain text that only this fake understands.
+use futures::future::{self, FutureExt};
+use grin_keychain::{ExtKeychain, Identifier, Keychain};
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::{Mutex, MutexGuard};
+
+use super::backend::{WalletBackend, WalletFuture};
+use super::{
+	global, AcctPathMapping, ContractNewArgsAPI, ContractRevokeArgsAPI, ContractSetupArgsAPI,
+	FeeEstimate, InitTxArgs, InvoiceProof, IssueInvoiceTxArgs, OutputCommitMapping, OutputStatus,
+	PaymentProof, RetrieveTxQueryArgs, ScanSummary, Slate, SlateState, Slatepack, SlatepackAddress,
+	StatusMessage, TxLogEntry, TxLogEntryType, WalletInfo, ZeroingString,
+};
+use crate::config::ForeignListenerConfig;
+use crate::error::GrinWalletInterfaceError;
+
+const FAKE_SLATEPACK_HEADER: &str = "BEGINSLATEPACK. FAKE ";
+const FAKE_SLATEPACK_FOOTER: &str = ". ENDSLATEPACK.";
+/// Fee the fake charges for every send
+pub const FAKE_FEE: u64 = 23_500_000;
+pub const FAKE_RECOVERY_PHRASE: &str = "abandon abandon abandon abandon abandon abandon \
+	abandon abandon abandon abandon abandon about";
+/// Well-formed payment proof with dummy signatures, as a proof file would hold it
+pub const FAKE_PAYMENT_PROOF: &str = r#"{
+	"amount": "1000000000",
+	"excess": "080000000000000000000000000000000000000000000000000000000000000000",
+	"recipient_address": "grin1ex3ls64wge0su4j38pj9zreejatplgkfap02y8wz9y3snu7dvq3q2xxzk9",
+	"recipient_sig": "00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000",
+	"sender_address": "grin1tpnxvenxvenxvenxvenxvenxvenxvenxvenxvenxvenxvenxvenqnr5dak",
+	"sender_sig": "00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"
+}"#;
+
+fn fake_err(msg: &str) -> GrinWalletInterfaceError {
+	grin_wallet_libwallet::Error::GenericError(msg.to_owned()).into()
+}
+
+fn empty_info() -> WalletInfo {
+	WalletInfo {
+		last_confirmed_height: 0,
+		minimum_confirmations: 2,
+		total: 0,
+		amount_awaiting_finalization: 0,
+		amount_awaiting_confirmation: 0,
+		amount_immature: 0,
+		amount_currently_spendable: 0,
+		amount_locked: 0,
+		amount_reverted: 0,
+	}
+}
+
+fn ready<T: Send + 'static>(res: Result<T, GrinWalletInterfaceError>) -> WalletFuture<T> {
+	future::ready(res).boxed()
+}
+
+pub struct FakeWalletState {
+	pub password: String,
+	pub is_open: bool,
+	/// Balances of the active account
+	pub info: WalletInfo,
+	pub accounts: Vec<AcctPathMapping>,
+	pub active_account: String,
+	/// Balances of the inactive accounts, swapped with `info` on account switch
+	pub other_info: HashMap<String, WalletInfo>,
+	pub txs: Vec<TxLogEntry>,
+	/// Outputs returned by `get_outputs`, not kept in step with balances
+	pub outputs: Vec<OutputCommitMapping>,
+	pub slates: HashMap<uuid::Uuid, Slate>,
+	pub address: SlatepackAddress,
+	/// Address the foreign listener was started on, while "running"
+	pub listener_addr: Option<String>,
+	/// Names of backend calls made, in order
+	pub calls: Vec<String>,
+}
+
+pub struct FakeWalletBackend {
+	state: Mutex<FakeWalletState>,
+}
+
+impl FakeWalletBackend {
+	pub fn new(password: &str) -> Self {
+		FakeWalletBackend {
+			state: Mutex::new(FakeWalletState {
+				password: password.to_owned(),
+				is_open: false,
+				info: empty_info(),
+				accounts: vec![AcctPathMapping {
+					label: "default".to_owned(),
+					path: ExtKeychain::derive_key_id(2, 0, 0, 0, 0),
+				}],
+				active_account: "default".to_owned(),
+				other_info: HashMap::new(),
+				txs: vec![],
+				outputs: vec![],
+				slates: HashMap::new(),
+				address: SlatepackAddress::random(),
+				listener_addr: None,
+				calls: vec![],
+			}),
+		}
+	}
+
+	/// Start with an open wallet holding the given spendable amount
+	pub fn open_with_balance(password: &str, amount: u64) -> Self {
+		let fake = FakeWalletBackend::new(password);
+		{
+			let mut s = fake.state();
+			s.is_open = true;
+			s.info.total = amount;
+			s.info.amount_currently_spendable = amount;
+		}
+		fake
+	}
+
+	pub fn state(&self) -> MutexGuard<FakeWalletState> {
+		self.state.lock().unwrap()
+	}
+
+	/// Record a receive as the running foreign listener would once a sender has paid this
+	/// wallet. Nothing is served over the network.
+	pub fn record_listener_receive(
+		&self,
+		amount: u64,
+	) -> Result<TxLogEntry, GrinWalletInterfaceError> {
+		let mut s = self.begin("record_listener_receive")?;
+		if s.listener_addr.is_none() {
+			return Err(fake_err("Listener not running"));
+		}
+		let mut slate = Slate::blank(2, false);
+		slate.amount = amount;
+		FakeWalletBackend::receive(&mut s, &slate, SlateState::Standard2);
+		Ok(s.txs.last().cloned().unwrap())
+	}
+
+	pub fn armor(slate: &Slate) -> String {
+		format!(
+			"{}{}{}",
+			FAKE_SLATEPACK_HEADER, slate.id, FAKE_SLATEPACK_FOOTER
+		)
+	}
+
+	fn unarmor(slatepack: &str) -> Option<uuid::Uuid> {
+		let id = slatepack
+			.trim()
+			.strip_prefix(FAKE_SLATEPACK_HEADER)?
+			.strip_suffix(FAKE_SLATEPACK_FOOTER)?;
+		uuid::Uuid::parse_str(id).ok()
+	}
+
+	fn active_path(s: &FakeWalletState) -> Identifier {
+		s.accounts
+			.iter()
+			.find(|a| a.label == s.active_account)
+			.map(|a| a.path.clone())
+			.unwrap_or_else(|| ExtKeychain::derive_key_id(2, 0, 0, 0, 0))
+	}
+
+	/// Record the call and check the wallet is open
+	fn begin(&self, call: &str) -> Result<MutexGuard<FakeWalletState>, GrinWalletInterfaceError> {
+		let mut s = self.state();
+		s.calls.push(call.to_owned());
+		if !s.is_open {
+			return Err(GrinWalletInterfaceError::OwnerAPINotInstantiated);
+		}
+		Ok(s)
+	}
+
+	fn add_tx(
+		s: &mut FakeWalletState,
+		slate: &Slate,
+		tx_type: TxLogEntryType,
+		credited: u64,
+		debited: u64,
+	) -> TxLogEntry {
+		let parent_key_id = FakeWalletBackend::active_path(s);
+		let mut tx = TxLogEntry::new(parent_key_id, tx_type, s.txs.len() as u32);
+		tx.tx_slate_id = Some(slate.id);
+		tx.amount_credited = credited;
+		tx.amount_debited = debited;
+		s.txs.push(tx.clone());
+		s.slates.insert(slate.id, slate.clone());
+		tx
+	}
+
+	fn send(
+		s: &mut FakeWalletState,
+		amount: u64,
+		state: SlateState,
+	) -> Result<Slate, GrinWalletInterfaceError> {
+		if amount > s.info.amount_currently_spendable {
+			return Err(fake_err("Not enough funds"));
+		}
+		let mut slate = Slate::blank(2, false);
+		slate.amount = amount;
+		slate.state = state;
+		s.info.amount_currently_spendable -= amount;
+		s.info.amount_locked += amount;
+		FakeWalletBackend::add_tx(s, &slate, TxLogEntryType::TxSent, 0, amount);
+		Ok(slate)
+	}
+
+	fn receive(s: &mut FakeWalletState, slate: &Slate, state: SlateState) -> Slate {
+		let mut ret_slate = slate.clone();
+		ret_slate.state = state;
+		s.info.amount_awaiting_finalization += slate.amount;
+		FakeWalletBackend::add_tx(s, &ret_slate, TxLogEntryType::TxReceived, slate.amount, 0);
+		ret_slate
+	}
+}
+
+impl WalletBackend for FakeWalletBackend {
+	fn wallet_is_open(&self) -> bool {
+		self.state().is_open
+	}
+
+	fn open_wallet(
+		&self,
+		password: String,
+		_top_level_directory: PathBuf,
+		_chain_type: global::ChainTypes,
+	) -> WalletFuture<()> {
+		let mut s = self.state();
+		s.calls.push("open_wallet".to_owned());
+		if s.password != password {
+			return ready(Err(fake_err("Invalid password")));
+		}
+		s.is_open = true;
+		ready(Ok(()))
+	}
+
+	fn close_wallet(&self) -> WalletFuture<()> {
+		let mut s = self.state();
+		s.calls.push("close_wallet".to_owned());
+		s.is_open = false;
+		s.listener_addr = None;
+		ready(Ok(()))
+	}
+
+	fn change_password(&self, old_password: String, new_password: String) -> WalletFuture<()> {
+		let res = self.begin("change_password").and_then(|mut s| {
+			if s.password != old_password {
+				return Err(fake_err("Invalid password"));
+			}
+			s.password = new_password;
+			Ok(())
+		});
+		ready(res)
+	}
+
+	fn get_recovery_phrase(&self, password: String) -> WalletFuture<ZeroingString> {
+		let res = self.begin("get_recovery_phrase").and_then(|s| {
+			if s.password != password {
+				return Err(fake_err("Invalid password"));
+			}
+			Ok(ZeroingString::from(FAKE_RECOVERY_PHRASE))
+		});
+		ready(res)
+	}
+
+	fn get_wallet_updater_status(&self) -> WalletFuture<Vec<StatusMessage>> {
+		ready(self.begin("get_wallet_updater_status").map(|_| vec![]))
+	}
+
+	fn decrypt_slatepack(
+		&self,
+		slatepack: String,
+	) -> WalletFuture<(Slatepack, Slate, Option<TxLogEntry>)> {
+		let res = self.begin("decrypt_slatepack").and_then(|s| {
+			let id = FakeWalletBackend::unarmor(&slatepack)
+				.ok_or_else(|| fake_err("Not a fake slatepack"))?;
+			let slate = s
+				.slates
+				.get(&id)
+				.cloned()
+				.ok_or_else(|| fake_err("Unknown slate"))?;
+			let tx = s.txs.iter().find(|t| t.tx_slate_id == Some(id)).cloned();
+			Ok((Slatepack::default(), slate, tx))
+		});
+		ready(res)
+	}
+
+	fn start_foreign_listener(
+		&self,
+		config: ForeignListenerConfig,
+	) -> Result<String, GrinWalletInterfaceError> {
+		let mut s = self.begin("start_foreign_listener")?;
+		if let Some(addr) = s.listener_addr.as_ref() {
+			return Err(GrinWalletInterfaceError::ForeignListenerRunning { addr: addr.clone() });
+		}
+		let addr = config
+			.socket_addr()
+			.ok_or_else(|| GrinWalletInterfaceError::InvalidListenerAddress {
+				addr: format!("{}:{}", config.bind_address, config.port),
+			})?
+			.to_string();
+		s.listener_addr = Some(addr.clone());
+		Ok(addr)
+	}
+
+	fn stop_foreign_listener(&self) {
+		let mut s = self.state();
+		s.calls.push("stop_foreign_listener".to_owned());
+		s.listener_addr = None;
+	}
+
+	fn foreign_listener_addr(&self) -> Option<String> {
+		self.state().listener_addr.clone()
+	}
+
+	fn get_wallet_info(&self, _refresh_from_node: bool) -> WalletFuture<(bool, WalletInfo)> {
+		ready(self.begin("get_wallet_info").map(|s| (true, s.info.clone())))
+	}
+
+	fn get_txs(
+		&self,
+		_query_args: Option<RetrieveTxQueryArgs>,
+	) -> WalletFuture<(bool, Vec<TxLogEntry>)> {
+		let res = self.begin("get_txs").map(|s| {
+			let path = FakeWalletBackend::active_path(&s);
+			let txs = s
+				.txs
+				.iter()
+				.filter(|t| t.parent_key_id == path)
+				.cloned()
+				.collect();
+			(true, txs)
+		});
+		ready(res)
+	}
+
+	fn get_outputs(
+		&self,
+		include_spent: bool,
+		_refresh_from_node: bool,
+	) -> WalletFuture<(bool, Vec<OutputCommitMapping>)> {
+		let res = self.begin("get_outputs").map(|s| {
+			let outputs = s
+				.outputs
+				.iter()
+				.filter(|o| include_spent || o.output.status != OutputStatus::Spent)
+				.cloned()
+				.collect();
+			(true, outputs)
+		});
+		ready(res)
+	}
+
+	fn scan(
+		&self,
+		_start_height: Option<u64>,
+		delete_unconfirmed: bool,
+	) -> WalletFuture<ScanSummary> {
+		let res = self.begin("scan").map(|mut s| {
+			let info_before = s.info.clone();
+			let outputs_before = s.outputs.len();
+			if delete_unconfirmed {
+				s.outputs
+					.retain(|o| o.output.status != OutputStatus::Unconfirmed);
+			}
+			ScanSummary {
+				info_before,
+				info_after: s.info.clone(),
+				outputs_before,
+				outputs_after: s.outputs.len(),
+			}
+		});
+		ready(res)
+	}
+
+	fn get_slatepack_address(&self) -> WalletFuture<(String, SlatepackAddress)> {
+		ready(
+			self.begin("get_slatepack_address")
+				.map(|s| (s.address.to_string(), s.address.clone())),
+		)
+	}
+
+	fn get_accounts(&self) -> WalletFuture<Vec<AcctPathMapping>> {
+		ready(self.begin("get_accounts").map(|s| s.accounts.clone()))
+	}
+
+	fn get_active_account(&self) -> WalletFuture<String> {
+		ready(
+			self.begin("get_active_account")
+				.map(|s| s.active_account.clone()),
+		)
+	}
+
+	fn create_account_path(&self, label: String) -> WalletFuture<String> {
+		let res = self.begin("create_account_path").and_then(|mut s| {
+			if s.accounts.iter().any(|a| a.label == label) {
+				return Err(fake_err("Account label already exists"));
+			}
+			let path = ExtKeychain::derive_key_id(2, s.accounts.len() as u32, 0, 0, 0);
+			s.accounts.push(AcctPathMapping {
+				label: label.clone(),
+				path,
+			});
+			s.other_info.insert(label.clone(), empty_info());
+			Ok(label)
+		});
+		ready(res)
+	}
+
+	fn set_active_account(&self, label: String) -> WalletFuture<String> {
+		let res = self.begin("set_active_account").and_then(|mut s| {
+			if label == s.active_account {
+				return Ok(label);
+			}
+			let info = s
+				.other_info
+				.remove(&label)
+				.ok_or_else(|| fake_err("Unknown account"))?;
+			let previous = std::mem::replace(&mut s.info, info);
+			let previous_label = std::mem::replace(&mut s.active_account, label.clone());
+			s.other_info.insert(previous_label, previous);
+			Ok(label)
+		});
+		ready(res)
+	}
+
+	fn estimate_fee(&self, init_args: InitTxArgs) -> WalletFuture<FeeEstimate> {
+		let res = self.begin("estimate_fee").and_then(|s| {
+			let spendable = s.info.amount_currently_spendable;
+			if init_args.amount + FAKE_FEE > spendable {
+				return Err(fake_err("Not enough funds"));
+			}
+			// Behaves as if all funds were in a single output
+			Ok(FeeEstimate {
+				amount: init_args.amount,
+				fee: FAKE_FEE,
+				inputs_total: spendable,
+				num_inputs: Some(1),
+				num_change_outputs: 1,
+			})
+		});
+		ready(res)
+	}
+
+	fn create_tx(
+		&self,
+		init_args: InitTxArgs,
+		_dest_slatepack_address: String,
+	) -> WalletFuture<(Slate, String)> {
+		let res = self.begin("create_tx").and_then(|mut s| {
+			let slate = FakeWalletBackend::send(&mut s, init_args.amount, SlateState::Standard1)?;
+			Ok((slate.clone(), FakeWalletBackend::armor(&slate)))
+		});
+		ready(res)
+	}
+
+	fn receive_tx_from_s1(
+		&self,
+		slate: Slate,
+		_dest_slatepack_address: String,
+	) -> WalletFuture<(Slate, Option<String>)> {
+		let res = self.begin("receive_tx_from_s1").map(|mut s| {
+			let ret_slate = FakeWalletBackend::receive(&mut s, &slate, SlateState::Standard2);
+			let armored = FakeWalletBackend::armor(&ret_slate);
+			(ret_slate, Some(armored))
+		});
+		ready(res)
+	}
+
+	fn finalize_from_s2(
+		&self,
+		slate: Slate,
+		_send_to_chain: bool,
+	) -> WalletFuture<(Slate, Option<String>)> {
+		let res = self.begin("finalize_from_s2").and_then(|mut s| {
+			if !s.slates.contains_key(&slate.id) {
+				return Err(fake_err("Unknown slate"));
+			}
+			let mut ret_slate = slate.clone();
+			ret_slate.state = SlateState::Standard3;
+			s.slates.insert(slate.id, ret_slate.clone());
+			s.info.amount_locked = s.info.amount_locked.saturating_sub(slate.amount);
+			s.info.total = s.info.total.saturating_sub(slate.amount);
+			Ok((ret_slate, None))
+		});
+		ready(res)
+	}
+
+	fn post_tx(&self, slate: Slate) -> WalletFuture<(Slate, Option<String>)> {
+		ready(self.begin("post_tx").map(|_| (slate, None)))
+	}
+
+	fn issue_invoice_tx(
+		&self,
+		args: IssueInvoiceTxArgs,
+		_dest_slatepack_address: String,
+	) -> WalletFuture<(Slate, String)> {
+		let res = self.begin("issue_invoice_tx").map(|mut s| {
+			let mut slate = Slate::blank(2, true);
+			slate.amount = args.amount;
+			slate.state = SlateState::Invoice1;
+			s.info.amount_awaiting_finalization += args.amount;
+			FakeWalletBackend::add_tx(&mut s, &slate, TxLogEntryType::TxReceived, args.amount, 0);
+			(slate.clone(), FakeWalletBackend::armor(&slate))
+		});
+		ready(res)
+	}
+
+	fn process_invoice_tx(
+		&self,
+		slate: Slate,
+		_init_args: InitTxArgs,
+		_dest_slatepack_address: String,
+	) -> WalletFuture<(Slate, Option<String>)> {
+		let res = self.begin("process_invoice_tx").and_then(|mut s| {
+			if slate.amount > s.info.amount_currently_spendable {
+				return Err(fake_err("Not enough funds"));
+			}
+			let mut ret_slate = slate.clone();
+			ret_slate.state = SlateState::Invoice2;
+			s.info.amount_currently_spendable -= slate.amount;
+			s.info.amount_locked += slate.amount;
+			FakeWalletBackend::add_tx(&mut s, &ret_slate, TxLogEntryType::TxSent, 0, slate.amount);
+			let armored = FakeWalletBackend::armor(&ret_slate);
+			Ok((ret_slate, Some(armored)))
+		});
+		ready(res)
+	}
+
+	fn finalize_invoice_tx(&self, slate: Slate) -> WalletFuture<(Slate, Option<String>)> {
+		let res = self.begin("finalize_invoice_tx").and_then(|mut s| {
+			if !s.slates.contains_key(&slate.id) {
+				return Err(fake_err("Unknown slate"));
+			}
+			let mut ret_slate = slate.clone();
+			ret_slate.state = SlateState::Invoice3;
+			s.slates.insert(slate.id, ret_slate.clone());
+			s.info.amount_awaiting_finalization =
+				s.info.amount_awaiting_finalization.saturating_sub(slate.amount);
+			s.info.amount_awaiting_confirmation += slate.amount;
+			Ok((ret_slate, None))
+		});
+		ready(res)
+	}
+
+	fn cancel_tx(&self, uuid: uuid::Uuid) -> WalletFuture<uuid::Uuid> {
+		let res = self.begin("cancel_tx").and_then(|mut s| {
+			let tx = match s.txs.iter_mut().find(|t| t.tx_slate_id == Some(uuid)) {
+				Some(t) => t,
+				None => return Err(fake_err("Transaction doesn't exist")),
+			};
+			let (amount, was_sent) = match tx.tx_type {
+				TxLogEntryType::TxSent => {
+					tx.tx_type = TxLogEntryType::TxSentCancelled;
+					(tx.amount_debited, true)
+				}
+				TxLogEntryType::TxReceived => {
+					tx.tx_type = TxLogEntryType::TxReceivedCancelled;
+					(tx.amount_credited, false)
+				}
+				_ => return Err(fake_err("Transaction can't be cancelled")),
+			};
+			if was_sent {
+				s.info.amount_locked = s.info.amount_locked.saturating_sub(amount);
+				s.info.amount_currently_spendable += amount;
+			} else {
+				s.info.amount_awaiting_finalization =
+					s.info.amount_awaiting_finalization.saturating_sub(amount);
+			}
+			Ok(uuid)
+		});
+		ready(res)
+	}
+
+	fn contract_new(
+		&self,
+		args: ContractNewArgsAPI,
+		_dest_slatepack_address: String,
+	) -> WalletFuture<(Slate, String)> {
+		let res = self.begin("contract_new").and_then(|mut s| {
+			let amount = args.setup_args.net_change.unwrap_or(0);
+			let slate = if amount < 0 {
+				FakeWalletBackend::send(&mut s, amount.unsigned_abs(), SlateState::Standard1)?
+			} else {
+				let mut slate = Slate::blank(2, true);
+				slate.amount = amount as u64;
+				slate.state = SlateState::Invoice1;
+				FakeWalletBackend::add_tx(
+					&mut s,
+					&slate,
+					TxLogEntryType::TxReceived,
+					slate.amount,
+					0,
+				);
+				slate
+			};
+			Ok((slate.clone(), FakeWalletBackend::armor(&slate)))
+		});
+		ready(res)
+	}
+
+	fn contract_sign(
+		&self,
+		slate: Slate,
+		_args: ContractSetupArgsAPI,
+		_dest_slatepack_address: String,
+		_send_to_chain_if_ready: bool,
+	) -> WalletFuture<(Slate, Option<String>)> {
+		let res = self.begin("contract_sign").map(|mut s| {
+			let next_state = match slate.state {
+				SlateState::Standard1 => SlateState::Standard2,
+				SlateState::Standard2 => SlateState::Standard3,
+				SlateState::Invoice1 => SlateState::Invoice2,
+				_ => SlateState::Invoice3,
+			};
+			let ret_slate = if s.slates.contains_key(&slate.id) {
+				let mut ret_slate = slate.clone();
+				ret_slate.state = next_state;
+				s.slates.insert(slate.id, ret_slate.clone());
+				ret_slate
+			} else {
+				FakeWalletBackend::receive(&mut s, &slate, next_state)
+			};
+			let armored = FakeWalletBackend::armor(&ret_slate);
+			(ret_slate, Some(armored))
+		});
+		ready(res)
+	}
+
+	fn contract_self_send(&self, args: ContractNewArgsAPI) -> WalletFuture<(Slate, TxLogEntry)> {
+		let res = self.begin("contract_self_send").and_then(|mut s| {
+			let amount = args.setup_args.net_change.unwrap_or(0).unsigned_abs();
+			let mut slate = Slate::blank(1, false);
+			slate.amount = amount;
+			slate.state = SlateState::Standard3;
+			let tx = FakeWalletBackend::add_tx(
+				&mut s,
+				&slate,
+				TxLogEntryType::TxSelfSpend,
+				amount,
+				amount,
+			);
+			Ok((slate, tx))
+		});
+		ready(res)
+	}
+
+	fn contract_revoke(&self, _args: ContractRevokeArgsAPI) -> WalletFuture<Option<Slate>> {
+		ready(self.begin("contract_revoke").map(|_| None))
+	}
+
+	fn retrieve_payment_proof_invoice(&self, _tx_id: Option<u32>) -> WalletFuture<InvoiceProof> {
+		ready(
+			self.begin("retrieve_payment_proof_invoice")
+				.and_then(|_| Err(GrinWalletInterfaceError::InvalidInvoiceProof)),
+		)
+	}
+
+	fn verify_payment_proof_invoice(
+		&self,
+		_recipient_address: String,
+		_proof: InvoiceProof,
+	) -> WalletFuture<()> {
+		ready(
+			self.begin("verify_payment_proof_invoice")
+				.and_then(|_| Err(GrinWalletInterfaceError::InvalidInvoiceProof)),
+		)
+	}
+
+	fn retrieve_payment_proof(&self, tx_id: Option<u32>) -> WalletFuture<PaymentProof> {
+		let res = self.begin("retrieve_payment_proof").and_then(|s| {
+			let tx = s
+				.txs
+				.iter()
+				.find(|t| Some(t.id) == tx_id && t.tx_type == TxLogEntryType::TxSent)
+				.ok_or_else(|| fake_err("No payment proof for transaction"))?;
+			let mut proof: PaymentProof =
+				serde_json::from_str(FAKE_PAYMENT_PROOF).map_err(|e| fake_err(&e.to_string()))?;
+			proof.amount = tx.amount_debited;
+			proof.sender_address = s.address.clone();
+			Ok(proof)
+		});
+		ready(res)
+	}
+
+	fn verify_payment_proof(&self, proof: PaymentProof) -> WalletFuture<(bool, bool)> {
+		let res = self.begin("verify_payment_proof").map(|s| {
+			(
+				proof.sender_address == s.address,
+				proof.recipient_address == s.address,
+			)
+		});
+		ready(res)
+	}
+}
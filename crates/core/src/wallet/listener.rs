@@ -0,0 +1,257 @@
+//! Foreign API HTTP listener
+//!
+//! Serves the wallet's foreign API the same way `grin-wallet listen` does, so senders can
+//! transact with the wallet synchronously rather than exchanging slatepacks by hand.
+use futures::channel::oneshot;
+use grin_api::{BasicAuthMiddleware, Router, GRIN_FOREIGN_BASIC_REALM};
+use grin_keychain::ExtKeychain;
+use grin_util::secp::key::SecretKey;
+use grin_util::{to_base64, Mutex};
+use grin_wallet_controller::controller::ForeignAPIHandlerV2;
+use grin_wallet_libwallet::{NodeClient, WalletInst, WalletLCProvider};
+use hyper::service::make_service_fn;
+use hyper::Server;
+use std::convert::Infallible;
+use std::net::SocketAddr;
+use std::sync::{mpsc, Arc};
+use std::thread::{self, JoinHandle};
+
+use crate::config::ForeignListenerConfig;
+use crate::error::GrinWalletInterfaceError;
+
+/// Path the foreign API is served under
+const FOREIGN_API_PATH: &str = "/v2/foreign";
+
+/// User name senders authenticate with when a secret is set
+const BASIC_AUTH_USER: &str = "grin";
+
+/// Running foreign API listener, stopped when dropped
+pub struct ForeignListener {
+	addr: SocketAddr,
+	shutdown: Option<oneshot::Sender<()>>,
+	thread: Option<JoinHandle<()>>,
+}
+
+impl ForeignListener {
+	pub fn start<L, C>(
+		wallet_inst: Arc<Mutex<Box<dyn WalletInst<'static, L, C, ExtKeychain>>>>,
+		keychain_mask: Option<SecretKey>,
+		config: &ForeignListenerConfig,
+	) -> Result<Self, GrinWalletInterfaceError>
+	where
+		L: WalletLCProvider<'static, C, ExtKeychain> + 'static,
+		C: NodeClient + 'static + Clone,
+	{
+		let addr = config.socket_addr().ok_or_else(|| {
+			GrinWalletInterfaceError::InvalidListenerAddress {
+				addr: format!("{}:{}", config.bind_address, config.port),
+			}
+		})?;
+		let start_err =
+			|e: &dyn std::fmt::Display| GrinWalletInterfaceError::ForeignListenerStart {
+				addr: addr.to_string(),
+				message: e.to_string(),
+			};
+
+		let handler = ForeignAPIHandlerV2::new(
+			wallet_inst,
+			Arc::new(Mutex::new(keychain_mask)),
+			false,
+			Mutex::new(None),
+		);
+		let mut router = Router::new();
+		if let Some(secret) = config.read_secret().map_err(|e| start_err(&e))? {
+			let api_basic_auth = format!(
+				"Basic {}",
+				to_base64(&format!("{}:{}", BASIC_AUTH_USER, secret))
+			);
+			router.add_middleware(Arc::new(BasicAuthMiddleware::new(
+				api_basic_auth,
+				&GRIN_FOREIGN_BASIC_REALM,
+				None,
+			)));
+		}
+		router
+			.add_route(FOREIGN_API_PATH, Arc::new(handler))
+			.map_err(|e| start_err(&e))?;
+
+		// Served the way grin_api's ApiServer does, except that the outcome of binding is sent
+		// back here rather than only logged by the server thread
+		let (shutdown, shutdown_rx) = oneshot::channel::<()>();
+		let (bound_tx, bound_rx) = mpsc::channel();
+		let thread = thread::Builder::new()
+			.name("foreign-listener".to_owned())
+			.spawn(move || {
+				let mut rt = match tokio::runtime::Runtime::new() {
+					Ok(rt) => rt,
+					Err(e) => {
+						let _ = bound_tx.send(Err(e.to_string()));
+						return;
+					}
+				};
+				rt.block_on(async move {
+					let server = match Server::try_bind(&addr) {
+						Ok(builder) => builder.serve(make_service_fn(move |_| {
+							let router = router.clone();
+							async move { Ok::<_, Infallible>(router) }
+						})),
+						Err(e) => {
+							let _ = bound_tx.send(Err(e.to_string()));
+							return;
+						}
+					};
+					let _ = bound_tx.send(Ok(server.local_addr()));
+					let server = server.with_graceful_shutdown(async {
+						shutdown_rx.await.ok();
+					});
+					if let Err(e) = server.await {
+						error!("Foreign API listener on {} failed: {}", addr, e);
+					}
+				});
+			})
+			.map_err(|e| start_err(&e))?;
+
+		let addr = match bound_rx.recv() {
+			Ok(Ok(addr)) => addr,
+			Ok(Err(message)) => {
+				let _ = thread.join();
+				return Err(start_err(&message));
+			}
+			// The thread ended without reporting, which only a panic does
+			Err(_) => {
+				let _ = thread.join();
+				return Err(start_err(&"listener thread stopped unexpectedly"));
+			}
+		};
+		info!("Foreign API listener started on {}", addr);
+
+		Ok(ForeignListener {
+			addr,
+			shutdown: Some(shutdown),
+			thread: Some(thread),
+		})
+	}
+
+	/// Address the listener is bound to
+	pub fn addr(&self) -> SocketAddr {
+		self.addr
+	}
+
+	pub fn stop(&mut self) {
+		if let Some(shutdown) = self.shutdown.take() {
+			let _ = shutdown.send(());
+			if let Some(thread) = self.thread.take() {
+				let _ = thread.join();
+			}
+			info!("Foreign API listener on {} stopped", self.addr);
+		}
+	}
+}
+
+impl Drop for ForeignListener {
+	fn drop(&mut self) {
+		self.stop();
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::config::FOREIGN_API_SECRET_FILE_NAME;
+	use crate::network::post_json_async;
+	use futures::executor::block_on;
+	use grin_core::global::{self, ChainTypes};
+	use grin_util::ZeroingString;
+	use grin_wallet_impls::{DefaultLCProvider, DefaultWalletImpl, HTTPNodeClient};
+	use serde_json::{json, Value};
+	use std::net::TcpListener;
+	use std::path::Path;
+
+	type TestLCProvider = DefaultLCProvider<'static, HTTPNodeClient, ExtKeychain>;
+	type TestWalletInst =
+		Arc<Mutex<Box<dyn WalletInst<'static, TestLCProvider, HTTPNodeClient, ExtKeychain>>>>;
+
+	/// New open wallet in `dir`. No node is reachable, so only calls that don't need one work.
+	fn test_wallet(dir: &Path) -> TestWalletInst {
+		global::set_local_chain_type(ChainTypes::AutomatedTesting);
+		let node_client = HTTPNodeClient::new("http://127.0.0.1:1", None).unwrap();
+		let mut wallet: Box<dyn WalletInst<'static, TestLCProvider, HTTPNodeClient, ExtKeychain>> =
+			Box::new(DefaultWalletImpl::<'static, HTTPNodeClient>::new(node_client).unwrap());
+		{
+			let lc = wallet.lc_provider().unwrap();
+			lc.set_top_level_directory(dir.to_str().unwrap()).unwrap();
+			lc.create_wallet(None, None, 32, ZeroingString::from("password"), true)
+				.unwrap();
+			lc.open_wallet(None, ZeroingString::from("password"), false, true)
+				.unwrap();
+		}
+		Arc::new(Mutex::new(wallet))
+	}
+
+	/// POST a V2 foreign API request, returning the HTTP status and the JSON body
+	fn post(addr: SocketAddr, method: &str, params: Value, secret: Option<&str>) -> (u16, Value) {
+		let body = json!({"jsonrpc": "2.0", "id": 1, "method": method, "params": params});
+		let auth =
+			secret.map(|s| format!("Basic {}", to_base64(&format!("{}:{}", BASIC_AUTH_USER, s))));
+		let headers = auth.iter().map(|a| ("authorization", a.as_str())).collect();
+		let url = format!("http://{}{}", addr, FOREIGN_API_PATH);
+		block_on(async {
+			let mut resp = post_json_async(url, body, headers, Some(10)).await.unwrap();
+			let text = isahc::AsyncReadResponseExt::text(&mut resp).await.unwrap();
+			(
+				resp.status().as_u16(),
+				serde_json::from_str(&text).unwrap_or(Value::Null),
+			)
+		})
+	}
+
+	#[test]
+	fn test_foreign_listener_serves_v2_api() {
+		let dir = tempfile::tempdir().unwrap();
+		let wallet = test_wallet(dir.path());
+
+		for secret in [None, Some("listener_secret")] {
+			let mut config = ForeignListenerConfig {
+				bind_address: "127.0.0.1".to_owned(),
+				port: 0,
+				..Default::default()
+			};
+			if let Some(s) = secret {
+				let path = dir.path().join(FOREIGN_API_SECRET_FILE_NAME);
+				std::fs::write(&path, s).unwrap();
+				config.secret_path = Some(path);
+			}
+			let listener = ForeignListener::start(wallet.clone(), None, &config).unwrap();
+			let addr = listener.addr();
+			assert_ne!(addr.port(), 0);
+
+			// A port that's taken is reported by start rather than only in the server thread
+			let taken = ForeignListenerConfig {
+				port: addr.port(),
+				..config.clone()
+			};
+			assert!(matches!(
+				ForeignListener::start(wallet.clone(), None, &taken),
+				Err(GrinWalletInterfaceError::ForeignListenerStart { .. })
+			));
+
+			let (status, resp) = post(addr, "check_version", json!([]), secret);
+			assert_eq!(status, 200);
+			assert_eq!(resp["result"]["Ok"]["foreign_api_version"], 2);
+
+			// Not a slate the wallet can take, but answered by it rather than turned away
+			let (status, resp) = post(addr, "receive_tx", json!([{}, null, null]), secret);
+			assert_eq!(status, 200);
+			assert!(resp["error"].is_object() || resp["result"]["Err"].is_object());
+
+			if secret.is_some() {
+				assert_eq!(post(addr, "check_version", json!([]), None).0, 401);
+				assert_eq!(post(addr, "check_version", json!([]), Some("wrong")).0, 401);
+			}
+
+			// Stopped when dropped, after which the port is free again
+			drop(listener);
+			assert!(TcpListener::bind(addr).is_ok());
+		}
+	}
+}
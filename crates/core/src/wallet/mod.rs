@@ -1,5 +1,5 @@
 /// Placeholder for all wallet calls
-/// Should eventually feature async calls that work via local wallet or remote owner API
+/// Calls go through the local wallet, or through a remote owner API if one is connected
 use grin_wallet::cmd::wallet_args::inst_wallet;
 use grin_wallet_api::{Foreign, Owner};
 use grin_wallet_config;
@@ -8,9 +8,10 @@ use grin_wallet_impls::DefaultLCProvider;
 use grin_wallet_libwallet::{NodeClient, WalletInst, WalletLCProvider};
 
 pub use grin_core::global;
+use grin_core::libtx::tx_fee;
 use grin_core::{self};
 use grin_keychain as keychain;
-use grin_util::{file, Mutex, ZeroingString};
+use grin_util::{file, Mutex};
 use keychain::mnemonic;
 
 use super::node::amount_to_hr_string;
@@ -19,22 +20,53 @@ use std::sync::{Arc, RwLock};
 
 use dirs;
 
+mod accounting;
+mod backend;
+#[cfg(any(test, feature = "test-utils"))]
+pub mod fake;
+mod listener;
+mod remote;
+mod slatepack_file;
+mod slatepack_qr;
+mod tx_export;
+
+pub use accounting::{
+	AccountingEntry, AccountingPeriod, AccountingReport, AccountingTotals,
+	ACCOUNTING_ENTRY_COLUMNS, ACCOUNTING_TOTAL_COLUMNS,
+};
+pub use backend::{WalletBackend, WalletFuture};
+pub use listener::ForeignListener;
+pub use remote::{RemoteOwner, DEFAULT_OWNER_API_URL};
+pub use slatepack_file::{
+	read_slatepack_file, write_slatepack_file, SlatepackFileFormat, MAX_SLATEPACK_FILE_SIZE,
+};
+pub use slatepack_qr::{
+	decode_qr, encode_qr, join_qr_parts, qr_parts, QrImage, MAX_QR_PART_LEN, QR_PART_PREFIX,
+};
+pub use tx_export::{
+	tx_export_csv, tx_export_json, tx_export_records, write_tx_export, TxExportFormat,
+	TxExportRecord, TX_EXPORT_COLUMNS,
+};
+
 // Re-exports
 pub use global::ChainTypes;
+pub use grin_util::ZeroingString;
 pub use grin_wallet_impls::HTTPNodeClient;
 pub use grin_wallet_libwallet::{
-	InitTxArgs, RetrieveTxQueryArgs, RetrieveTxQuerySortOrder, Slate, SlateState, Slatepack,
+	AcctPathMapping, InitTxArgs, IssueInvoiceTxArgs, OutputCommitMapping, OutputData, OutputStatus,
+	PaymentProof, RetrieveTxQueryArgs, RetrieveTxQuerySortOrder, Slate, SlateState, Slatepack,
 	SlatepackAddress, StatusMessage, TxLogEntry, TxLogEntryType, WalletInfo,
 };
 
 pub use grin_wallet_config::GlobalWalletConfig;
 
 pub use grin_wallet_libwallet::contract::types::{
-	ContractNewArgsAPI, ContractRevokeArgsAPI, ContractSetupArgsAPI, ProofArgs,
+	ContractNewArgsAPI, ContractRevokeArgsAPI, ContractSetupArgsAPI, OutputSelectionArgs, ProofArgs,
 };
 
 pub use grin_wallet_libwallet::contract::proofs::InvoiceProof;
 
+use crate::config::ForeignListenerConfig;
 use crate::error::GrinWalletInterfaceError;
 use crate::logger;
 
@@ -94,6 +126,78 @@ pub type WalletInterfaceHttpNodeClient = WalletInterface<
 	HTTPNodeClient,
 >;
 
+/// Wallet contents before and after a scan, to summarise what it changed
+#[derive(Debug, Clone)]
+pub struct ScanSummary {
+	pub info_before: WalletInfo,
+	pub info_after: WalletInfo,
+	pub outputs_before: usize,
+	pub outputs_after: usize,
+}
+
+/// Whether an outstanding send's TTL has passed at the given chain height. Receives are
+/// left alone, as the sender may still post the transaction.
+pub fn tx_is_expired(tx: &TxLogEntry, height: u64) -> bool {
+	let outstanding = match tx.tx_type {
+		TxLogEntryType::TxSent => !tx.confirmed,
+		_ => false,
+	};
+	outstanding
+		&& tx
+			.ttl_cutoff_height
+			.map_or(false, |cutoff| height >= cutoff)
+}
+
+/// What a send would cost, worked out without building or locking anything
+#[derive(Debug, Clone)]
+pub struct FeeEstimate {
+	pub amount: u64,
+	pub fee: u64,
+	/// Total value of the inputs the transaction would spend
+	pub inputs_total: u64,
+	/// Number of inputs, when known. For the wallet's own selection it can only be told
+	/// from the fee, which stops growing with the inputs once it reaches the minimum.
+	pub num_inputs: Option<usize>,
+	pub num_change_outputs: usize,
+}
+
+impl FeeEstimate {
+	/// Estimate for spending exactly the given input values. As in the wallet's own
+	/// selection, no change outputs are made when the inputs cover amount and fee exactly.
+	pub fn for_inputs(amount: u64, input_values: &[u64], num_change_outputs: usize) -> Self {
+		let num_inputs = input_values.len();
+		let inputs_total = input_values.iter().sum();
+		let num_change_outputs = if inputs_total == amount + tx_fee(num_inputs, 1, 1) {
+			0
+		} else {
+			num_change_outputs
+		};
+		FeeEstimate {
+			amount,
+			fee: tx_fee(num_inputs, num_change_outputs + 1, 1),
+			inputs_total,
+			num_inputs: Some(num_inputs),
+			num_change_outputs,
+		}
+	}
+
+	/// Number of inputs that gives `fee` with the given outputs and a single kernel,
+	/// if that fee is above the minimum
+	fn inputs_for_fee(fee: u64, num_outputs: usize) -> Option<usize> {
+		(1..=num_outputs * 4)
+			.find(|n| tx_fee(*n, num_outputs, 1) == fee)
+			.filter(|n| tx_fee(n + 1, num_outputs, 1) != fee)
+	}
+
+	pub fn total_debited(&self) -> u64 {
+		self.amount + self.fee
+	}
+
+	pub fn change(&self) -> u64 {
+		self.inputs_total.saturating_sub(self.total_debited())
+	}
+}
+
 pub struct WalletInterface<L, C>
 where
 	L: WalletLCProvider<'static, C, grin_keychain::keychain::ExtKeychain> + 'static,
@@ -105,6 +209,10 @@ where
 	pub owner_api: Option<Owner<L, C, keychain::ExtKeychain>>,
 	// Also need reference to foreign API
 	pub foreign_api: Option<Foreign<'static, L, C, keychain::ExtKeychain>>,
+	// Remote owner API, used in place of the above when connected
+	pub remote_api: Option<RemoteOwner>,
+	// Foreign API HTTP listener, while running
+	foreign_listener: Option<ForeignListener>,
 	// Simple flag to check whether wallet has been opened
 	wallet_is_open: bool,
 	// Hold on to check node foreign API secret for now
@@ -165,6 +273,8 @@ where
 			config: None,
 			owner_api: None,
 			foreign_api: None,
+			remote_api: None,
+			foreign_listener: None,
 			wallet_is_open: false,
 			check_node_foreign_api_secret_path: None,
 			node_client,
@@ -197,6 +307,12 @@ where
 		self.wallet_is_open
 	}
 
+	/// Remote owner API, if connected. Calls are made on a copy so the interface isn't locked
+	/// while waiting on the remote wallet.
+	fn remote_api(wallet_interface: &Arc<RwLock<WalletInterface<L, C>>>) -> Option<RemoteOwner> {
+		wallet_interface.read().unwrap().remote_api.clone()
+	}
+
 	pub fn set_use_embedded_node(
 		wallet_interface: Arc<RwLock<WalletInterface<L, C>>>,
 		value: bool,
@@ -274,6 +390,7 @@ where
 		let mut w = wallet_interface.write().unwrap();
 		w.owner_api = Some(Owner::new(wallet_inst.clone(), None));
 		w.foreign_api = Some(Foreign::new(wallet_inst.clone(), None, None, false));
+		w.remote_api = None;
 		global::set_local_chain_type(chain_type);
 
 		Ok(())
@@ -381,9 +498,51 @@ where
 		}
 	}
 
+	/// Connects to a wallet served by a remote owner API instead of instantiating one locally.
+	/// The API secret is read from the given `.owner_api_secret` file, if any.
+	pub async fn open_remote_wallet(
+		wallet_interface: Arc<RwLock<WalletInterface<L, C>>>,
+		password: String,
+		owner_api_url: String,
+		owner_api_secret_path: Option<PathBuf>,
+		foreign_api_url: Option<String>,
+		chain_type: global::ChainTypes,
+	) -> Result<(), GrinWalletInterfaceError> {
+		let owner_api_secret = owner_api_secret_path
+			.and_then(|p| file::get_first_line(p.to_str().map(|s| s.to_owned())));
+
+		let mut remote = RemoteOwner::new(owner_api_url, owner_api_secret, foreign_api_url, None);
+		remote.open_wallet(&password).await?;
+		remote
+			.start_updater(std::time::Duration::from_secs(60))
+			.await?;
+
+		let mut w = wallet_interface.write().unwrap();
+		w.owner_api = None;
+		w.foreign_api = None;
+		w.remote_api = Some(remote);
+		w.wallet_is_open = true;
+		w.set_chain_type(chain_type);
+		global::set_local_chain_type(chain_type);
+		Ok(())
+	}
+
 	pub async fn close_wallet(
 		wallet_interface: Arc<RwLock<WalletInterface<L, C>>>,
 	) -> Result<(), GrinWalletInterfaceError> {
+		let (listener, remote) = {
+			let mut w = wallet_interface.write().unwrap();
+			let remote = w.remote_api.take();
+			if remote.is_some() {
+				w.wallet_is_open = false;
+			}
+			(w.foreign_listener.take(), remote)
+		};
+		// Stopping the listener joins its thread, so it's left until the lock is released
+		drop(listener);
+		if let Some(mut r) = remote {
+			return r.close_wallet().await;
+		}
 		let mut w = wallet_interface.write().unwrap();
 		if let Some(o) = &w.owner_api {
 			o.close_wallet(None);
@@ -394,9 +553,68 @@ where
 		}
 	}
 
-	pub fn get_wallet_updater_status(
+	/// Change the password protecting the wallet seed, then re-open the wallet with it
+	pub async fn change_password(
+		wallet_interface: Arc<RwLock<WalletInterface<L, C>>>,
+		old_password: String,
+		new_password: String,
+	) -> Result<(), GrinWalletInterfaceError> {
+		if let Some(mut r) = WalletInterface::remote_api(&wallet_interface) {
+			r.change_password(&old_password, &new_password).await?;
+			r.close_wallet().await?;
+			r.open_wallet(&new_password).await?;
+			r.start_updater(std::time::Duration::from_secs(60)).await?;
+			// Keep the token of the reopened wallet
+			wallet_interface.write().unwrap().remote_api = Some(r);
+			return Ok(());
+		}
+		let w = wallet_interface.write().unwrap();
+		if let Some(o) = &w.owner_api {
+			// Reject a wrong password while the updater is still running
+			let _ = o.get_mnemonic(None, old_password.clone().into())?;
+			o.stop_updater()?;
+			let res = (|| -> Result<(), GrinWalletInterfaceError> {
+				{
+					let mut w_lock = o.wallet_inst.lock();
+					let p = w_lock.lc_provider()?;
+					p.change_password(None, old_password.into(), new_password.clone().into())?;
+				}
+				// The open keychain was derived with the old password
+				o.close_wallet(None)?;
+				let _ = o.open_wallet(None, new_password.into(), false)?;
+				Ok(())
+			})();
+			// Restart the updater whether or not the change went through
+			let restarted = o.start_updater(None, std::time::Duration::from_secs(60));
+			return res.and(restarted.map_err(GrinWalletInterfaceError::from));
+		} else {
+			return Err(GrinWalletInterfaceError::OwnerAPINotInstantiated);
+		}
+	}
+
+	/// Retrieve the wallet's recovery phrase, which requires the wallet password again
+	pub async fn get_recovery_phrase(
+		wallet_interface: Arc<RwLock<WalletInterface<L, C>>>,
+		password: String,
+	) -> Result<ZeroingString, GrinWalletInterfaceError> {
+		if let Some(r) = WalletInterface::remote_api(&wallet_interface) {
+			return r.get_mnemonic(&password).await;
+		}
+		let w = wallet_interface.read().unwrap();
+		if let Some(o) = &w.owner_api {
+			let res = o.get_mnemonic(None, password.into())?;
+			return Ok(res);
+		} else {
+			return Err(GrinWalletInterfaceError::OwnerAPINotInstantiated);
+		}
+	}
+
+	pub async fn get_wallet_updater_status(
 		wallet_interface: Arc<RwLock<WalletInterface<L, C>>>,
 	) -> Result<Vec<StatusMessage>, GrinWalletInterfaceError> {
+		if let Some(r) = WalletInterface::remote_api(&wallet_interface) {
+			return r.get_updater_messages(1).await;
+		}
 		let w = wallet_interface.read().unwrap();
 		if let Some(o) = &w.owner_api {
 			let res = o.get_updater_messages(1)?;
@@ -423,11 +641,72 @@ where
 		Ok(api.create_slatepack_message(None, &unenc_slate, Some(0), recipients)?)
 	}
 
+	pub async fn encrypt_slatepack_remote(
+		api: &RemoteOwner,
+		dest: &str,
+		unenc_slate: &Slate,
+	) -> Result<String, GrinWalletInterfaceError> {
+		let address = match SlatepackAddress::try_from(dest) {
+			Ok(a) => a,
+			Err(_) => return Err(GrinWalletInterfaceError::InvalidSlatepackAddress),
+		};
+		api.create_slatepack_message(unenc_slate, Some(0), vec![address])
+			.await
+	}
+
+	/// Serve the foreign API over HTTP, returning the address it's listening on
+	pub fn start_foreign_listener(
+		wallet_interface: Arc<RwLock<WalletInterface<L, C>>>,
+		config: ForeignListenerConfig,
+	) -> Result<String, GrinWalletInterfaceError> {
+		let mut w = wallet_interface.write().unwrap();
+		if let Some(l) = &w.foreign_listener {
+			return Err(GrinWalletInterfaceError::ForeignListenerRunning {
+				addr: l.addr().to_string(),
+			});
+		}
+		if w.remote_api.is_some() {
+			return Err(GrinWalletInterfaceError::RemoteOwnerAPIUnsupported {
+				method: "start_foreign_listener".to_owned(),
+			});
+		}
+		let wallet_inst = match &w.owner_api {
+			Some(o) => o.wallet_inst.clone(),
+			None => return Err(GrinWalletInterfaceError::OwnerAPINotInstantiated),
+		};
+		// Wallets are opened without a keychain mask
+		let listener = ForeignListener::start(wallet_inst, None, &config)?;
+		let addr = listener.addr().to_string();
+		w.foreign_listener = Some(listener);
+		Ok(addr)
+	}
+
+	pub fn stop_foreign_listener(wallet_interface: Arc<RwLock<WalletInterface<L, C>>>) {
+		let listener = wallet_interface.write().unwrap().foreign_listener.take();
+		// Dropped without the lock held, as stopping it joins the server thread
+		drop(listener);
+	}
+
+	pub fn foreign_listener_addr(
+		wallet_interface: Arc<RwLock<WalletInterface<L, C>>>,
+	) -> Option<String> {
+		let w = wallet_interface.read().unwrap();
+		w.foreign_listener.as_ref().map(|l| l.addr().to_string())
+	}
+
 	/// Attempt to decode and decrypt a given slatepack, also return associated transaction (if we can find it)
-	pub fn decrypt_slatepack(
+	pub async fn decrypt_slatepack(
 		wallet_interface: Arc<RwLock<WalletInterface<L, C>>>,
 		slatepack: String,
 	) -> Result<(Slatepack, Slate, Option<TxLogEntry>), GrinWalletInterfaceError> {
+		if let Some(r) = WalletInterface::remote_api(&wallet_interface) {
+			let sp = r
+				.decode_slatepack_message(slatepack.clone(), vec![0])
+				.await?;
+			let slate = r.slate_from_slatepack_message(slatepack, vec![0]).await?;
+			let txs = r.retrieve_txs(false, None, Some(slate.id), None).await?;
+			return Ok((sp, slate, txs.1.first().cloned()));
+		}
 		let w = wallet_interface.read().unwrap();
 		if let Some(o) = &w.owner_api {
 			let sp = o.decode_slatepack_message(None, slatepack.clone(), vec![0])?;
@@ -448,6 +727,9 @@ where
 		wallet_interface: Arc<RwLock<WalletInterface<L, C>>>,
 		refresh_from_node: bool,
 	) -> Result<(bool, WalletInfo), GrinWalletInterfaceError> {
+		if let Some(r) = WalletInterface::remote_api(&wallet_interface) {
+			return r.retrieve_summary_info(refresh_from_node, 2).await;
+		}
 		let w = wallet_interface.read().unwrap();
 		if let Some(o) = &w.owner_api {
 			let res = o.retrieve_summary_info(None, refresh_from_node, 2)?;
@@ -461,6 +743,9 @@ where
 		wallet_interface: Arc<RwLock<WalletInterface<L, C>>>,
 		query_args: Option<RetrieveTxQueryArgs>,
 	) -> Result<(bool, Vec<TxLogEntry>), GrinWalletInterfaceError> {
+		if let Some(r) = WalletInterface::remote_api(&wallet_interface) {
+			return r.retrieve_txs(true, None, None, query_args.as_ref()).await;
+		}
 		let w = wallet_interface.read().unwrap();
 		if let Some(o) = &w.owner_api {
 			let res = o.retrieve_txs(None, true, None, None, query_args)?;
@@ -482,9 +767,149 @@ where
 		}
 	}
 
+	/// Outputs of the active account, along with whether they were refreshed from the node
+	pub async fn get_outputs(
+		wallet_interface: Arc<RwLock<WalletInterface<L, C>>>,
+		include_spent: bool,
+		refresh_from_node: bool,
+	) -> Result<(bool, Vec<OutputCommitMapping>), GrinWalletInterfaceError> {
+		if let Some(r) = WalletInterface::remote_api(&wallet_interface) {
+			return r
+				.retrieve_outputs(include_spent, refresh_from_node, None)
+				.await;
+		}
+		let w = wallet_interface.read().unwrap();
+		if let Some(o) = &w.owner_api {
+			let res = o.retrieve_outputs(None, include_spent, refresh_from_node, None)?;
+			return Ok(res);
+		} else {
+			return Err(GrinWalletInterfaceError::OwnerAPINotInstantiated);
+		}
+	}
+
+	/// Scan the chain from `start_height` (or the wallet's first block if none) to
+	/// repair outputs and transactions. Progress is reported through the updater
+	/// status messages as `StatusMessage::Scanning`.
+	pub async fn scan(
+		wallet_interface: Arc<RwLock<WalletInterface<L, C>>>,
+		start_height: Option<u64>,
+		delete_unconfirmed: bool,
+	) -> Result<ScanSummary, GrinWalletInterfaceError> {
+		if let Some(r) = WalletInterface::remote_api(&wallet_interface) {
+			let (_, info_before) = r.retrieve_summary_info(false, 2).await?;
+			let (_, outputs_before) = r.retrieve_outputs(false, false, None).await?;
+			r.scan(start_height, delete_unconfirmed).await?;
+			let (_, info_after) = r.retrieve_summary_info(false, 2).await?;
+			let (_, outputs_after) = r.retrieve_outputs(false, false, None).await?;
+			return Ok(ScanSummary {
+				info_before,
+				info_after,
+				outputs_before: outputs_before.len(),
+				outputs_after: outputs_after.len(),
+			});
+		}
+		let w = wallet_interface.read().unwrap();
+		if let Some(o) = &w.owner_api {
+			let (_, info_before) = o.retrieve_summary_info(None, false, 2)?;
+			let (_, outputs_before) = o.retrieve_outputs(None, false, false, None)?;
+			o.scan(None, start_height, delete_unconfirmed)?;
+			let (_, info_after) = o.retrieve_summary_info(None, false, 2)?;
+			let (_, outputs_after) = o.retrieve_outputs(None, false, false, None)?;
+			return Ok(ScanSummary {
+				info_before,
+				info_after,
+				outputs_before: outputs_before.len(),
+				outputs_after: outputs_after.len(),
+			});
+		} else {
+			return Err(GrinWalletInterfaceError::OwnerAPINotInstantiated);
+		}
+	}
+
+	/// All accounts (labelled parent key paths) in the wallet
+	pub async fn get_accounts(
+		wallet_interface: Arc<RwLock<WalletInterface<L, C>>>,
+	) -> Result<Vec<AcctPathMapping>, GrinWalletInterfaceError> {
+		if let Some(r) = WalletInterface::remote_api(&wallet_interface) {
+			return r.accounts().await;
+		}
+		let w = wallet_interface.read().unwrap();
+		if let Some(o) = &w.owner_api {
+			let res = o.accounts(None)?;
+			return Ok(res);
+		} else {
+			return Err(GrinWalletInterfaceError::OwnerAPINotInstantiated);
+		}
+	}
+
+	/// Label of the account that info, transaction and send calls operate on
+	pub async fn get_active_account(
+		wallet_interface: Arc<RwLock<WalletInterface<L, C>>>,
+	) -> Result<String, GrinWalletInterfaceError> {
+		if let Some(r) = WalletInterface::remote_api(&wallet_interface) {
+			return Ok(r.active_account().to_owned());
+		}
+		let w = wallet_interface.read().unwrap();
+		if let Some(o) = &w.owner_api {
+			let accounts = o.accounts(None)?;
+			let parent_key_id = {
+				let mut w_lock = o.wallet_inst.lock();
+				let lc = w_lock.lc_provider()?;
+				lc.wallet_inst()?.parent_key_id()
+			};
+			return accounts
+				.into_iter()
+				.find(|a| a.path == parent_key_id)
+				.map(|a| a.label)
+				.ok_or(GrinWalletInterfaceError::ActiveAccountNotFound);
+		} else {
+			return Err(GrinWalletInterfaceError::OwnerAPINotInstantiated);
+		}
+	}
+
+	pub async fn create_account_path(
+		wallet_interface: Arc<RwLock<WalletInterface<L, C>>>,
+		label: String,
+	) -> Result<String, GrinWalletInterfaceError> {
+		if let Some(r) = WalletInterface::remote_api(&wallet_interface) {
+			r.create_account_path(&label).await?;
+			return Ok(label);
+		}
+		let w = wallet_interface.write().unwrap();
+		if let Some(o) = &w.owner_api {
+			o.create_account_path(None, &label)?;
+			return Ok(label);
+		} else {
+			return Err(GrinWalletInterfaceError::OwnerAPINotInstantiated);
+		}
+	}
+
+	/// Switch the account that info, transaction and send calls operate on
+	pub async fn set_active_account(
+		wallet_interface: Arc<RwLock<WalletInterface<L, C>>>,
+		label: String,
+	) -> Result<String, GrinWalletInterfaceError> {
+		if let Some(mut r) = WalletInterface::remote_api(&wallet_interface) {
+			r.set_active_account(&label).await?;
+			wallet_interface.write().unwrap().remote_api = Some(r);
+			return Ok(label);
+		}
+		let w = wallet_interface.write().unwrap();
+		if let Some(o) = &w.owner_api {
+			o.set_active_account(None, &label)?;
+			return Ok(label);
+		} else {
+			return Err(GrinWalletInterfaceError::OwnerAPINotInstantiated);
+		}
+	}
+
 	pub async fn get_slatepack_address(
 		wallet_interface: Arc<RwLock<WalletInterface<L, C>>>,
 	) -> Result<(String, SlatepackAddress), GrinWalletInterfaceError> {
+		if let Some(r) = WalletInterface::remote_api(&wallet_interface) {
+			let res = r.get_slatepack_address(0).await?;
+			return Ok((res.to_string(), res));
+		}
 		let w = wallet_interface.read().unwrap();
 		if let Some(o) = &w.owner_api {
 			let res = o.get_slatepack_address(None, 0)?;
@@ -494,16 +919,68 @@ where
 		}
 	}
 
+	/// Dry run of `init_send_tx` to find the fee and inputs a send would use
+	pub async fn estimate_fee(
+		wallet_interface: Arc<RwLock<WalletInterface<L, C>>>,
+		init_args: InitTxArgs,
+	) -> Result<FeeEstimate, GrinWalletInterfaceError> {
+		let args = InitTxArgs {
+			estimate_only: Some(true),
+			..init_args
+		};
+		// An estimate comes back with the total of the selected inputs as its amount
+		let slate = match WalletInterface::remote_api(&wallet_interface) {
+			Some(r) => r.init_send_tx(&args).await?,
+			None => match &wallet_interface.read().unwrap().owner_api {
+				Some(o) => o.init_send_tx(None, args.clone())?,
+				None => return Err(GrinWalletInterfaceError::OwnerAPINotInstantiated),
+			},
+		};
+		let fee = slate.fee_fields.fee();
+		let num_change_outputs = if slate.amount > args.amount + fee {
+			args.num_change_outputs as usize
+		} else {
+			0
+		};
+		Ok(FeeEstimate {
+			amount: args.amount,
+			fee,
+			inputs_total: slate.amount,
+			num_inputs: FeeEstimate::inputs_for_fee(fee, num_change_outputs + 1),
+			num_change_outputs,
+		})
+	}
+
 	pub async fn create_tx(
 		wallet_interface: Arc<RwLock<WalletInterface<L, C>>>,
 		init_args: InitTxArgs,
 		dest_slatepack_address: String,
 	) -> Result<(Slate, String), GrinWalletInterfaceError> {
-		let w = wallet_interface.write().unwrap();
-		let _address = match SlatepackAddress::try_from(dest_slatepack_address.as_str()) {
-			Ok(a) => Some(a),
+		let address = match SlatepackAddress::try_from(dest_slatepack_address.as_str()) {
+			Ok(a) => a,
 			Err(_) => return Err(GrinWalletInterfaceError::InvalidSlatepackAddress),
 		};
+		// Request a payment proof from the recipient unless the caller asked for another address
+		let init_args = InitTxArgs {
+			payment_proof_recipient_address: init_args
+				.payment_proof_recipient_address
+				.or(Some(address)),
+			..init_args
+		};
+		if let Some(r) = WalletInterface::remote_api(&wallet_interface) {
+			let slate = r.init_send_tx(&init_args).await?;
+			r.tx_lock_outputs(&slate).await?;
+			return Ok((
+				slate.clone(),
+				WalletInterface::<L, C>::encrypt_slatepack_remote(
+					&r,
+					&dest_slatepack_address,
+					&slate,
+				)
+				.await?,
+			));
+		}
+		let w = wallet_interface.write().unwrap();
 		if let Some(o) = &w.owner_api {
 			let slate = { o.init_send_tx(None, init_args)? };
 			o.tx_lock_outputs(None, &slate)?;
@@ -521,6 +998,16 @@ where
 		slate: Slate,
 		dest_slatepack_address: String,
 	) -> Result<(Slate, Option<String>), GrinWalletInterfaceError> {
+		if let Some(r) = WalletInterface::remote_api(&wallet_interface) {
+			let ret_slate = r.receive_tx(&slate).await?;
+			let encrypted = WalletInterface::<L, C>::encrypt_slatepack_remote(
+				&r,
+				&dest_slatepack_address,
+				&ret_slate,
+			)
+			.await?;
+			return Ok((ret_slate, Some(encrypted)));
+		}
 		let w = wallet_interface.write().unwrap();
 		let ret_slate;
 		if let Some(f) = &w.foreign_api {
@@ -542,20 +1029,114 @@ where
 		slate: Slate,
 		send_to_chain: bool,
 	) -> Result<(Slate, Option<String>), GrinWalletInterfaceError> {
+		if let Some(r) = WalletInterface::remote_api(&wallet_interface) {
+			let ret_slate = r.finalize_tx(&slate).await?;
+			if send_to_chain {
+				r.post_tx(&ret_slate, false).await?;
+			}
+			return Ok((ret_slate, None));
+		}
 		let w = wallet_interface.write().unwrap();
 		if let Some(o) = &w.owner_api {
 			let ret_slate = o.finalize_tx(None, &slate)?;
-			o.post_tx(None, &ret_slate, false)?;
+			if send_to_chain {
+				o.post_tx(None, &ret_slate, false)?;
+			}
 			return Ok((ret_slate, None));
 		} else {
 			return Err(GrinWalletInterfaceError::OwnerAPINotInstantiated);
 		}
 	}
 
+	/// Create an invoice (I1) slate requesting payment, encrypted to the payer
+	pub async fn issue_invoice_tx(
+		wallet_interface: Arc<RwLock<WalletInterface<L, C>>>,
+		args: IssueInvoiceTxArgs,
+		dest_slatepack_address: String,
+	) -> Result<(Slate, String), GrinWalletInterfaceError> {
+		if SlatepackAddress::try_from(dest_slatepack_address.as_str()).is_err() {
+			return Err(GrinWalletInterfaceError::InvalidSlatepackAddress);
+		}
+		if let Some(r) = WalletInterface::remote_api(&wallet_interface) {
+			let slate = r.issue_invoice_tx(&args).await?;
+			return Ok((
+				slate.clone(),
+				WalletInterface::<L, C>::encrypt_slatepack_remote(
+					&r,
+					&dest_slatepack_address,
+					&slate,
+				)
+				.await?,
+			));
+		}
+		let w = wallet_interface.write().unwrap();
+		if let Some(o) = &w.owner_api {
+			let slate = o.issue_invoice_tx(None, args)?;
+			return Ok((
+				slate.clone(),
+				WalletInterface::encrypt_slatepack(o, &dest_slatepack_address, &slate)?,
+			));
+		} else {
+			return Err(GrinWalletInterfaceError::OwnerAPINotInstantiated);
+		}
+	}
+
+	/// Pay an invoice (I1), locking the inputs used and returning the I2 slate for the issuer
+	pub async fn process_invoice_tx(
+		wallet_interface: Arc<RwLock<WalletInterface<L, C>>>,
+		slate: Slate,
+		init_args: InitTxArgs,
+		dest_slatepack_address: String,
+	) -> Result<(Slate, Option<String>), GrinWalletInterfaceError> {
+		if let Some(r) = WalletInterface::remote_api(&wallet_interface) {
+			let ret_slate = r.process_invoice_tx(&slate, &init_args).await?;
+			r.tx_lock_outputs(&ret_slate).await?;
+			let encrypted = WalletInterface::<L, C>::encrypt_slatepack_remote(
+				&r,
+				&dest_slatepack_address,
+				&ret_slate,
+			)
+			.await?;
+			return Ok((ret_slate, Some(encrypted)));
+		}
+		let w = wallet_interface.write().unwrap();
+		if let Some(o) = &w.owner_api {
+			let ret_slate = o.process_invoice_tx(None, &slate, init_args)?;
+			o.tx_lock_outputs(None, &ret_slate)?;
+			let encrypted =
+				WalletInterface::encrypt_slatepack(o, &dest_slatepack_address, &ret_slate)?;
+			return Ok((ret_slate, Some(encrypted)));
+		} else {
+			return Err(GrinWalletInterfaceError::OwnerAPINotInstantiated);
+		}
+	}
+
+	/// Finalize a paid invoice (I2) on the issuer's side and post it
+	pub async fn finalize_invoice_tx(
+		wallet_interface: Arc<RwLock<WalletInterface<L, C>>>,
+		slate: Slate,
+	) -> Result<(Slate, Option<String>), GrinWalletInterfaceError> {
+		if let Some(r) = WalletInterface::remote_api(&wallet_interface) {
+			let ret_slate = r.finalize_invoice_tx(&slate).await?;
+			return Ok((ret_slate, None));
+		}
+		let w = wallet_interface.write().unwrap();
+		if let Some(f) = &w.foreign_api {
+			let ret_slate = f.finalize_tx(&slate, true)?;
+			return Ok((ret_slate, None));
+		} else {
+			return Err(GrinWalletInterfaceError::ForeignAPINotInstantiated);
+		}
+	}
+
 	pub async fn cancel_tx(
 		wallet_interface: Arc<RwLock<WalletInterface<L, C>>>,
 		uuid: uuid::Uuid,
 	) -> Result<uuid::Uuid, GrinWalletInterfaceError> {
+		if let Some(r) = WalletInterface::remote_api(&wallet_interface) {
+			r.cancel_tx(None, Some(uuid)).await?;
+			return Ok(uuid);
+		}
 		let w = wallet_interface.write().unwrap();
 		if let Some(o) = &w.owner_api {
 			o.cancel_tx(None, None, Some(uuid))?;
@@ -569,6 +1150,10 @@ where
 		wallet_interface: Arc<RwLock<WalletInterface<L, C>>>,
 		slate: Slate,
 	) -> Result<(Slate, Option<String>), GrinWalletInterfaceError> {
+		if let Some(r) = WalletInterface::remote_api(&wallet_interface) {
+			r.post_tx(&slate, false).await?;
+			return Ok((slate, None));
+		}
 		let w = wallet_interface.write().unwrap();
 		if let Some(o) = &w.owner_api {
 			let ret_slate = slate.clone();
@@ -584,6 +1169,18 @@ where
 		args: ContractNewArgsAPI,
 		dest_slatepack_address: String,
 	) -> Result<(Slate, String), GrinWalletInterfaceError> {
+		if let Some(r) = WalletInterface::remote_api(&wallet_interface) {
+			let slate = r.contract_new(&args).await?;
+			return Ok((
+				slate.clone(),
+				WalletInterface::<L, C>::encrypt_slatepack_remote(
+					&r,
+					&dest_slatepack_address,
+					&slate,
+				)
+				.await?,
+			));
+		}
 		let w = wallet_interface.write().unwrap();
 		if let Some(o) = &w.owner_api {
 			let slate = o.contract_new(None, &args)?;
@@ -603,6 +1200,22 @@ where
 		dest_slatepack_address: String,
 		send_to_chain_if_ready: bool,
 	) -> Result<(Slate, Option<String>), GrinWalletInterfaceError> {
+		if let Some(r) = WalletInterface::remote_api(&wallet_interface) {
+			let slate = r.contract_sign(&slate, &args).await?;
+			if send_to_chain_if_ready {
+				if slate.state == SlateState::Standard3 || slate.state == SlateState::Invoice3 {
+					r.post_tx(&slate, false).await?;
+					return Ok((slate, None));
+				}
+			}
+			let encrypted = WalletInterface::<L, C>::encrypt_slatepack_remote(
+				&r,
+				&dest_slatepack_address,
+				&slate,
+			)
+			.await?;
+			return Ok((slate, Some(encrypted)));
+		}
 		let w = wallet_interface.write().unwrap();
 		if let Some(o) = &w.owner_api {
 			let slate = o.contract_sign(None, &slate, &args)?;
@@ -630,6 +1243,11 @@ where
 		args: ContractNewArgsAPI,
 	) -> Result<(Slate, TxLogEntry), GrinWalletInterfaceError> {
 		let w = wallet_interface.write().unwrap();
+		if w.remote_api.is_some() {
+			return Err(GrinWalletInterfaceError::RemoteOwnerAPIUnsupported {
+				method: "contract_self_send".to_owned(),
+			});
+		}
 		if let Some(o) = &w.owner_api {
 			let slate = o.contract_new(None, &args)?;
 			let slate = o.contract_sign(None, &slate, &args.setup_args)?;
@@ -650,6 +1268,11 @@ where
 		args: &ContractRevokeArgsAPI,
 	) -> Result<Option<Slate>, GrinWalletInterfaceError> {
 		let w = wallet_interface.write().unwrap();
+		if w.remote_api.is_some() {
+			return Err(GrinWalletInterfaceError::RemoteOwnerAPIUnsupported {
+				method: "contract_revoke".to_owned(),
+			});
+		}
 		if let Some(o) = &w.owner_api {
 			let slate = o.contract_revoke(None, args)?;
 			return Ok(slate);
@@ -663,6 +1286,11 @@ where
 		slate: &Slate,
 	) -> Result<usize, GrinWalletInterfaceError> {
 		let w = wallet_interface.write().unwrap();
+		if w.remote_api.is_some() {
+			return Err(GrinWalletInterfaceError::RemoteOwnerAPIUnsupported {
+				method: "get_slate_index_matching_my_context".to_owned(),
+			});
+		}
 		if let Some(o) = &w.owner_api {
 			let index = o.get_slate_index_matching_my_context(None, slate)?;
 			return Ok(index);
@@ -676,6 +1304,11 @@ where
 		tx_id: Option<u32>,
 	) -> Result<InvoiceProof, GrinWalletInterfaceError> {
 		let w = wallet_interface.write().unwrap();
+		if w.remote_api.is_some() {
+			return Err(GrinWalletInterfaceError::RemoteOwnerAPIUnsupported {
+				method: "retrieve_payment_proof_invoice".to_owned(),
+			});
+		}
 		if let Some(o) = &w.owner_api {
 			let res = o.retrieve_payment_proof_invoice(None, true, tx_id, None)?;
 			return Ok(res);
@@ -699,6 +1332,42 @@ where
 		}
 	}
 
+	/// Payment proof of a legacy send, as received from the recipient
+	pub async fn retrieve_payment_proof(
+		wallet_interface: Arc<RwLock<WalletInterface<L, C>>>,
+		tx_id: Option<u32>,
+		tx_slate_id: Option<uuid::Uuid>,
+	) -> Result<PaymentProof, GrinWalletInterfaceError> {
+		if let Some(r) = WalletInterface::remote_api(&wallet_interface) {
+			return r.retrieve_payment_proof(true, tx_id, tx_slate_id).await;
+		}
+		let w = wallet_interface.write().unwrap();
+		if let Some(o) = &w.owner_api {
+			let res = o.retrieve_payment_proof(None, true, tx_id, tx_slate_id)?;
+			return Ok(res);
+		} else {
+			return Err(GrinWalletInterfaceError::OwnerAPINotInstantiated);
+		}
+	}
+
+	/// Check a legacy payment proof's signatures and that its kernel is on chain. Returns
+	/// whether the sender and recipient addresses belong to this wallet.
+	pub async fn verify_payment_proof(
+		wallet_interface: Arc<RwLock<WalletInterface<L, C>>>,
+		proof: PaymentProof,
+	) -> Result<(bool, bool), GrinWalletInterfaceError> {
+		if let Some(r) = WalletInterface::remote_api(&wallet_interface) {
+			return r.verify_payment_proof(&proof).await;
+		}
+		let w = wallet_interface.write().unwrap();
+		if let Some(o) = &w.owner_api {
+			let res = o.verify_payment_proof(None, &proof)?;
+			return Ok(res);
+		} else {
+			return Err(GrinWalletInterfaceError::OwnerAPINotInstantiated);
+		}
+	}
+
 	/*pub async fn tx_lock_outputs(
 		wallet_interface: Arc<RwLock<WalletInterface<L, C>>>,
 		init_args: InitTxArgs,
@@ -713,9 +1382,37 @@ where
 			return Err(GrinWalletInterfaceError::OwnerAPINotInstantiated);
 		}
 	}*/
+}
 
-	/*pub async fn get_recovery_phrase(wallet_interface: Arc<RwLock<WalletInterface<L, C>>>, password: String) -> String {
-		let mut w = wallet_interface.read().unwrap();
-		w.owner_api.get_mnemonic(name, password.into())
-	}*/
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn test_fee_estimate_for_inputs() {
+		let amount = 1_000_000_000;
+
+		let estimate = FeeEstimate::for_inputs(amount, &[3_000_000_000, 2_000_000_000], 2);
+		assert_eq!(estimate.fee, tx_fee(2, 3, 1));
+		assert_eq!(estimate.num_inputs, Some(2));
+		assert_eq!(estimate.num_change_outputs, 2);
+		assert_eq!(estimate.change(), 4_000_000_000 - estimate.fee);
+
+		// Inputs covering amount and fee exactly need no change output
+		let exact = amount + tx_fee(1, 1, 1);
+		let estimate = FeeEstimate::for_inputs(amount, &[exact], 1);
+		assert_eq!(estimate.fee, tx_fee(1, 1, 1));
+		assert_eq!(estimate.num_change_outputs, 0);
+		assert_eq!(estimate.change(), 0);
+	}
+
+	#[test]
+	fn test_inputs_for_fee() {
+		for n in 1..8 {
+			assert_eq!(FeeEstimate::inputs_for_fee(tx_fee(n, 2, 1), 2), Some(n));
+		}
+		// From eight inputs on, two outputs cost the minimum fee whatever the inputs
+		assert_eq!(FeeEstimate::inputs_for_fee(tx_fee(8, 2, 1), 2), None);
+		assert_eq!(FeeEstimate::inputs_for_fee(tx_fee(1, 2, 1) + 1, 2), None);
+	}
 }
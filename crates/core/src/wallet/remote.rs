@@ -0,0 +1,917 @@
+/// Client for a grin-wallet V3 owner API running as a separate service, possibly
+/// on another machine. All owner calls are encrypted with a shared key negotiated
+/// through `init_secure_api`, as the V3 API requires.
+use grin_util::secp::key::{PublicKey, SecretKey};
+use grin_util::{from_hex, static_secp_instance, to_base64, to_hex};
+use grin_wallet_api::{EncryptedRequest, EncryptedResponse, JsonId};
+use grin_wallet_libwallet::{SlateVersion, VersionedSlate};
+
+use serde::de::DeserializeOwned;
+use serde_json::{json, Value};
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Arc;
+
+use super::{
+	AcctPathMapping, ContractNewArgsAPI, ContractSetupArgsAPI, InitTxArgs, IssueInvoiceTxArgs,
+	OutputCommitMapping, PaymentProof, RetrieveTxQueryArgs, Slate, Slatepack, SlatepackAddress,
+	StatusMessage, TxLogEntry, WalletInfo, ZeroingString,
+};
+use crate::error::DownloadError;
+use crate::error::GrinWalletInterfaceError;
+use crate::network::post_json_async;
+
+/// Default owner API endpoint of a grin-wallet listening with `owner_api`
+pub const DEFAULT_OWNER_API_URL: &str = "http://127.0.0.1:3420/v3/owner";
+
+/// Timeout for individual owner API calls, in seconds
+const REQUEST_TIMEOUT: u64 = 60;
+
+fn remote_err<E: std::fmt::Display>(e: E) -> GrinWalletInterfaceError {
+	GrinWalletInterfaceError::RemoteOwnerAPI {
+		message: format!("{}", e),
+	}
+}
+
+/// Derive the shared secret both sides use for `encrypted_request_v3`, i.e. the
+/// x coordinate of our secret key multiplied by their public key
+fn derive_shared_key(
+	sec_key: &SecretKey,
+	other_pub_key: &PublicKey,
+) -> Result<SecretKey, GrinWalletInterfaceError> {
+	let secp = static_secp_instance();
+	let secp = secp.lock();
+	let mut shared_pub_key = other_pub_key.clone();
+	shared_pub_key.mul_assign(&secp, sec_key).map_err(remote_err)?;
+	let x_coord = shared_pub_key.serialize_vec(&secp, true);
+	SecretKey::from_slice(&secp, &x_coord[1..]).map_err(remote_err)
+}
+
+fn new_ephemeral_key() -> Result<(SecretKey, PublicKey), GrinWalletInterfaceError> {
+	let secp = static_secp_instance();
+	let secp = secp.lock();
+	let sec_key =
+		SecretKey::from_slice(&secp, &rand::random::<[u8; 32]>()).map_err(remote_err)?;
+	let pub_key = PublicKey::from_secret_key(&secp, &sec_key).map_err(remote_err)?;
+	Ok((sec_key, pub_key))
+}
+
+fn auth_header(api_secret: &Option<String>) -> Option<String> {
+	api_secret
+		.as_ref()
+		.map(|s| format!("Basic {}", to_base64(&format!("grin:{}", s))))
+}
+
+/// Pull the `Ok` value out of a JSON-RPC response, turning anything else into an error
+fn parse_rpc_result<T: DeserializeOwned>(res: &Value) -> Result<T, GrinWalletInterfaceError> {
+	if !res["error"].is_null() {
+		return Err(GrinWalletInterfaceError::RemoteOwnerAPI {
+			message: res["error"]["message"]
+				.as_str()
+				.map(|s| s.to_owned())
+				.unwrap_or_else(|| res["error"].to_string()),
+		});
+	}
+	if !res["result"]["Err"].is_null() {
+		return Err(GrinWalletInterfaceError::RemoteOwnerAPI {
+			message: res["result"]["Err"].to_string(),
+		});
+	}
+	serde_json::from_value(res["result"]["Ok"].clone()).map_err(remote_err)
+}
+
+fn versioned(slate: &Slate) -> Result<VersionedSlate, GrinWalletInterfaceError> {
+	Ok(VersionedSlate::into_version(slate.clone(), SlateVersion::V4)?)
+}
+
+/// Cheap to clone, so a call can be made on a copy without holding the wallet interface lock
+/// for the length of the request
+#[derive(Clone)]
+pub struct RemoteOwner {
+	owner_api_url: String,
+	owner_api_secret: Option<String>,
+	// Receiving goes through the foreign API, which lives on its own listener
+	foreign_api_url: Option<String>,
+	foreign_api_secret: Option<String>,
+	// Negotiated by `init_secure_api`
+	shared_key: Option<SecretKey>,
+	// Returned by `open_wallet`, passed back on every call that needs the wallet open
+	token: Option<Value>,
+	// The owner API can set the active account but not report it, so remember the last one set
+	active_account: String,
+	next_id: Arc<AtomicU32>,
+}
+
+impl RemoteOwner {
+	pub fn new(
+		owner_api_url: String,
+		owner_api_secret: Option<String>,
+		foreign_api_url: Option<String>,
+		foreign_api_secret: Option<String>,
+	) -> Self {
+		RemoteOwner {
+			owner_api_url,
+			owner_api_secret,
+			foreign_api_url,
+			foreign_api_secret,
+			shared_key: None,
+			token: None,
+			active_account: "default".to_owned(),
+			next_id: Arc::new(AtomicU32::new(1)),
+		}
+	}
+
+	pub fn owner_api_url(&self) -> &str {
+		&self.owner_api_url
+	}
+
+	pub fn is_open(&self) -> bool {
+		self.token.is_some()
+	}
+
+	pub fn active_account(&self) -> &str {
+		&self.active_account
+	}
+
+	fn next_id(&self) -> u32 {
+		self.next_id.fetch_add(1, Ordering::SeqCst)
+	}
+
+	async fn post(
+		url: &str,
+		api_secret: &Option<String>,
+		body: &Value,
+	) -> Result<Value, GrinWalletInterfaceError> {
+		let auth = auth_header(api_secret);
+		let mut headers = vec![];
+		if let Some(a) = auth.as_ref() {
+			headers.push(("authorization", a.as_str()));
+		}
+		let mut resp = post_json_async(url, body, headers, Some(REQUEST_TIMEOUT)).await?;
+		if !resp.status().is_success() {
+			return Err(DownloadError::InvalidStatusCode {
+				code: resp.status(),
+				url: url.to_owned(),
+			}
+			.into());
+		}
+		let text = isahc::AsyncReadResponseExt::text(&mut resp)
+			.await
+			.map_err(remote_err)?;
+		serde_json::from_str(&text).map_err(remote_err)
+	}
+
+	/// Unencrypted owner call, only used to set up the shared key
+	async fn call_plain<T: DeserializeOwned>(
+		&self,
+		method: &str,
+		params: Value,
+	) -> Result<T, GrinWalletInterfaceError> {
+		let req = json!({
+			"jsonrpc": "2.0",
+			"method": method,
+			"id": self.next_id(),
+			"params": params,
+		});
+		let res = RemoteOwner::post(&self.owner_api_url, &self.owner_api_secret, &req).await?;
+		parse_rpc_result(&res)
+	}
+
+	/// Owner call wrapped in `encrypted_request_v3`
+	async fn call<T: DeserializeOwned>(
+		&self,
+		method: &str,
+		params: Value,
+	) -> Result<T, GrinWalletInterfaceError> {
+		let key = match self.shared_key.as_ref() {
+			Some(k) => k,
+			None => return Err(GrinWalletInterfaceError::RemoteOwnerAPINotConnected),
+		};
+		let id = self.next_id();
+		let inner = json!({
+			"jsonrpc": "2.0",
+			"method": method,
+			"id": id,
+			"params": params,
+		});
+		let enc_req =
+			EncryptedRequest::from_json(&JsonId::IntId(id), &inner, key).map_err(remote_err)?;
+		let enc_req = serde_json::to_value(&enc_req).map_err(remote_err)?;
+		let res = RemoteOwner::post(&self.owner_api_url, &self.owner_api_secret, &enc_req).await?;
+
+		// Errors in the envelope itself (bad key, bad nonce) come back unencrypted
+		if !res["error"].is_null() {
+			return parse_rpc_result(&res);
+		}
+		let enc_resp: EncryptedResponse =
+			serde_json::from_value(res["result"]["Ok"].clone()).map_err(remote_err)?;
+		let decrypted = enc_resp.decrypt(key).map_err(remote_err)?;
+		parse_rpc_result(&decrypted)
+	}
+
+	/// Foreign API calls take positional parameters and aren't encrypted
+	async fn call_foreign<T: DeserializeOwned>(
+		&self,
+		method: &str,
+		params: Value,
+	) -> Result<T, GrinWalletInterfaceError> {
+		let url = match self.foreign_api_url.as_ref() {
+			Some(u) => u,
+			None => return Err(GrinWalletInterfaceError::ForeignAPINotInstantiated),
+		};
+		let req = json!({
+			"jsonrpc": "2.0",
+			"method": method,
+			"id": self.next_id(),
+			"params": params,
+		});
+		let res = RemoteOwner::post(url, &self.foreign_api_secret, &req).await?;
+		parse_rpc_result(&res)
+	}
+
+	fn token(&self) -> Result<Value, GrinWalletInterfaceError> {
+		match self.token.as_ref() {
+			Some(t) => Ok(t.clone()),
+			None => Err(GrinWalletInterfaceError::RemoteOwnerAPINotConnected),
+		}
+	}
+
+	/// Exchange ECDH public keys with the owner API and derive the shared key
+	pub async fn init_secure_api(&mut self) -> Result<(), GrinWalletInterfaceError> {
+		let (sec_key, pub_key) = new_ephemeral_key()?;
+		let pub_key_hex = {
+			let secp = static_secp_instance();
+			let secp = secp.lock();
+			to_hex(&pub_key.serialize_vec(&secp, true)[..])
+		};
+		let server_key_hex: String = self
+			.call_plain("init_secure_api", json!({ "ecdh_pubkey": pub_key_hex }))
+			.await?;
+		let server_key = {
+			let secp = static_secp_instance();
+			let secp = secp.lock();
+			let bytes = from_hex(&server_key_hex).map_err(remote_err)?;
+			PublicKey::from_slice(&secp, &bytes).map_err(remote_err)?
+		};
+		self.shared_key = Some(derive_shared_key(&sec_key, &server_key)?);
+		Ok(())
+	}
+
+	pub async fn open_wallet(&mut self, password: &str) -> Result<(), GrinWalletInterfaceError> {
+		if self.shared_key.is_none() {
+			self.init_secure_api().await?;
+		}
+		let token: Value = self
+			.call(
+				"open_wallet",
+				json!({
+					"name": null,
+					"password": password,
+				}),
+			)
+			.await?;
+		self.token = Some(token);
+		// Wallets are opened on the default account
+		self.active_account = "default".to_owned();
+		Ok(())
+	}
+
+	pub async fn close_wallet(&mut self) -> Result<(), GrinWalletInterfaceError> {
+		let _: Value = self.call("close_wallet", json!({ "name": null })).await?;
+		self.token = None;
+		Ok(())
+	}
+
+	pub async fn change_password(
+		&self,
+		old_password: &str,
+		new_password: &str,
+	) -> Result<(), GrinWalletInterfaceError> {
+		let _: Value = self
+			.call(
+				"change_password",
+				json!({
+					"name": null,
+					"old": old_password,
+					"new": new_password,
+				}),
+			)
+			.await?;
+		Ok(())
+	}
+
+	pub async fn get_mnemonic(
+		&self,
+		password: &str,
+	) -> Result<ZeroingString, GrinWalletInterfaceError> {
+		let phrase: String = self
+			.call(
+				"get_mnemonic",
+				json!({
+					"name": null,
+					"password": password,
+				}),
+			)
+			.await?;
+		Ok(ZeroingString::from(phrase))
+	}
+
+	pub async fn start_updater(
+		&self,
+		frequency: std::time::Duration,
+	) -> Result<(), GrinWalletInterfaceError> {
+		let _: Value = self
+			.call(
+				"start_updater",
+				json!({
+					"token": self.token()?,
+					"frequency": frequency.as_millis() as u32,
+				}),
+			)
+			.await?;
+		Ok(())
+	}
+
+	pub async fn get_updater_messages(
+		&self,
+		count: u32,
+	) -> Result<Vec<StatusMessage>, GrinWalletInterfaceError> {
+		self.call("get_updater_messages", json!({ "count": count }))
+			.await
+	}
+
+	pub async fn accounts(&self) -> Result<Vec<AcctPathMapping>, GrinWalletInterfaceError> {
+		self.call("accounts", json!({ "token": self.token()? }))
+			.await
+	}
+
+	pub async fn create_account_path(&self, label: &str) -> Result<(), GrinWalletInterfaceError> {
+		let _: Value = self
+			.call(
+				"create_account_path",
+				json!({
+					"token": self.token()?,
+					"label": label,
+				}),
+			)
+			.await?;
+		Ok(())
+	}
+
+	pub async fn set_active_account(
+		&mut self,
+		label: &str,
+	) -> Result<(), GrinWalletInterfaceError> {
+		let _: Value = self
+			.call(
+				"set_active_account",
+				json!({
+					"token": self.token()?,
+					"label": label,
+				}),
+			)
+			.await?;
+		self.active_account = label.to_owned();
+		Ok(())
+	}
+
+	pub async fn retrieve_summary_info(
+		&self,
+		refresh_from_node: bool,
+		minimum_confirmations: u64,
+	) -> Result<(bool, WalletInfo), GrinWalletInterfaceError> {
+		self.call(
+			"retrieve_summary_info",
+			json!({
+				"token": self.token()?,
+				"refresh_from_node": refresh_from_node,
+				"minimum_confirmations": minimum_confirmations,
+			}),
+		)
+		.await
+	}
+
+	pub async fn retrieve_txs(
+		&self,
+		refresh_from_node: bool,
+		tx_id: Option<u32>,
+		tx_slate_id: Option<uuid::Uuid>,
+		query_args: Option<&RetrieveTxQueryArgs>,
+	) -> Result<(bool, Vec<TxLogEntry>), GrinWalletInterfaceError> {
+		self.call(
+			"retrieve_txs",
+			json!({
+				"token": self.token()?,
+				"refresh_from_node": refresh_from_node,
+				"tx_id": tx_id,
+				"tx_slate_id": tx_slate_id,
+				"query_args": query_args,
+			}),
+		)
+		.await
+	}
+
+	pub async fn retrieve_outputs(
+		&self,
+		include_spent: bool,
+		refresh_from_node: bool,
+		tx_id: Option<u32>,
+	) -> Result<(bool, Vec<OutputCommitMapping>), GrinWalletInterfaceError> {
+		self.call(
+			"retrieve_outputs",
+			json!({
+				"token": self.token()?,
+				"include_spent": include_spent,
+				"refresh_from_node": refresh_from_node,
+				"tx_id": tx_id,
+			}),
+		)
+		.await
+	}
+
+	pub async fn scan(
+		&self,
+		start_height: Option<u64>,
+		delete_unconfirmed: bool,
+	) -> Result<(), GrinWalletInterfaceError> {
+		let _: Value = self
+			.call(
+				"scan",
+				json!({
+					"token": self.token()?,
+					"start_height": start_height,
+					"delete_unconfirmed": delete_unconfirmed,
+				}),
+			)
+			.await?;
+		Ok(())
+	}
+
+	pub async fn get_slatepack_address(
+		&self,
+		derivation_index: u32,
+	) -> Result<SlatepackAddress, GrinWalletInterfaceError> {
+		self.call(
+			"get_slatepack_address",
+			json!({
+				"token": self.token()?,
+				"derivation_index": derivation_index,
+			}),
+		)
+		.await
+	}
+
+	pub async fn init_send_tx(&self, args: &InitTxArgs) -> Result<Slate, GrinWalletInterfaceError> {
+		let slate: VersionedSlate = self
+			.call(
+				"init_send_tx",
+				json!({
+					"token": self.token()?,
+					"args": args,
+				}),
+			)
+			.await?;
+		Ok(Slate::from(slate))
+	}
+
+	pub async fn issue_invoice_tx(
+		&self,
+		args: &IssueInvoiceTxArgs,
+	) -> Result<Slate, GrinWalletInterfaceError> {
+		let slate: VersionedSlate = self
+			.call(
+				"issue_invoice_tx",
+				json!({
+					"token": self.token()?,
+					"args": args,
+				}),
+			)
+			.await?;
+		Ok(Slate::from(slate))
+	}
+
+	pub async fn process_invoice_tx(
+		&self,
+		slate: &Slate,
+		args: &InitTxArgs,
+	) -> Result<Slate, GrinWalletInterfaceError> {
+		let slate: VersionedSlate = self
+			.call(
+				"process_invoice_tx",
+				json!({
+					"token": self.token()?,
+					"slate": versioned(slate)?,
+					"args": args,
+				}),
+			)
+			.await?;
+		Ok(Slate::from(slate))
+	}
+
+	pub async fn tx_lock_outputs(&self, slate: &Slate) -> Result<(), GrinWalletInterfaceError> {
+		let _: Value = self
+			.call(
+				"tx_lock_outputs",
+				json!({
+					"token": self.token()?,
+					"slate": versioned(slate)?,
+				}),
+			)
+			.await?;
+		Ok(())
+	}
+
+	pub async fn finalize_tx(&self, slate: &Slate) -> Result<Slate, GrinWalletInterfaceError> {
+		let slate: VersionedSlate = self
+			.call(
+				"finalize_tx",
+				json!({
+					"token": self.token()?,
+					"slate": versioned(slate)?,
+				}),
+			)
+			.await?;
+		Ok(Slate::from(slate))
+	}
+
+	pub async fn post_tx(
+		&self,
+		slate: &Slate,
+		fluff: bool,
+	) -> Result<(), GrinWalletInterfaceError> {
+		let _: Value = self
+			.call(
+				"post_tx",
+				json!({
+					"token": self.token()?,
+					"slate": versioned(slate)?,
+					"fluff": fluff,
+				}),
+			)
+			.await?;
+		Ok(())
+	}
+
+	pub async fn cancel_tx(
+		&self,
+		tx_id: Option<u32>,
+		tx_slate_id: Option<uuid::Uuid>,
+	) -> Result<(), GrinWalletInterfaceError> {
+		let _: Value = self
+			.call(
+				"cancel_tx",
+				json!({
+					"token": self.token()?,
+					"tx_id": tx_id,
+					"tx_slate_id": tx_slate_id,
+				}),
+			)
+			.await?;
+		Ok(())
+	}
+
+	pub async fn retrieve_payment_proof(
+		&self,
+		refresh_from_node: bool,
+		tx_id: Option<u32>,
+		tx_slate_id: Option<uuid::Uuid>,
+	) -> Result<PaymentProof, GrinWalletInterfaceError> {
+		self.call(
+			"retrieve_payment_proof",
+			json!({
+				"token": self.token()?,
+				"refresh_from_node": refresh_from_node,
+				"tx_id": tx_id,
+				"tx_slate_id": tx_slate_id,
+			}),
+		)
+		.await
+	}
+
+	pub async fn verify_payment_proof(
+		&self,
+		proof: &PaymentProof,
+	) -> Result<(bool, bool), GrinWalletInterfaceError> {
+		self.call(
+			"verify_payment_proof",
+			json!({
+				"token": self.token()?,
+				"proof": proof,
+			}),
+		)
+		.await
+	}
+
+	pub async fn create_slatepack_message(
+		&self,
+		slate: &Slate,
+		sender_index: Option<u32>,
+		recipients: Vec<SlatepackAddress>,
+	) -> Result<String, GrinWalletInterfaceError> {
+		self.call(
+			"create_slatepack_message",
+			json!({
+				"token": self.token()?,
+				"slate": versioned(slate)?,
+				"sender_index": sender_index,
+				"recipients": recipients,
+			}),
+		)
+		.await
+	}
+
+	pub async fn decode_slatepack_message(
+		&self,
+		message: String,
+		secret_indices: Vec<u32>,
+	) -> Result<Slatepack, GrinWalletInterfaceError> {
+		self.call(
+			"decode_slatepack_message",
+			json!({
+				"token": self.token()?,
+				"message": message,
+				"secret_indices": secret_indices,
+			}),
+		)
+		.await
+	}
+
+	pub async fn slate_from_slatepack_message(
+		&self,
+		message: String,
+		secret_indices: Vec<u32>,
+	) -> Result<Slate, GrinWalletInterfaceError> {
+		let slate: VersionedSlate = self
+			.call(
+				"slate_from_slatepack_message",
+				json!({
+					"token": self.token()?,
+					"message": message,
+					"secret_indices": secret_indices,
+				}),
+			)
+			.await?;
+		Ok(Slate::from(slate))
+	}
+
+	pub async fn contract_new(
+		&self,
+		args: &ContractNewArgsAPI,
+	) -> Result<Slate, GrinWalletInterfaceError> {
+		let slate: VersionedSlate = self
+			.call(
+				"contract_new",
+				json!({
+					"token": self.token()?,
+					"args": args,
+				}),
+			)
+			.await?;
+		Ok(Slate::from(slate))
+	}
+
+	pub async fn contract_sign(
+		&self,
+		slate: &Slate,
+		args: &ContractSetupArgsAPI,
+	) -> Result<Slate, GrinWalletInterfaceError> {
+		let slate: VersionedSlate = self
+			.call(
+				"contract_sign",
+				json!({
+					"token": self.token()?,
+					"slate": versioned(slate)?,
+					"args": args,
+				}),
+			)
+			.await?;
+		Ok(Slate::from(slate))
+	}
+
+	/// Receive a sender-initiated slate through the remote wallet's foreign API
+	pub async fn receive_tx(&self, slate: &Slate) -> Result<Slate, GrinWalletInterfaceError> {
+		let slate: VersionedSlate = self
+			.call_foreign("receive_tx", json!([versioned(slate)?, null, null]))
+			.await?;
+		Ok(Slate::from(slate))
+	}
+
+	/// Finalize an invoice through the foreign API, which also posts it
+	pub async fn finalize_invoice_tx(
+		&self,
+		slate: &Slate,
+	) -> Result<Slate, GrinWalletInterfaceError> {
+		let slate: VersionedSlate = self
+			.call_foreign("finalize_tx", json!([versioned(slate)?]))
+			.await?;
+		Ok(Slate::from(slate))
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use futures::executor::block_on;
+	use std::io::{BufRead, BufReader, Read, Write};
+	use std::net::TcpListener;
+	use std::sync::{Arc, Mutex};
+	use std::thread;
+
+	const TEST_SECRET: &str = "test_owner_secret";
+	const TEST_TOKEN: &str = "d096b3cb75986b3b13f80b8f5243a9edf0af4c74ac37578c5a12cfb5b59b1868";
+
+	/// Read a single HTTP request from the stream, returning the authorization
+	/// header (if any) and the parsed JSON body
+	fn read_request(
+		reader: &mut BufReader<std::net::TcpStream>,
+	) -> Option<(Option<String>, Value)> {
+		let mut content_length = 0;
+		let mut auth = None;
+		loop {
+			let mut line = String::new();
+			if reader.read_line(&mut line).ok()? == 0 {
+				return None;
+			}
+			let line = line.trim_end();
+			if line.is_empty() {
+				break;
+			}
+			let lower = line.to_lowercase();
+			if let Some(v) = lower.strip_prefix("content-length:") {
+				content_length = v.trim().parse().ok()?;
+			}
+			if lower.starts_with("authorization:") {
+				auth = Some(line["authorization:".len()..].trim().to_owned());
+			}
+		}
+		let mut body = vec![0; content_length];
+		reader.read_exact(&mut body).ok()?;
+		Some((auth, serde_json::from_slice(&body).ok()?))
+	}
+
+	fn write_response(stream: &mut std::net::TcpStream, status: &str, body: &Value) {
+		let body = body.to_string();
+		let _ = write!(
+			stream,
+			"HTTP/1.1 {}\r\ncontent-type: application/json\r\ncontent-length: {}\r\n\r\n{}",
+			status,
+			body.len(),
+			body
+		);
+		let _ = stream.flush();
+	}
+
+	fn wallet_info_json() -> Value {
+		json!({
+			"last_confirmed_height": "1000",
+			"minimum_confirmations": "2",
+			"total": "60000000000",
+			"amount_awaiting_finalization": "0",
+			"amount_awaiting_confirmation": "0",
+			"amount_immature": "0",
+			"amount_currently_spendable": "60000000000",
+			"amount_locked": "0",
+			"amount_reverted": "0",
+		})
+	}
+
+	/// Answer an owner API method the way grin-wallet would for a fresh wallet
+	fn dispatch(method: &str, params: &Value) -> Value {
+		match method {
+			"open_wallet" => json!({ "Ok": TEST_TOKEN }),
+			"close_wallet" => json!({ "Ok": null }),
+			"retrieve_summary_info" => {
+				if params["token"] != json!(TEST_TOKEN) {
+					return json!({ "Err": { "GenericError": "bad token" } });
+				}
+				json!({ "Ok": [true, wallet_info_json()] })
+			}
+			"retrieve_txs" => json!({ "Ok": [true, []] }),
+			_ => json!({ "Err": { "GenericError": format!("unknown method {}", method) } }),
+		}
+	}
+
+	/// Handle a single (outer) JSON-RPC request the way the owner API listener would
+	fn handle_request(req: &Value, shared_key: &Mutex<Option<SecretKey>>) -> Value {
+		let id = req["id"].clone();
+		match req["method"].as_str() {
+			Some("init_secure_api") => {
+				let client_key = {
+					let secp = static_secp_instance();
+					let secp = secp.lock();
+					let bytes = from_hex(req["params"]["ecdh_pubkey"].as_str().unwrap()).unwrap();
+					PublicKey::from_slice(&secp, &bytes).unwrap()
+				};
+				let (sec_key, pub_key) = new_ephemeral_key().unwrap();
+				let key = derive_shared_key(&sec_key, &client_key).unwrap();
+				*shared_key.lock().unwrap() = Some(key);
+				let secp = static_secp_instance();
+				let secp = secp.lock();
+				json!({
+					"jsonrpc": "2.0",
+					"id": id,
+					"result": { "Ok": to_hex(&pub_key.serialize_vec(&secp, true)[..]) },
+				})
+			}
+			Some("encrypted_request_v3") => {
+				let key = shared_key.lock().unwrap().clone().unwrap();
+				let enc_req: EncryptedRequest = serde_json::from_value(req.clone()).unwrap();
+				let inner = enc_req.decrypt(&key).unwrap();
+				let inner_resp = json!({
+					"jsonrpc": "2.0",
+					"id": inner["id"],
+					"result": dispatch(inner["method"].as_str().unwrap(), &inner["params"]),
+				});
+				let inner_id = JsonId::IntId(inner["id"].as_u64().unwrap() as u32);
+				let enc_resp = EncryptedResponse::from_json(&inner_id, &inner_resp, &key).unwrap();
+				json!({
+					"jsonrpc": "2.0",
+					"id": id,
+					"result": { "Ok": enc_resp },
+				})
+			}
+			_ => json!({
+				"jsonrpc": "2.0",
+				"id": id,
+				"error": { "code": -32601, "message": "Method not found" },
+			}),
+		}
+	}
+
+	/// Minimal stand-in for a grin-wallet owner API listener. Handles
+	/// `init_secure_api` and `encrypted_request_v3`, rejecting unauthenticated calls
+	fn spawn_owner_api() -> String {
+		let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+		let addr = listener.local_addr().unwrap();
+		let shared_key = Arc::new(Mutex::new(None));
+		thread::spawn(move || {
+			for stream in listener.incoming() {
+				let mut stream = match stream {
+					Ok(s) => s,
+					Err(_) => break,
+				};
+				let shared_key = shared_key.clone();
+				thread::spawn(move || {
+					let mut reader = BufReader::new(stream.try_clone().unwrap());
+					while let Some((auth, req)) = read_request(&mut reader) {
+						if auth != auth_header(&Some(TEST_SECRET.to_owned())) {
+							write_response(&mut stream, "401 Unauthorized", &json!({}));
+							continue;
+						}
+						let resp = handle_request(&req, &shared_key);
+						write_response(&mut stream, "200 OK", &resp);
+					}
+				});
+			}
+		});
+		format!("http://{}/v3/owner", addr)
+	}
+
+	#[test]
+	fn test_remote_owner_summary_info() {
+		let url = spawn_owner_api();
+		let mut remote = RemoteOwner::new(url, Some(TEST_SECRET.to_owned()), None, None);
+
+		block_on(remote.open_wallet("password")).unwrap();
+		assert!(remote.is_open());
+
+		let (refreshed, info) = block_on(remote.retrieve_summary_info(true, 2)).unwrap();
+		assert!(refreshed);
+		assert_eq!(info.total, 60_000_000_000);
+		assert_eq!(info.amount_currently_spendable, 60_000_000_000);
+
+		let (_, txs) = block_on(remote.retrieve_txs(true, None, None, None)).unwrap();
+		assert!(txs.is_empty());
+
+		block_on(remote.close_wallet()).unwrap();
+		assert!(!remote.is_open());
+	}
+
+	#[test]
+	fn test_remote_owner_errors() {
+		let url = spawn_owner_api();
+
+		// wrong api secret is rejected before any key exchange
+		let mut remote = RemoteOwner::new(url.clone(), Some("wrong".to_owned()), None, None);
+		assert!(block_on(remote.open_wallet("password")).is_err());
+
+		// calls before the wallet is opened don't hit the wire
+		let remote = RemoteOwner::new(url.clone(), Some(TEST_SECRET.to_owned()), None, None);
+		match block_on(remote.retrieve_summary_info(true, 2)) {
+			Err(GrinWalletInterfaceError::RemoteOwnerAPINotConnected) => {}
+			r => panic!("unexpected result {:?}", r.map(|_| ())),
+		}
+
+		// errors returned by the wallet are surfaced
+		let mut remote = RemoteOwner::new(url, Some(TEST_SECRET.to_owned()), None, None);
+		block_on(remote.open_wallet("password")).unwrap();
+		match block_on(remote.call::<Value>("scan", json!({}))) {
+			Err(GrinWalletInterfaceError::RemoteOwnerAPI { message }) => {
+				assert!(message.contains("unknown method scan"))
+			}
+			r => panic!("unexpected result {:?}", r),
+		}
+
+		// receiving requires a foreign API endpoint
+		assert!(remote.foreign_api_url.is_none());
+	}
+}
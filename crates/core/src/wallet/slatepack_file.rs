@@ -0,0 +1,177 @@
+//! Reading and writing slatepacks as files
+//!
+//! Slatepacks are handled as armored text everywhere else in the wallet, so files in any of the
+//! supported formats are converted to and from that representation here.
+use grin_wallet_libwallet::{Slate, SlatepackArmor, Slatepacker, SlatepackerArgs};
+
+use std::fmt::Display;
+use std::fs;
+use std::path::Path;
+
+use crate::error::GrinWalletInterfaceError;
+
+/// Largest slatepack file we'll attempt to read
+pub const MAX_SLATEPACK_FILE_SIZE: u64 = 1024 * 1024;
+
+/// Start of every armored slatepack
+const ARMOR_HEADER: &str = "BEGINSLATEPACK.";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SlatepackFileFormat {
+	/// Armored slatepack text, as copied to the clipboard
+	Armored,
+	/// Binary slatepack
+	Binary,
+	/// Slatepack serialized as JSON
+	Json,
+}
+
+impl SlatepackFileFormat {
+	pub const ALL: [SlatepackFileFormat; 3] = [
+		SlatepackFileFormat::Armored,
+		SlatepackFileFormat::Binary,
+		SlatepackFileFormat::Json,
+	];
+
+	pub const fn file_ext(&self) -> &'static str {
+		match self {
+			SlatepackFileFormat::Armored => "slatepack",
+			SlatepackFileFormat::Binary => "bin",
+			SlatepackFileFormat::Json => "json",
+		}
+	}
+
+	/// Format implied by a file's extension, defaulting to armored text
+	pub fn from_path(path: &Path) -> Self {
+		let ext = path
+			.extension()
+			.map(|e| e.to_string_lossy().to_lowercase())
+			.unwrap_or_default();
+		Self::ALL
+			.iter()
+			.find(|f| f.file_ext() == ext)
+			.copied()
+			.unwrap_or(SlatepackFileFormat::Armored)
+	}
+}
+
+impl Display for SlatepackFileFormat {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			SlatepackFileFormat::Armored => f.write_str("Slatepack"),
+			SlatepackFileFormat::Binary => f.write_str("Binary"),
+			SlatepackFileFormat::Json => f.write_str("JSON"),
+		}
+	}
+}
+
+fn packer<'a>() -> Slatepacker<'a> {
+	Slatepacker::new(SlatepackerArgs {
+		sender: None,
+		recipients: vec![],
+		dec_key: None,
+	})
+}
+
+fn invalid_file(path: &Path) -> GrinWalletInterfaceError {
+	GrinWalletInterfaceError::InvalidSlatepackFile {
+		file: path.display().to_string(),
+	}
+}
+
+fn file_io_error(path: &Path, source: std::io::Error) -> GrinWalletInterfaceError {
+	GrinWalletInterfaceError::SlatepackFileIo {
+		file: path.display().to_string(),
+		source,
+	}
+}
+
+/// Read a slatepack file in any supported format, returning it as armored text.
+/// Plain JSON slates, as written by older wallets, are also accepted.
+pub fn read_slatepack_file(path: &Path) -> Result<String, GrinWalletInterfaceError> {
+	let size = fs::metadata(path)
+		.map_err(|e| file_io_error(path, e))?
+		.len();
+	if size > MAX_SLATEPACK_FILE_SIZE {
+		return Err(GrinWalletInterfaceError::SlatepackFileTooLarge {
+			file: path.display().to_string(),
+			size,
+			max: MAX_SLATEPACK_FILE_SIZE,
+		});
+	}
+	let data = fs::read(path).map_err(|e| file_io_error(path, e))?;
+
+	// Armored text is passed on as is, the wallet decodes it later
+	if let Ok(text) = std::str::from_utf8(&data) {
+		if text.trim_start().starts_with(ARMOR_HEADER) {
+			return Ok(text.trim().to_owned());
+		}
+	}
+
+	let packer = packer();
+	let slatepack = match packer.deser_slatepack(&data, false) {
+		// Binary or JSON slatepack
+		Ok(slatepack) => slatepack,
+		// Bare slate
+		Err(_) => {
+			let text = String::from_utf8(data).map_err(|_| invalid_file(path))?;
+			let slate = Slate::deserialize_upgrade(&text).map_err(|_| invalid_file(path))?;
+			packer.create_slatepack(&slate)?
+		}
+	};
+	let armored = SlatepackArmor::encode(&slatepack)?;
+	Ok(armored.trim().to_owned())
+}
+
+/// Write an armored slatepack to a file in the given format
+pub fn write_slatepack_file(
+	path: &Path,
+	armored: &str,
+	format: SlatepackFileFormat,
+) -> Result<(), GrinWalletInterfaceError> {
+	let data = match format {
+		SlatepackFileFormat::Armored => format!("{}\n", armored.trim()).into_bytes(),
+		SlatepackFileFormat::Binary => SlatepackArmor::decode(armored.trim().as_bytes())?,
+		SlatepackFileFormat::Json => {
+			let slatepack = packer().deser_slatepack(armored.trim().as_bytes(), false)?;
+			serde_json::to_string_pretty(&slatepack)
+				.map_err(|_| invalid_file(path))?
+				.into_bytes()
+		}
+	};
+	fs::write(path, data).map_err(|e| file_io_error(path, e))
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn test_slatepack_file_formats_round_trip() {
+		let dir = tempfile::tempdir().unwrap();
+		let packer = packer();
+		let slatepack = packer.create_slatepack(&Slate::blank(2, false)).unwrap();
+		let armored = SlatepackArmor::encode(&slatepack).unwrap();
+
+		for format in SlatepackFileFormat::ALL.iter() {
+			let path = dir.path().join(format!("tx.{}", format.file_ext()));
+			assert_eq!(SlatepackFileFormat::from_path(&path), *format);
+			write_slatepack_file(&path, &armored, *format).unwrap();
+			assert_eq!(read_slatepack_file(&path).unwrap(), armored.trim());
+		}
+
+		let garbage = dir.path().join("garbage.slatepack");
+		fs::write(&garbage, "not a slatepack").unwrap();
+		assert!(matches!(
+			read_slatepack_file(&garbage),
+			Err(GrinWalletInterfaceError::InvalidSlatepackFile { .. })
+		));
+
+		let large = dir.path().join("large.slatepack");
+		fs::write(&large, vec![b'a'; MAX_SLATEPACK_FILE_SIZE as usize + 1]).unwrap();
+		assert!(matches!(
+			read_slatepack_file(&large),
+			Err(GrinWalletInterfaceError::SlatepackFileTooLarge { .. })
+		));
+	}
+}
@@ -0,0 +1,197 @@
+//! QR codes for slatepacks and slatepack addresses
+//!
+//! A slatepack can be longer than a single QR code holds reliably. Long slatepacks are split into
+//! numbered parts of the form `grinqr:{index}/{total}:{chunk}`, shown one after the other, so a
+//! scanner can put them back together.
+use qrcode::{Color, EcLevel, QrCode};
+use std::collections::BTreeMap;
+
+use crate::error::GrinWalletInterfaceError;
+
+/// Longest text encoded as a single QR code, anything longer is split into parts
+pub const MAX_QR_PART_LEN: usize = 800;
+
+/// Start of every part of a multi-part QR sequence
+pub const QR_PART_PREFIX: &str = "grinqr";
+
+/// Blank modules around the code, as required by the QR spec
+const QUIET_ZONE: usize = 4;
+
+/// Pixels per module in rendered images
+const MODULE_PIXELS: usize = 6;
+
+/// Rendered QR code as 8-bit grayscale pixels
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QrImage {
+	pub width: u32,
+	pub height: u32,
+	pub pixels: Vec<u8>,
+}
+
+impl QrImage {
+	/// Pixels as RGBA, as expected by image widgets
+	pub fn to_rgba(&self) -> Vec<u8> {
+		self.pixels
+			.iter()
+			.flat_map(|p| [*p, *p, *p, 0xff])
+			.collect()
+	}
+}
+
+/// Split text into the parts to show as QR codes, a single part if it fits in one code
+pub fn qr_parts(text: &str) -> Vec<String> {
+	let chars: Vec<char> = text.chars().collect();
+	if chars.len() <= MAX_QR_PART_LEN {
+		return vec![text.to_owned()];
+	}
+	let chunks: Vec<String> = chars
+		.chunks(MAX_QR_PART_LEN)
+		.map(|c| c.iter().collect())
+		.collect();
+	let total = chunks.len();
+	chunks
+		.into_iter()
+		.enumerate()
+		.map(|(i, chunk)| format!("{}:{}/{}:{}", QR_PART_PREFIX, i + 1, total, chunk))
+		.collect()
+}
+
+/// Render text as a QR code image
+pub fn encode_qr(text: &str) -> Result<QrImage, GrinWalletInterfaceError> {
+	let code = QrCode::with_error_correction_level(text.as_bytes(), EcLevel::M)?;
+	let modules = code.width();
+	let colors = code.to_colors();
+	let size = (modules + 2 * QUIET_ZONE) * MODULE_PIXELS;
+
+	let mut pixels = vec![0xff; size * size];
+	for (i, color) in colors.iter().enumerate() {
+		if *color != Color::Dark {
+			continue;
+		}
+		let x0 = (i % modules + QUIET_ZONE) * MODULE_PIXELS;
+		let y0 = (i / modules + QUIET_ZONE) * MODULE_PIXELS;
+		for y in y0..y0 + MODULE_PIXELS {
+			pixels[y * size + x0..y * size + x0 + MODULE_PIXELS].fill(0);
+		}
+	}
+
+	Ok(QrImage {
+		width: size as u32,
+		height: size as u32,
+		pixels,
+	})
+}
+
+/// Find and decode every QR code in an 8-bit grayscale image
+pub fn decode_qr(width: usize, height: usize, pixels: &[u8]) -> Vec<String> {
+	let mut image =
+		rqrr::PreparedImage::prepare_from_greyscale(width, height, |x, y| pixels[y * width + x]);
+	image
+		.detect_grids()
+		.into_iter()
+		.filter_map(|grid| grid.decode().ok())
+		.map(|(_, content)| content)
+		.collect()
+}
+
+/// Index, total and chunk of one part of a multi-part sequence
+fn parse_qr_part(code: &str) -> Option<(usize, usize, &str)> {
+	let mut fields = code.trim().splitn(3, ':');
+	if fields.next()? != QR_PART_PREFIX {
+		return None;
+	}
+	let (index, total) = fields.next()?.split_once('/')?;
+	let (index, total) = (index.parse().ok()?, total.parse().ok()?);
+	if index == 0 || index > total {
+		return None;
+	}
+	Some((index, total, fields.next()?))
+}
+
+/// Put decoded QR codes back together into the text they were made from. Parts of a multi-part
+/// sequence can be given in any order and more than once, but all of them are needed.
+pub fn join_qr_parts(codes: &[String]) -> Result<String, GrinWalletInterfaceError> {
+	let mut parts = BTreeMap::new();
+	let mut total = None;
+	for code in codes {
+		match parse_qr_part(code) {
+			Some((index, part_total, chunk)) => {
+				if *total.get_or_insert(part_total) != part_total {
+					return Err(GrinWalletInterfaceError::QrPartsMismatch);
+				}
+				parts.insert(index, chunk);
+			}
+			// A complete slatepack or address in a single code
+			None if total.is_none() && parts.is_empty() => return Ok(code.trim().to_owned()),
+			None => {}
+		}
+	}
+	match total {
+		None => Err(GrinWalletInterfaceError::QrNotFound),
+		Some(total) if parts.len() < total => Err(GrinWalletInterfaceError::QrPartsMissing {
+			found: parts.len(),
+			total,
+		}),
+		Some(_) => Ok(parts.values().copied().collect()),
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn test_qr_parts_and_encoding() {
+		let address = "grin1tpnxvenxvenxvenxvenxvenxvenxvenxvenxvenxvenxvenxvenqnr5dak";
+		assert_eq!(qr_parts(address), vec![address.to_owned()]);
+
+		let image = encode_qr(address).unwrap();
+		assert_eq!(image.width, image.height);
+		assert_eq!(image.pixels.len(), (image.width * image.height) as usize);
+		assert_eq!(image.to_rgba().len(), image.pixels.len() * 4);
+		// Quiet zone is blank, finder pattern starts dark
+		let corner = QUIET_ZONE * MODULE_PIXELS;
+		assert_eq!(image.pixels[0], 0xff);
+		assert_eq!(image.pixels[corner * image.width as usize + corner], 0);
+
+		let slatepack = format!("BEGINSLATEPACK. {} ENDSLATEPACK.", "x".repeat(2000));
+		let parts = qr_parts(&slatepack);
+		assert_eq!(parts.len(), 3);
+		assert!(parts[0].starts_with("grinqr:1/3:"));
+		assert!(parts[2].starts_with("grinqr:3/3:"));
+		let joined: String = parts
+			.iter()
+			.map(|p| p.splitn(3, ':').nth(2).unwrap())
+			.collect();
+		assert_eq!(joined, slatepack);
+		for part in parts.iter() {
+			assert!(encode_qr(part).is_ok());
+		}
+	}
+
+	#[test]
+	fn test_qr_decode_and_join() {
+		let address = "grin1tpnxvenxvenxvenxvenxvenxvenxvenxvenxvenxvenxvenxvenqnr5dak";
+		let image = encode_qr(address).unwrap();
+		let decoded = decode_qr(image.width as usize, image.height as usize, &image.pixels);
+		assert_eq!(decoded, vec![address.to_owned()]);
+		assert_eq!(join_qr_parts(&decoded).unwrap(), address);
+
+		let slatepack = format!("BEGINSLATEPACK. {} ENDSLATEPACK.", "x".repeat(2000));
+		let mut parts = qr_parts(&slatepack);
+		let last = parts.pop().unwrap();
+		assert!(matches!(
+			join_qr_parts(&parts),
+			Err(GrinWalletInterfaceError::QrPartsMissing { found: 2, total: 3 })
+		));
+		// Order and repeats don't matter
+		parts.insert(0, last);
+		parts.push(parts[1].clone());
+		assert_eq!(join_qr_parts(&parts).unwrap(), slatepack);
+
+		assert!(matches!(
+			join_qr_parts(&[]),
+			Err(GrinWalletInterfaceError::QrNotFound)
+		));
+	}
+}
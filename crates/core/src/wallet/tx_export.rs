@@ -0,0 +1,324 @@
+//! Exporting the transaction log for bookkeeping
+//!
+//! Every transaction becomes one record with the same fields in the same order, whichever format
+//! it's written in, so exports from different days can be compared or appended to each other.
+use chrono::NaiveDate;
+use grin_util::to_hex;
+use grin_wallet_libwallet::{TxLogEntry, TxLogEntryType};
+use serde::Serialize;
+
+use std::fmt::Display;
+use std::fs;
+use std::path::Path;
+
+use super::parse_abs_tx_amount_fee;
+use crate::config::TxNotes;
+use crate::error::GrinWalletInterfaceError;
+use crate::node::amount_to_hr_string;
+
+/// Column names of an export, in the order fields are written
+pub const TX_EXPORT_COLUMNS: [&str; 21] = [
+	"id",
+	"type",
+	"shared_tx_id",
+	"creation_time",
+	"status",
+	"ttl_cutoff_height",
+	"height",
+	"confirmed",
+	"confirmation_time",
+	"num_inputs",
+	"num_outputs",
+	"amount_credited",
+	"amount_debited",
+	"amount",
+	"fee",
+	"net_difference",
+	"payment_proof",
+	"kernel",
+	"tx_data",
+	"label",
+	"note",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TxExportFormat {
+	Csv,
+	Json,
+}
+
+impl TxExportFormat {
+	pub const ALL: [TxExportFormat; 2] = [TxExportFormat::Csv, TxExportFormat::Json];
+
+	pub const fn file_ext(&self) -> &'static str {
+		match self {
+			TxExportFormat::Csv => "csv",
+			TxExportFormat::Json => "json",
+		}
+	}
+
+	/// Format implied by a file's extension, defaulting to CSV
+	pub fn from_path(path: &Path) -> Self {
+		let ext = path
+			.extension()
+			.map(|e| e.to_string_lossy().to_lowercase())
+			.unwrap_or_default();
+		Self::ALL
+			.iter()
+			.find(|f| f.file_ext() == ext)
+			.copied()
+			.unwrap_or(TxExportFormat::Csv)
+	}
+}
+
+impl Display for TxExportFormat {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			TxExportFormat::Csv => f.write_str("CSV"),
+			TxExportFormat::Json => f.write_str("JSON"),
+		}
+	}
+}
+
+/// One exported transaction. Fields are declared in the order of `TX_EXPORT_COLUMNS`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct TxExportRecord {
+	pub id: u32,
+	#[serde(rename = "type")]
+	pub tx_type: String,
+	pub shared_tx_id: Option<String>,
+	pub creation_time: String,
+	pub status: String,
+	pub ttl_cutoff_height: Option<u64>,
+	pub height: Option<u64>,
+	pub confirmed: bool,
+	pub confirmation_time: Option<String>,
+	pub num_inputs: usize,
+	pub num_outputs: usize,
+	pub amount_credited: String,
+	pub amount_debited: String,
+	/// Amount moved, without the fee
+	pub amount: String,
+	pub fee: String,
+	pub net_difference: String,
+	pub payment_proof: String,
+	pub kernel: Option<String>,
+	pub tx_data: Option<String>,
+	pub label: Option<String>,
+	pub note: Option<String>,
+}
+
+impl TxExportRecord {
+	pub fn new(tx: &TxLogEntry, notes: &TxNotes) -> Self {
+		let is_send = matches!(
+			tx.tx_type,
+			TxLogEntryType::TxSent | TxLogEntryType::TxSentCancelled
+		);
+		let (amount, fee) = parse_abs_tx_amount_fee(tx, is_send);
+		let net_difference = if tx.amount_credited >= tx.amount_debited {
+			amount_to_hr_string(tx.amount_credited - tx.amount_debited, true)
+		} else {
+			format!(
+				"-{}",
+				amount_to_hr_string(tx.amount_debited - tx.amount_credited, true)
+			)
+		};
+		let status = if matches!(
+			tx.tx_type,
+			TxLogEntryType::TxSentCancelled | TxLogEntryType::TxReceivedCancelled
+		) {
+			"cancelled"
+		} else if tx.confirmed {
+			"confirmed"
+		} else {
+			"unconfirmed"
+		};
+		let payment_proof = match &tx.payment_proof {
+			Some(p) if p.receiver_signature.is_some() => "signed",
+			Some(_) => "pending",
+			None => "",
+		};
+		let note = tx.tx_slate_id.as_ref().and_then(|id| notes.get(id));
+		let non_empty = |s: &str| Some(s.to_owned()).filter(|s| !s.is_empty());
+
+		TxExportRecord {
+			id: tx.id,
+			tx_type: tx.tx_type.to_string().replace('\n', " "),
+			shared_tx_id: tx.tx_slate_id.map(|id| id.to_string()),
+			creation_time: tx.creation_ts.to_rfc3339(),
+			status: status.to_owned(),
+			ttl_cutoff_height: tx.ttl_cutoff_height,
+			height: tx.kernel_lookup_min_height,
+			confirmed: tx.confirmed,
+			confirmation_time: tx.confirmation_ts.map(|t| t.to_rfc3339()),
+			num_inputs: tx.num_inputs,
+			num_outputs: tx.num_outputs,
+			amount_credited: amount_to_hr_string(tx.amount_credited, true),
+			amount_debited: amount_to_hr_string(tx.amount_debited, true),
+			amount,
+			fee,
+			net_difference,
+			payment_proof: payment_proof.to_owned(),
+			kernel: tx.kernel_excess.map(|k| to_hex(&k.0[..])),
+			tx_data: tx.stored_tx.clone(),
+			label: note.and_then(|n| non_empty(&n.label)),
+			note: note.and_then(|n| non_empty(&n.note)),
+		}
+	}
+
+	/// Fields as text, in the order of `TX_EXPORT_COLUMNS`
+	fn fields(&self) -> [String; 21] {
+		let opt = |o: &Option<String>| o.clone().unwrap_or_default();
+		let opt_num = |o: Option<u64>| o.map(|n| n.to_string()).unwrap_or_default();
+		[
+			self.id.to_string(),
+			self.tx_type.clone(),
+			opt(&self.shared_tx_id),
+			self.creation_time.clone(),
+			self.status.clone(),
+			opt_num(self.ttl_cutoff_height),
+			opt_num(self.height),
+			self.confirmed.to_string(),
+			opt(&self.confirmation_time),
+			self.num_inputs.to_string(),
+			self.num_outputs.to_string(),
+			self.amount_credited.clone(),
+			self.amount_debited.clone(),
+			self.amount.clone(),
+			self.fee.clone(),
+			self.net_difference.clone(),
+			self.payment_proof.clone(),
+			opt(&self.kernel),
+			opt(&self.tx_data),
+			opt(&self.label),
+			opt(&self.note),
+		]
+	}
+}
+
+/// Records for the transactions created within the given dates, both inclusive, oldest first
+pub fn tx_export_records<'a>(
+	txs: impl IntoIterator<Item = &'a TxLogEntry>,
+	notes: &TxNotes,
+	from: Option<NaiveDate>,
+	to: Option<NaiveDate>,
+) -> Vec<TxExportRecord> {
+	let mut txs: Vec<&TxLogEntry> = txs
+		.into_iter()
+		.filter(|tx| {
+			let date = tx.creation_ts.date_naive();
+			from.map_or(true, |from| date >= from) && to.map_or(true, |to| date <= to)
+		})
+		.collect();
+	txs.sort_by(|a, b| (a.creation_ts, a.id).cmp(&(b.creation_ts, b.id)));
+	txs.into_iter()
+		.map(|tx| TxExportRecord::new(tx, notes))
+		.collect()
+}
+
+/// Quote a CSV field if it contains anything that would break the row
+pub(super) fn csv_field(field: &str) -> String {
+	if field.contains(|c| matches!(c, ',' | '"' | '\n' | '\r')) {
+		format!("\"{}\"", field.replace('"', "\"\""))
+	} else {
+		field.to_owned()
+	}
+}
+
+pub fn tx_export_csv(records: &[TxExportRecord]) -> String {
+	let mut csv = TX_EXPORT_COLUMNS.join(",");
+	csv.push('\n');
+	for record in records {
+		let fields: Vec<String> = record.fields().iter().map(|f| csv_field(f)).collect();
+		csv.push_str(&fields.join(","));
+		csv.push('\n');
+	}
+	csv
+}
+
+pub fn tx_export_json(records: &[TxExportRecord]) -> Result<String, GrinWalletInterfaceError> {
+	serde_json::to_string_pretty(records).map_err(GrinWalletInterfaceError::TxExportSerialize)
+}
+
+/// Write records to a file in the given format
+pub fn write_tx_export(
+	path: &Path,
+	records: &[TxExportRecord],
+	format: TxExportFormat,
+) -> Result<(), GrinWalletInterfaceError> {
+	let data = match format {
+		TxExportFormat::Csv => tx_export_csv(records),
+		TxExportFormat::Json => tx_export_json(records)?,
+	};
+	fs::write(path, data).map_err(|source| GrinWalletInterfaceError::TxExportIo {
+		file: path.display().to_string(),
+		source,
+	})
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::config::TxNote;
+	use chrono::{TimeZone, Utc};
+	use grin_keychain::Identifier;
+	use uuid::Uuid;
+
+	fn tx(id: u32, day: u32, tx_type: TxLogEntryType, amount: u64) -> TxLogEntry {
+		let mut tx = TxLogEntry::new(Identifier::zero(), tx_type.clone(), id);
+		tx.creation_ts = Utc.with_ymd_and_hms(2023, 3, day, 12, 0, 0).unwrap();
+		tx.tx_slate_id = Some(Uuid::from_u128(id as u128));
+		match tx_type {
+			TxLogEntryType::TxSent => tx.amount_debited = amount,
+			_ => tx.amount_credited = amount,
+		}
+		tx
+	}
+
+	#[test]
+	fn test_tx_export_records_and_formats() {
+		let txs = vec![
+			tx(2, 20, TxLogEntryType::TxSent, 2_000_000_000),
+			tx(1, 10, TxLogEntryType::TxReceived, 1_000_000_000),
+			tx(3, 30, TxLogEntryType::TxReceived, 3_000_000_000),
+		];
+		let mut notes = TxNotes::default();
+		notes.set(
+			&Uuid::from_u128(2),
+			TxNote {
+				label: "Rent".to_owned(),
+				note: "March, \"flat 2\"".to_owned(),
+			},
+		);
+
+		let from = NaiveDate::from_ymd_opt(2023, 3, 10);
+		let to = NaiveDate::from_ymd_opt(2023, 3, 20);
+		let records = tx_export_records(&txs, &notes, from, to);
+		assert_eq!(records.iter().map(|r| r.id).collect::<Vec<_>>(), vec![1, 2]);
+		assert_eq!(records[1].net_difference, "-2.0");
+		assert_eq!(records[1].label.as_deref(), Some("Rent"));
+		assert_eq!(records[0].label, None);
+
+		let csv = tx_export_csv(&records);
+		let lines: Vec<&str> = csv.lines().collect();
+		assert_eq!(lines.len(), 3);
+		assert_eq!(lines[0], TX_EXPORT_COLUMNS.join(","));
+		assert!(lines[2].ends_with(",Rent,\"March, \"\"flat 2\"\"\""));
+
+		// JSON fields come out in the same order as the CSV columns
+		let json = tx_export_json(&records).unwrap();
+		let positions: Vec<usize> = TX_EXPORT_COLUMNS
+			.iter()
+			.map(|c| json.find(&format!("\"{}\":", c)).unwrap())
+			.collect();
+		assert!(positions.windows(2).all(|w| w[0] < w[1]));
+
+		let dir = tempfile::tempdir().unwrap();
+		for format in TxExportFormat::ALL.iter() {
+			let path = dir.path().join(format!("txs.{}", format.file_ext()));
+			assert_eq!(TxExportFormat::from_path(&path), *format);
+			write_tx_export(&path, &records, *format).unwrap();
+			assert!(fs::read_to_string(&path).unwrap().contains("Rent"));
+		}
+	}
+}
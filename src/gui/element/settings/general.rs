@@ -282,18 +282,24 @@ pub fn data_container<'a>(state: &'a StateContainer, config: &Config) -> Contain
 		let title = Container::new(Text::new(localized_string("currency")).size(DEFAULT_FONT_SIZE))
 			.style(grin_gui_core::theme::ContainerStyle::NormalBackground);
 
-		let pick_list = PickList::new(&Currency::ALL[..], Some(config.currency), |c| {
+		// a fiat currency set in grin-gui.yml by hand may not be listed
+		let mut currencies = Currency::all();
+		if !currencies.contains(&config.currency) {
+			currencies.push(config.currency);
+		}
+
+		let pick_list = PickList::new(currencies, Some(config.currency), |c| {
 			Message::Interaction(Interaction::GeneralSettingsViewInteraction(
 				LocalViewInteraction::CurrencySelected(c),
 			))
 		})
 		.text_size(14)
-		.width(Length::Fixed(120.0))
+		.width(Length::Fixed(220.0))
 		.style(grin_gui_core::theme::PickListStyle::Primary);
 
 		let container = Container::new(pick_list)
 			.center_y()
-			.width(Length::Fixed(120.0))
+			.width(Length::Fixed(220.0))
 			.style(grin_gui_core::theme::ContainerStyle::NormalBackground);
 
 		Column::new()
@@ -14,6 +14,7 @@ pub enum Mode {
 	Init,
 	CreateWallet(String),
 	ImportWallet,
+	RemoteWallet,
 	Operation,
 }
 
@@ -63,6 +64,9 @@ pub fn data_container<'a>(state: &'a StateContainer, config: &'a Config) -> Cont
 		Mode::ImportWallet => {
 			setup::wallet_import::data_container(config, &state.setup_state.import_wallet_state)
 		}
+		Mode::RemoteWallet => {
+			setup::wallet_remote::data_container(config, &state.setup_state.remote_wallet_state)
+		}
 	};
 
 	let column = Column::new()
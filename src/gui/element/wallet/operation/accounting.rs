@@ -0,0 +1,445 @@
+use crate::log_error;
+use grin_gui_core::config::{Config, Currency};
+use grin_gui_core::wallet::{AccountingPeriod, AccountingReport, TxExportFormat, TxLogEntry};
+use native_dialog::FileDialog;
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+use strfmt::strfmt;
+
+use {
+	super::super::super::{
+		BUTTON_HEIGHT, BUTTON_WIDTH, DEFAULT_FONT_SIZE, DEFAULT_HEADER_FONT_SIZE, DEFAULT_PADDING,
+		SMALLER_FONT_SIZE,
+	},
+	super::home,
+	crate::gui::{GrinGui, Interaction, Message},
+	crate::localization::{localized_number_format, localized_string},
+	crate::Result,
+	anyhow::Context,
+	grin_gui_core::node::amount_to_hr_string,
+	grin_gui_core::theme::{Button, Column, Container, Element, Row, Scrollable, Text},
+	iced::widget::Space,
+	iced::{alignment, Alignment, Command, Length},
+};
+
+#[derive(Default)]
+pub struct StateContainer {
+	pub period: AccountingPeriod,
+	/// All transactions of the wallet, retrieved when the report is shown
+	txs: Vec<TxLogEntry>,
+	pub report: Option<AccountingReport>,
+	/// Where the last export went
+	exported: Option<String>,
+}
+
+impl StateContainer {
+	pub fn clear(&mut self) {
+		*self = Default::default();
+	}
+}
+
+#[derive(Debug, Clone)]
+pub enum LocalViewInteraction {
+	Back,
+	Refresh,
+	TxsUpdateSuccess(Vec<TxLogEntry>),
+	TxsUpdateFailure(Arc<RwLock<Option<anyhow::Error>>>),
+	Period(AccountingPeriod),
+	Export,
+}
+
+/// Value the retrieved transactions with the current price history and currency
+fn update_report(grin_gui: &mut GrinGui) {
+	let operation_state = &mut grin_gui.wallet_state.operation_state;
+	let state = &mut operation_state.accounting_state;
+	state.report = Some(AccountingReport::new(
+		&state.txs,
+		&operation_state.home_state.price_history,
+		grin_gui.config.currency,
+		state.period,
+		&operation_state.home_state.tx_list_display_state.tx_notes,
+	));
+}
+
+pub fn handle_message<'a>(
+	grin_gui: &mut GrinGui,
+	message: LocalViewInteraction,
+) -> Result<Command<Message>> {
+	let state = &mut grin_gui.wallet_state.operation_state.accounting_state;
+
+	match message {
+		LocalViewInteraction::Back => {
+			log::debug!("Interaction::WalletOperationAccountingViewInteraction(Back)");
+			state.exported = None;
+			grin_gui.wallet_state.operation_state.mode =
+				crate::gui::element::wallet::operation::Mode::Home;
+		}
+		LocalViewInteraction::Refresh => {
+			let w = grin_gui.wallet_backend.clone();
+			let fut = move || w.get_txs(None);
+			return Ok(Command::perform(fut(), |tx_list_res| {
+				let message = match tx_list_res.context("Failed to retrieve transaction list") {
+					Ok((_, txs)) => LocalViewInteraction::TxsUpdateSuccess(txs),
+					Err(e) => {
+						LocalViewInteraction::TxsUpdateFailure(Arc::new(RwLock::new(Some(e))))
+					}
+				};
+				Message::Interaction(Interaction::WalletOperationAccountingViewInteraction(
+					message,
+				))
+			}));
+		}
+		LocalViewInteraction::TxsUpdateSuccess(txs) => {
+			state.txs = txs;
+			update_report(grin_gui);
+		}
+		LocalViewInteraction::TxsUpdateFailure(err) => {
+			grin_gui.error = err.write().unwrap().take();
+			if let Some(e) = grin_gui.error.as_ref() {
+				log_error(e);
+			}
+		}
+		LocalViewInteraction::Period(period) => {
+			state.period = period;
+			update_report(grin_gui);
+		}
+		LocalViewInteraction::Export => {
+			log::debug!("Interaction::WalletOperationAccountingViewInteraction(Export)");
+			state.exported = None;
+			let report = match state.report.as_ref() {
+				Some(r) => r,
+				None => return Ok(Command::none()),
+			};
+
+			let mut file_dialog = FileDialog::new();
+			for format in TxExportFormat::ALL.iter() {
+				file_dialog = file_dialog.add_filter(&format.to_string(), &[format.file_ext()]);
+			}
+			let mut path = match file_dialog.show_save_single_file() {
+				Ok(Some(path)) => path,
+				Ok(None) => return Ok(Command::none()),
+				Err(e) => {
+					log::debug!("accounting.rs::LocalViewInteraction::Export {}", e);
+					return Ok(Command::none());
+				}
+			};
+			if path.extension().is_none() {
+				path.set_extension(TxExportFormat::Csv.file_ext());
+			}
+
+			match report
+				.write(&path, TxExportFormat::from_path(&path))
+				.context("Failed to export accounting report")
+			{
+				Ok(()) => {
+					let mut vars = HashMap::new();
+					vars.insert("path".to_string(), path.display().to_string());
+					let fmt = localized_string("accounting-exported");
+					state.exported = Some(strfmt(&fmt, &vars).unwrap());
+				}
+				Err(e) => {
+					log_error(&e);
+					grin_gui.error = Some(e);
+				}
+			}
+		}
+	}
+
+	Ok(Command::none())
+}
+
+pub fn data_container<'a>(
+	config: &'a Config,
+	state: &'a StateContainer,
+	home_state: &'a home::StateContainer,
+) -> Container<'a, Message> {
+	// Title row
+	let title = Text::new(localized_string("accounting-title"))
+		.size(DEFAULT_HEADER_FONT_SIZE)
+		.horizontal_alignment(alignment::Horizontal::Center);
+
+	let title_container = Container::new(title)
+		.style(grin_gui_core::theme::ContainerStyle::BrightBackground)
+		.padding(iced::Padding::from([
+			2, // top
+			0, // right
+			2, // bottom
+			5, // left
+		]));
+
+	let header_row = Row::new().push(title_container);
+
+	let header_container = Container::new(header_row).padding(iced::Padding::from([
+		0,                      // top
+		0,                      // right
+		DEFAULT_PADDING as u16, // bottom
+		0,                      // left
+	]));
+
+	let unit_spacing = 15.0;
+
+	let button_height = Length::Fixed(BUTTON_HEIGHT);
+	let button_width = Length::Fixed(BUTTON_WIDTH);
+
+	let currency = config.currency.code();
+	let mut vars = HashMap::new();
+	vars.insert("currency".to_string(), currency.clone());
+	let fmt = localized_string("accounting-instruction");
+	let instruction = Text::new(strfmt(&fmt, &vars).unwrap())
+		.size(SMALLER_FONT_SIZE)
+		.horizontal_alignment(alignment::Horizontal::Left);
+
+	let mut column = Column::new()
+		.push(
+			Container::new(instruction)
+				.style(grin_gui_core::theme::ContainerStyle::NormalBackground),
+		)
+		.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)));
+
+	// without another currency or its prices, values are just the grin amounts or missing
+	let warning = if config.currency == Currency::GRIN {
+		Some(localized_string("accounting-grin"))
+	} else if home_state.price_history.is_empty() {
+		Some(localized_string("accounting-no-prices"))
+	} else {
+		None
+	};
+	if let Some(warning) = warning {
+		column = column
+			.push(
+				Text::new(warning)
+					.size(DEFAULT_FONT_SIZE)
+					.style(grin_gui_core::theme::text::TextStyle::Warning),
+			)
+			.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)));
+	}
+
+	let button = |label: &str, message: LocalViewInteraction, selected: bool| {
+		let label_container =
+			Container::new(Text::new(localized_string(label)).size(DEFAULT_FONT_SIZE))
+				.width(button_width)
+				.height(button_height)
+				.center_x()
+				.center_y()
+				.align_x(alignment::Horizontal::Center);
+
+		let style = if selected {
+			grin_gui_core::theme::ButtonStyle::Selected
+		} else {
+			grin_gui_core::theme::ButtonStyle::Primary
+		};
+		let button: Element<Interaction> = Button::new(label_container)
+			.style(style)
+			.on_press(Interaction::WalletOperationAccountingViewInteraction(
+				message,
+			))
+			.into();
+
+		let container = Container::new(button.map(Message::Interaction)).padding(1);
+		Container::new(container)
+			.style(grin_gui_core::theme::ContainerStyle::Segmented)
+			.padding(1)
+	};
+
+	let mut period_row = Row::new().align_items(Alignment::Center);
+	for period in AccountingPeriod::ALL.iter() {
+		let label = match period {
+			AccountingPeriod::Month => "accounting-month",
+			AccountingPeriod::Quarter => "accounting-quarter",
+			AccountingPeriod::Year => "accounting-year",
+		};
+		period_row = period_row
+			.push(button(
+				label,
+				LocalViewInteraction::Period(*period),
+				state.period == *period,
+			))
+			.push(Space::new(Length::Fixed(unit_spacing), Length::Fixed(0.0)));
+	}
+	column = column
+		.push(period_row)
+		.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)));
+
+	let cell = |text: String, width: f32| {
+		Container::new(Text::new(text).size(DEFAULT_FONT_SIZE)).width(Length::Fixed(width))
+	};
+	let row = |cells: [String; 8]| {
+		let widths = [100.0, 60.0, 120.0, 120.0, 120.0, 120.0, 100.0, 100.0];
+		cells
+			.into_iter()
+			.zip(widths)
+			.fold(Row::new().spacing(5), |row, (text, width)| {
+				row.push(cell(text, width))
+			})
+	};
+
+	match state.report.as_ref() {
+		Some(report) if !report.totals.is_empty() => {
+			let value_header = |key: &str| {
+				let mut vars = HashMap::new();
+				vars.insert("currency".to_string(), currency.clone());
+				strfmt(&localized_string(key), &vars).unwrap()
+			};
+			column = column.push(row([
+				localized_string("accounting-period"),
+				localized_string("accounting-count"),
+				localized_string("accounting-received"),
+				value_header("accounting-value"),
+				localized_string("accounting-sent"),
+				value_header("accounting-value"),
+				localized_string("accounting-fees"),
+				value_header("accounting-value"),
+			]));
+			// values in the report's precision, written the way the language writes numbers
+			let number_format = localized_number_format();
+			let value = |v: f64| number_format.format(v, report.currency.precision());
+			for totals in report.totals.iter() {
+				column = column.push(row([
+					totals.period.clone(),
+					totals.count.to_string(),
+					amount_to_hr_string(totals.received, true),
+					value(totals.received_value),
+					amount_to_hr_string(totals.sent, true),
+					value(totals.sent_value),
+					amount_to_hr_string(totals.fees, true),
+					value(totals.fees_value),
+				]));
+			}
+			column = column.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)));
+
+			let unpriced: usize = report.totals.iter().map(|t| t.unpriced).sum();
+			if unpriced > 0 && config.currency != Currency::GRIN {
+				let mut vars = HashMap::new();
+				vars.insert("count".to_string(), unpriced.to_string());
+				let fmt = localized_string("accounting-unpriced");
+				column = column
+					.push(
+						Text::new(strfmt(&fmt, &vars).unwrap())
+							.size(DEFAULT_FONT_SIZE)
+							.style(grin_gui_core::theme::text::TextStyle::Warning),
+					)
+					.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)));
+			}
+		}
+		Some(_) => {
+			column = column
+				.push(Text::new(localized_string("accounting-empty")).size(DEFAULT_FONT_SIZE))
+				.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)));
+		}
+		None => {}
+	}
+
+	if let Some(exported) = state.exported.as_ref() {
+		column = column
+			.push(Text::new(exported).size(DEFAULT_FONT_SIZE))
+			.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)));
+	}
+
+	let button_row = Row::new()
+		.push(button("tx-export", LocalViewInteraction::Export, false))
+		.push(Space::new(Length::Fixed(unit_spacing), Length::Fixed(0.0)))
+		.push(button("back", LocalViewInteraction::Back, false))
+		.align_items(Alignment::Center);
+
+	column = column.push(button_row);
+
+	let form_container = Container::new(column)
+		.width(Length::Fill)
+		.padding(iced::Padding::from([
+			0, // top
+			0, // right
+			0, // bottom
+			5, // left
+		]));
+
+	// form container should be scrollable in tiny windows
+	let scrollable = Scrollable::new(form_container)
+		.height(Length::Fill)
+		.style(grin_gui_core::theme::ScrollableStyle::Primary);
+
+	let content = Container::new(scrollable)
+		.width(Length::Fill)
+		.height(Length::Shrink)
+		.style(grin_gui_core::theme::ContainerStyle::NormalBackground);
+
+	let wrapper_column = Column::new()
+		.height(Length::Fill)
+		.push(header_container)
+		.push(content);
+
+	// Returns the final container.
+	Container::new(wrapper_column).padding(iced::Padding::from([
+		DEFAULT_PADDING, // top
+		DEFAULT_PADDING, // right
+		DEFAULT_PADDING, // bottom
+		DEFAULT_PADDING, // left
+	]))
+}
+
+#[cfg(test)]
+mod test {
+	use super::super::test_util::{funded_gui, run_command, start_send};
+	use super::super::tx_list_display::{self, LocalViewInteraction as TI};
+	use super::{LocalViewInteraction as AI, *};
+	use chrono::{TimeZone, Utc};
+
+	#[test]
+	fn test_accounting_report_from_price_history() {
+		let (fake, mut grin_gui) = funded_gui();
+		grin_gui.config.currency = Currency::USD;
+		for amount in [1_000_000_000, 2_000_000_000] {
+			start_send(&fake, amount);
+		}
+		for (tx, month) in fake.state().txs.iter_mut().zip([3, 4]) {
+			tx.confirmed = true;
+			tx.confirmation_ts = Some(Utc.with_ymd_and_hms(2023, month, 15, 12, 0, 0).unwrap());
+		}
+		let price_history = &mut grin_gui
+			.wallet_state
+			.operation_state
+			.home_state
+			.price_history;
+		for (month, price) in [(3, 0.5), (4, 2.0)] {
+			let day = Utc.with_ymd_and_hms(2023, month, 1, 0, 0, 0).unwrap();
+			price_history.insert(day, price);
+		}
+
+		// Showing the report retrieves all transactions before valuing them
+		let mut messages =
+			run_command(tx_list_display::handle_message(&mut grin_gui, TI::ShowReport).unwrap());
+		while let Some(message) = messages.pop() {
+			let l = match message {
+				Message::Interaction(Interaction::WalletOperationAccountingViewInteraction(l)) => l,
+				m => panic!("unexpected message {:?}", m),
+			};
+			messages.extend(run_command(handle_message(&mut grin_gui, l).unwrap()));
+		}
+		assert_eq!(
+			grin_gui.wallet_state.operation_state.mode,
+			super::super::Mode::Accounting
+		);
+
+		let report = |grin_gui: &GrinGui| {
+			let state = &grin_gui.wallet_state.operation_state.accounting_state;
+			let report = state.report.as_ref().unwrap();
+			report
+				.totals
+				.iter()
+				.map(|t| (t.period.clone(), report.format_value(t.sent_value)))
+				.collect::<Vec<_>>()
+		};
+		assert_eq!(
+			report(&grin_gui),
+			vec![
+				("2023-03".to_owned(), "0.5000".to_owned()),
+				("2023-04".to_owned(), "4.0000".to_owned()),
+			]
+		);
+
+		handle_message(&mut grin_gui, AI::Period(AccountingPeriod::Year)).unwrap();
+		assert_eq!(
+			report(&grin_gui),
+			vec![("2023".to_owned(), "4.5000".to_owned())]
+		);
+	}
+}
@@ -2,7 +2,7 @@ use super::tx_list::{self, ExpandType};
 use crate::log_error;
 use async_std::prelude::FutureExt;
 use grin_gui_core::{
-	config::Config,
+	config::{Config, TxMethod},
 	wallet::{TxLogEntry, TxLogEntryType},
 };
 //use grin_gui_widgets::{header};
@@ -49,6 +49,9 @@ impl Default for StateContainer {
 pub enum Action {
 	CreateTx,
 	ApplyTx,
+	IssueInvoice,
+	Outputs,
+	Scan,
 }
 
 #[derive(Debug, Clone)]
@@ -73,6 +76,14 @@ pub fn handle_message<'a>(
 			);
 			match action {
 				Action::CreateTx => {
+					// Start from the wallet's saved send parameters
+					if let Some(wallet) = grin_gui.config.current_wallet() {
+						grin_gui
+							.wallet_state
+							.operation_state
+							.create_tx_state
+							.load_send_defaults(&wallet.send_defaults);
+					}
 					grin_gui.wallet_state.operation_state.mode =
 						crate::gui::element::wallet::operation::Mode::CreateTx
 				}
@@ -80,6 +91,24 @@ pub fn handle_message<'a>(
 					grin_gui.wallet_state.operation_state.mode =
 						crate::gui::element::wallet::operation::Mode::ApplyTx
 				}
+				Action::IssueInvoice => {
+					grin_gui.wallet_state.operation_state.mode =
+						crate::gui::element::wallet::operation::Mode::IssueInvoice
+				}
+				Action::Outputs => {
+					grin_gui.wallet_state.operation_state.mode =
+						crate::gui::element::wallet::operation::Mode::Outputs;
+					let fut = move || async {};
+					return Ok(Command::perform(fut(), |_| {
+						Message::Interaction(Interaction::WalletOperationOutputsViewInteraction(
+							super::outputs::LocalViewInteraction::Refresh,
+						))
+					}));
+				}
+				Action::Scan => {
+					grin_gui.wallet_state.operation_state.mode =
+						crate::gui::element::wallet::operation::Mode::Scan
+				}
 			}
 		}
 	}
@@ -143,6 +172,60 @@ pub fn data_container<'a>(
 
 	let apply_tx_button: Element<Interaction> = apply_tx_button.into();
 
+	let issue_invoice_container =
+		Container::new(Text::new(localized_string("wallet-issue-invoice")).size(DEFAULT_FONT_SIZE))
+			.width(button_width)
+			.align_y(alignment::Vertical::Center)
+			.align_x(alignment::Horizontal::Center);
+
+	let mut issue_invoice_button = Button::new(issue_invoice_container)
+		.width(button_width)
+		.style(grin_gui_core::theme::ButtonStyle::Primary);
+
+	if home_state.node_synched {
+		issue_invoice_button = issue_invoice_button.on_press(
+			Interaction::WalletOperationHomeActionMenuViewInteraction(
+				LocalViewInteraction::SelectAction(Action::IssueInvoice),
+			),
+		)
+	}
+
+	let issue_invoice_button: Element<Interaction> = issue_invoice_button.into();
+
+	let outputs_container =
+		Container::new(Text::new(localized_string("wallet-outputs")).size(DEFAULT_FONT_SIZE))
+			.width(button_width)
+			.align_y(alignment::Vertical::Center)
+			.align_x(alignment::Horizontal::Center);
+
+	let outputs_button: Element<Interaction> = Button::new(outputs_container)
+		.width(button_width)
+		.style(grin_gui_core::theme::ButtonStyle::Primary)
+		.on_press(Interaction::WalletOperationHomeActionMenuViewInteraction(
+			LocalViewInteraction::SelectAction(Action::Outputs),
+		))
+		.into();
+
+	let scan_container =
+		Container::new(Text::new(localized_string("wallet-scan")).size(DEFAULT_FONT_SIZE))
+			.width(button_width)
+			.align_y(alignment::Vertical::Center)
+			.align_x(alignment::Horizontal::Center);
+
+	let mut scan_button = Button::new(scan_container)
+		.width(button_width)
+		.style(grin_gui_core::theme::ButtonStyle::Primary);
+
+	// Scanning needs a synched node to read the chain from
+	if home_state.node_synched {
+		scan_button =
+			scan_button.on_press(Interaction::WalletOperationHomeActionMenuViewInteraction(
+				LocalViewInteraction::SelectAction(Action::Scan),
+			))
+	}
+
+	let scan_button: Element<Interaction> = scan_button.into();
+
 	// TODO refactor since many of the buttons around the UI repeat this theme
 	let create_container = Container::new(create_tx_button.map(Message::Interaction)).padding(1);
 	let create_container = Container::new(create_container)
@@ -154,13 +237,42 @@ pub fn data_container<'a>(
 		.style(grin_gui_core::theme::ContainerStyle::Segmented)
 		.padding(1);
 
-	let menu_column = Row::new()
+	let issue_invoice_container =
+		Container::new(issue_invoice_button.map(Message::Interaction)).padding(1);
+	let issue_invoice_container = Container::new(issue_invoice_container)
+		.style(grin_gui_core::theme::ContainerStyle::Segmented)
+		.padding(1);
+
+	let outputs_container = Container::new(outputs_button.map(Message::Interaction)).padding(1);
+	let outputs_container = Container::new(outputs_container)
+		.style(grin_gui_core::theme::ContainerStyle::Segmented)
+		.padding(1);
+
+	let scan_container = Container::new(scan_button.map(Message::Interaction)).padding(1);
+	let scan_container = Container::new(scan_container)
+		.style(grin_gui_core::theme::ContainerStyle::Segmented)
+		.padding(1);
+
+	let mut menu_column = Row::new()
 		.push(description_container)
 		.push(Space::with_width(Length::Fixed(DEFAULT_PADDING)))
 		.push(create_container)
 		.push(Space::with_width(Length::Fixed(DEFAULT_PADDING)))
 		.push(apply_container);
 
+	// Invoices are issued through the legacy invoice API
+	if config.tx_method == TxMethod::Legacy {
+		menu_column = menu_column
+			.push(Space::with_width(Length::Fixed(DEFAULT_PADDING)))
+			.push(issue_invoice_container);
+	}
+
+	let menu_column = menu_column
+		.push(Space::with_width(Length::Fixed(DEFAULT_PADDING)))
+		.push(outputs_container)
+		.push(Space::with_width(Length::Fixed(DEFAULT_PADDING)))
+		.push(scan_container);
+
 	Container::new(menu_column).padding(iced::Padding::from([
 		5, // top
 		5, // right
@@ -3,11 +3,18 @@ use crate::log_error;
 use async_std::prelude::FutureExt;
 use grin_gui_core::{
 	config::Config,
-	wallet::{Slate, Slatepack, TxLogEntry, TxLogEntryType},
+	config::TxMethod,
+	price::PriceQuote,
+	wallet::{
+		read_slatepack_file, Slate, Slatepack, SlatepackAddress, SlatepackFileFormat, TxLogEntry,
+		TxLogEntryType,
+	},
 };
 use iced_aw::Card;
 use iced_core::Widget;
+use native_dialog::FileDialog;
 use serde::de;
+use std::convert::TryFrom;
 use std::path::PathBuf;
 
 use super::tx_list::{HeaderState, TxList};
@@ -24,7 +31,7 @@ use {
 	grin_gui_core::theme::{
 		Button, Column, Container, Element, PickList, Row, Scrollable, TableRow, Text, TextInput,
 	},
-	grin_gui_core::wallet::{StatusMessage, WalletInfo, WalletInterface},
+	grin_gui_core::wallet::{StatusMessage, WalletInfo},
 	grin_gui_core::{node::amount_to_hr_string, theme::ColorPalette},
 	iced::widget::{button, pick_list, scrollable, text_input, Checkbox, Space},
 	iced::{alignment, Alignment, Command, Length},
@@ -75,6 +82,12 @@ pub enum LocalViewInteraction {
 	ApplyTransaction(String),
 	ReadFromClipboardSuccess(String),
 	ReadFromClipboardFailure,
+	/// armored slatepack, decoded slatepack with its slate and transaction
+	SlatepackDecryptOk(String, (Slatepack, Slate, Option<TxLogEntry>)),
+	/// text to show instead of the slatepack
+	SlatepackDecryptError(String),
+	OpenSlatepackFile,
+	OpenQrImage,
 	ShowSlate,
 	CancelSelfSpend,
 }
@@ -100,8 +113,8 @@ pub fn handle_message<'a>(
 					}
 					None => None,
 				};
-				let w = grin_gui.wallet_interface.clone();
-				let fut = move || WalletInterface::cancel_tx(w, tx_id.unwrap());
+				let w = grin_gui.wallet_backend.clone();
+				let fut = move || w.cancel_tx(tx_id.unwrap());
 
 				return Ok(Command::perform(fut(), |_| {
 					return Message::Interaction(
@@ -132,29 +145,93 @@ pub fn handle_message<'a>(
 		}
 		LocalViewInteraction::ReadFromClipboardSuccess(value) => {
 			debug!("Read from clipboard: {}", value);
-			let w = grin_gui.wallet_interface.clone();
-			let decode_res = WalletInterface::decrypt_slatepack(w, value.clone());
-			match decode_res {
+			return Ok(read_slatepack(
+				grin_gui,
+				value,
+				localized_string("tx-slatepack-read-failure"),
+			));
+		}
+		LocalViewInteraction::OpenSlatepackFile => {
+			let extensions = SlatepackFileFormat::ALL
+				.iter()
+				.map(|f| f.file_ext())
+				.chain(std::iter::once("txt"))
+				.collect::<Vec<_>>();
+			let file_dialog = FileDialog::new().add_filter("Slatepack", &extensions);
+			let path = match file_dialog.show_open_single_file() {
+				Ok(Some(path)) => path,
+				Ok(None) => return Ok(Command::none()),
+				Err(e) => {
+					log::debug!("apply_tx.rs::LocalViewInteraction::OpenSlatepackFile {}", e);
+					return Ok(Command::none());
+				}
+			};
+			match read_slatepack_file(&path) {
+				Ok(value) => {
+					debug!("Read slatepack from {}", path.display());
+					return Ok(read_slatepack(
+						grin_gui,
+						value,
+						localized_string("tx-slatepack-file-read-failure"),
+					));
+				}
 				Err(e) => {
-					state.slatepack_read_data = localized_string("tx-slatepack-read-failure");
+					error!("Failed to read slatepack file: {}", e);
+					state.slatepack_read_data =
+						format!("{}: {}", localized_string("tx-slatepack-file-invalid"), e);
 					state.confirm_state.slatepack_parsed = None;
 					state.slatepack_read_data_full = Default::default();
 					state.can_continue = false;
 				}
-				Ok(s) => {
-					debug!("{}", s.1);
-					// Truncate a bit for compact display purposes
-					let mut s1 = value.clone();
-					s1.truncate(27);
-					let s2 = value
-						.clone()
-						.split_off(usize::saturating_sub(value.len(), 23));
-					let short_display = format!("{}...{}", s1, s2);
-
-					state.slatepack_read_data_full = value.clone();
-					state.slatepack_read_data = short_display;
-					state.confirm_state.slatepack_parsed = Some(s);
-					state.can_continue = true;
+			}
+		}
+		LocalViewInteraction::OpenQrImage => {
+			let file_dialog = FileDialog::new().add_filter("Image", &["png", "jpg", "jpeg"]);
+			let paths = match file_dialog.show_open_multiple_file() {
+				Ok(paths) if !paths.is_empty() => paths,
+				Ok(_) => return Ok(Command::none()),
+				Err(e) => {
+					log::debug!("apply_tx.rs::LocalViewInteraction::OpenQrImage {}", e);
+					return Ok(Command::none());
+				}
+			};
+			match super::qr::read_qr_images(&paths) {
+				// An address can't be applied, so start a send to it instead
+				Ok(value) if SlatepackAddress::try_from(value.as_str()).is_ok() => {
+					debug!("Read slatepack address from QR code: {}", value);
+					let operation_state = &mut grin_gui.wallet_state.operation_state;
+					match grin_gui.config.tx_method {
+						TxMethod::Legacy => {
+							if let Some(wallet) = grin_gui.config.current_wallet() {
+								operation_state
+									.create_tx_state
+									.load_send_defaults(&wallet.send_defaults);
+							}
+							operation_state.create_tx_state.recipient_address_value = value;
+						}
+						TxMethod::Contracts => {
+							operation_state
+								.create_tx_contracts_state
+								.recipient_address_value = value;
+						}
+					}
+					operation_state.mode = crate::gui::element::wallet::operation::Mode::CreateTx;
+				}
+				Ok(value) => {
+					debug!("Read slatepack from QR code");
+					return Ok(read_slatepack(
+						grin_gui,
+						value,
+						localized_string("tx-slatepack-qr-read-failure"),
+					));
+				}
+				Err(e) => {
+					error!("Failed to read slatepack QR code: {:?}", e);
+					state.slatepack_read_data =
+						format!("{}: {}", localized_string("tx-slatepack-qr-invalid"), e);
+					state.confirm_state.slatepack_parsed = None;
+					state.slatepack_read_data_full = Default::default();
+					state.can_continue = false;
 				}
 			}
 		}
@@ -173,6 +250,27 @@ pub fn handle_message<'a>(
 		LocalViewInteraction::ReadFromClipboardFailure => {
 			error!("Failed to read from clipboard");
 		}
+		LocalViewInteraction::SlatepackDecryptOk(value, s) => {
+			debug!("{}", s.1);
+			// Truncate a bit for compact display purposes
+			let mut s1 = value.clone();
+			s1.truncate(27);
+			let s2 = value
+				.clone()
+				.split_off(usize::saturating_sub(value.len(), 23));
+			let short_display = format!("{}...{}", s1, s2);
+
+			state.slatepack_read_data_full = value.clone();
+			state.slatepack_read_data = short_display;
+			state.confirm_state.slatepack_parsed = Some(s);
+			state.can_continue = true;
+		}
+		LocalViewInteraction::SlatepackDecryptError(failure_text) => {
+			state.slatepack_read_data = failure_text;
+			state.confirm_state.slatepack_parsed = None;
+			state.slatepack_read_data_full = Default::default();
+			state.can_continue = false;
+		}
 		LocalViewInteraction::ShowSlate => {
 			// ensure back button on showing slate screen comes back here
 			grin_gui
@@ -203,8 +301,8 @@ pub fn handle_message<'a>(
 					}
 					None => None,
 				};
-				let w = grin_gui.wallet_interface.clone();
-				let fut = move || WalletInterface::cancel_tx(w, tx_id.unwrap());
+				let w = grin_gui.wallet_backend.clone();
+				let fut = move || w.cancel_tx(tx_id.unwrap());
 				debug!("Cancelling self-spend tx: {:?}", tx_id);
 
 				return Ok(Command::perform(fut(), |_| {
@@ -222,7 +320,27 @@ pub fn handle_message<'a>(
 	Ok(Command::none())
 }
 
-pub fn data_container<'a>(config: &'a Config, state: &'a StateContainer) -> Container<'a, Message> {
+/// Decode and decrypt an armored slatepack, showing `failure_text` if this wallet can't
+fn read_slatepack(grin_gui: &mut GrinGui, value: String, failure_text: String) -> Command<Message> {
+	let fut = grin_gui.wallet_backend.decrypt_slatepack(value.clone());
+	Command::perform(fut, move |r| match r {
+		Ok(s) => Message::Interaction(Interaction::WalletOperationApplyTxViewInteraction(
+			LocalViewInteraction::SlatepackDecryptOk(value, s),
+		)),
+		Err(e) => {
+			debug!("Failed to decrypt slatepack: {}", e);
+			Message::Interaction(Interaction::WalletOperationApplyTxViewInteraction(
+				LocalViewInteraction::SlatepackDecryptError(failure_text),
+			))
+		}
+	})
+}
+
+pub fn data_container<'a>(
+	config: &'a Config,
+	state: &'a StateContainer,
+	quote: Option<PriceQuote>,
+) -> Container<'a, Message> {
 	let unit_spacing = 15.0;
 	let mut title_key = localized_string("apply-tx");
 
@@ -366,7 +484,7 @@ pub fn data_container<'a>(config: &'a Config, state: &'a StateContainer) -> Cont
 	if state.can_continue {
 		// Add parsed slatepack contents area here
 		let parsed_slate_content =
-			super::apply_tx_confirm::data_container(config, &state.confirm_state);
+			super::apply_tx_confirm::data_container(config, &state.confirm_state, quote);
 
 		slatepack_area = slatepack_area.push(parsed_slate_content);
 	}
@@ -390,6 +508,36 @@ pub fn data_container<'a>(config: &'a Config, state: &'a StateContainer) -> Cont
 
 	let submit_button: Element<Interaction> = submit_button.into();
 
+	let open_file_button_label_container =
+		Container::new(Text::new(localized_string("tx-open-file")).size(DEFAULT_FONT_SIZE))
+			.width(button_width)
+			.height(button_height)
+			.center_x()
+			.center_y()
+			.align_x(alignment::Horizontal::Center);
+
+	let open_file_button: Element<Interaction> = Button::new(open_file_button_label_container)
+		.style(grin_gui_core::theme::ButtonStyle::Primary)
+		.on_press(Interaction::WalletOperationApplyTxViewInteraction(
+			LocalViewInteraction::OpenSlatepackFile,
+		))
+		.into();
+
+	let open_qr_button_label_container =
+		Container::new(Text::new(localized_string("tx-open-qr")).size(DEFAULT_FONT_SIZE))
+			.width(button_width)
+			.height(button_height)
+			.center_x()
+			.center_y()
+			.align_x(alignment::Horizontal::Center);
+
+	let open_qr_button: Element<Interaction> = Button::new(open_qr_button_label_container)
+		.style(grin_gui_core::theme::ButtonStyle::Primary)
+		.on_press(Interaction::WalletOperationApplyTxViewInteraction(
+			LocalViewInteraction::OpenQrImage,
+		))
+		.into();
+
 	let continue_button_label_container =
 		Container::new(Text::new(localized_string("tx-continue")).size(DEFAULT_FONT_SIZE))
 			.width(button_width)
@@ -431,6 +579,16 @@ pub fn data_container<'a>(config: &'a Config, state: &'a StateContainer) -> Cont
 		.style(grin_gui_core::theme::ContainerStyle::Segmented)
 		.padding(1);
 
+	let open_file_container = Container::new(open_file_button.map(Message::Interaction)).padding(1);
+	let open_file_container = Container::new(open_file_container)
+		.style(grin_gui_core::theme::ContainerStyle::Segmented)
+		.padding(1);
+
+	let open_qr_container = Container::new(open_qr_button.map(Message::Interaction)).padding(1);
+	let open_qr_container = Container::new(open_qr_container)
+		.style(grin_gui_core::theme::ContainerStyle::Segmented)
+		.padding(1);
+
 	let continue_container = Container::new(continue_button.map(Message::Interaction)).padding(1);
 	let continue_container = Container::new(continue_container)
 		.style(grin_gui_core::theme::ContainerStyle::Segmented)
@@ -445,6 +603,10 @@ pub fn data_container<'a>(config: &'a Config, state: &'a StateContainer) -> Cont
 	if !state.can_continue {
 		button_row = button_row
 			.push(submit_container)
+			.push(Space::new(Length::Fixed(unit_spacing), Length::Fixed(0.0)))
+			.push(open_file_container)
+			.push(Space::new(Length::Fixed(unit_spacing), Length::Fixed(0.0)))
+			.push(open_qr_container)
 			.push(Space::new(Length::Fixed(unit_spacing), Length::Fixed(0.0)));
 	} else {
 		button_row = button_row
@@ -1,8 +1,10 @@
+use super::quote;
 use super::tx_list::{self, ExpandType};
 use crate::log_error;
 use async_std::prelude::FutureExt;
 use grin_gui_core::{
 	config::{Config, TxMethod},
+	price::PriceQuote,
 	wallet::{
 		ContractNewArgsAPI, ContractSetupArgsAPI, ProofArgs, Slate, SlateState, Slatepack,
 		TxLogEntry, TxLogEntryType,
@@ -28,8 +30,11 @@ use {
 	grin_gui_core::theme::{
 		Button, Column, Container, Element, PickList, Row, Scrollable, TableRow, Text, TextInput,
 	},
-	grin_gui_core::wallet::{parse_abs_tx_amount_fee, StatusMessage, WalletInfo, WalletInterface},
-	grin_gui_core::{node::amount_to_hr_string, theme::ColorPalette},
+	grin_gui_core::wallet::{parse_abs_tx_amount_fee, StatusMessage, WalletInfo},
+	grin_gui_core::{
+		node::{amount_from_hr_string, amount_to_hr_string},
+		theme::ColorPalette,
+	},
 	iced::widget::{button, pick_list, scrollable, text_input, Checkbox, Space},
 	iced::{alignment, Alignment, Command, Length},
 	serde::{Deserialize, Serialize},
@@ -109,15 +114,13 @@ pub fn handle_message<'a>(
 				Some(s) => s.to_string(),
 			};
 
-			let w = grin_gui.wallet_interface.clone();
+			let w = grin_gui.wallet_backend.clone();
 			let out_slate = slate.clone();
 			if grin_gui.config.tx_method == TxMethod::Legacy {
 				match slate.state {
 					SlateState::Standard1 => {
 						state.is_signing = true;
-						let fut = move || {
-							WalletInterface::receive_tx_from_s1(w, out_slate, sp_sending_address)
-						};
+						let fut = move || w.receive_tx_from_s1(out_slate, sp_sending_address);
 
 						return Ok(Command::perform(fut(), |r| {
 							match r.context("Failed to Progress Transaction") {
@@ -140,7 +143,7 @@ pub fn handle_message<'a>(
 					}
 					SlateState::Standard2 => {
 						state.is_signing = true;
-						let fut = move || WalletInterface::finalize_from_s2(w, out_slate, true);
+						let fut = move || w.finalize_from_s2(out_slate, true);
 
 						return Ok(Command::perform(fut(), |r| {
 							match r.context("Failed to Progress Transaction") {
@@ -161,6 +164,68 @@ pub fn handle_message<'a>(
 							}
 						}));
 					}
+					SlateState::Invoice1 => {
+						// Pay the requested amount with the wallet's usual send parameters
+						let params = grin_gui
+							.config
+							.current_wallet()
+							.map(|w| w.send_defaults.clone())
+							.unwrap_or_default();
+						let args = super::create_tx::init_tx_args(
+							grin_gui
+								.wallet_state
+								.operation_state
+								.home_state
+								.active_account
+								.clone(),
+							slate.amount,
+							&params,
+						);
+						state.is_signing = true;
+						let fut = move || w.process_invoice_tx(out_slate, args, sp_sending_address);
+
+						return Ok(Command::perform(fut(), |r| {
+							match r.context("Failed to Pay Invoice") {
+								Ok((slate, enc_slate)) => Message::Interaction(
+									Interaction::WalletOperationApplyTxConfirmViewInteraction(
+										LocalViewInteraction::TxAcceptSuccess(
+											slate, enc_slate, false,
+										),
+									),
+								),
+								Err(e) => Message::Interaction(
+									Interaction::WalletOperationApplyTxConfirmViewInteraction(
+										LocalViewInteraction::TxAcceptFailure(Arc::new(
+											RwLock::new(Some(e)),
+										)),
+									),
+								),
+							}
+						}));
+					}
+					SlateState::Invoice2 => {
+						state.is_signing = true;
+						let fut = move || w.finalize_invoice_tx(out_slate);
+
+						return Ok(Command::perform(fut(), |r| {
+							match r.context("Failed to Finalize Invoice") {
+								Ok((slate, enc_slate)) => Message::Interaction(
+									Interaction::WalletOperationApplyTxConfirmViewInteraction(
+										LocalViewInteraction::TxAcceptSuccess(
+											slate, enc_slate, true,
+										),
+									),
+								),
+								Err(e) => Message::Interaction(
+									Interaction::WalletOperationApplyTxConfirmViewInteraction(
+										LocalViewInteraction::TxAcceptFailure(Arc::new(
+											RwLock::new(Some(e)),
+										)),
+									),
+								),
+							}
+						}));
+					}
 					_ => {
 						log::error!("Slate state not yet supported");
 						return Ok(Command::none());
@@ -209,7 +274,7 @@ pub fn handle_message<'a>(
 
 				if state.is_self_send {
 					debug!("SLATE STATE SELF_SEND: {}", slate.state);
-					let fut = move || WalletInterface::post_tx(w, out_slate);
+					let fut = move || w.post_tx(out_slate);
 					return Ok(Command::perform(fut(), |r| {
 						match r.context("Failed to Progress Transaction") {
 							Ok((slate, enc_slate)) => {
@@ -235,7 +300,7 @@ pub fn handle_message<'a>(
 				} else {
 					let fut = move || {
 						debug!("SIGN ARGS: {:?}", args);
-						WalletInterface::contract_sign(w, out_slate, args, sp_sending_address, true)
+						w.contract_sign(out_slate, args, sp_sending_address, true)
 					};
 
 					return Ok(Command::perform(fut(), |r| {
@@ -331,7 +396,11 @@ fn parse_info_strings(in_str: &str, amount: &str) -> String {
 	amount_included
 }
 
-pub fn data_container<'a>(config: &'a Config, state: &'a StateContainer) -> Container<'a, Message> {
+pub fn data_container<'a>(
+	config: &'a Config,
+	state: &'a StateContainer,
+	quote: Option<PriceQuote>,
+) -> Container<'a, Message> {
 	let unit_spacing = 15.0;
 
 	if state.slatepack_parsed.is_none() {
@@ -370,6 +439,18 @@ pub fn data_container<'a>(config: &'a Config, state: &'a StateContainer) -> Cont
 			amt_stmt
 		}
 		SlateState::Standard3 => "This transaction is finalised - Standard workflow".to_owned(),
+		SlateState::Invoice1 => {
+			other_wallet_label = localized_string("tx-recipient-name");
+			reception_instruction_1 = localized_string("tx-invoice-payment-instruction");
+			reception_instruction_2 = localized_string("tx-invoice-payment-instruction-2");
+			parse_info_strings(&localized_string("tx-sending"), &amount)
+		}
+		SlateState::Invoice2 => {
+			other_wallet_label = localized_string("tx-sender-name");
+			reception_instruction_1 = "".to_owned();
+			reception_instruction_2 = localized_string("tx-s1-finalization-3");
+			parse_info_strings(&localized_string("tx-i1-finalization-1"), &amount)
+		}
 		_ => "Support still in development".to_owned(),
 	};
 
@@ -430,7 +511,25 @@ pub fn data_container<'a>(config: &'a Config, state: &'a StateContainer) -> Cont
 	let state_container =
 		Container::new(state).style(grin_gui_core::theme::ContainerStyle::BrightBackground);
 
-	let state_row = Row::new().push(state_container);
+	let mut state_row = Row::new()
+		.push(state_container)
+		.align_items(Alignment::Center);
+
+	// What the amount is worth at the current price
+	let amount_value = quote
+		.as_ref()
+		.zip(amount_from_hr_string(&amount).ok().filter(|a| *a > 0));
+	if let Some((quote, amount)) = amount_value {
+		let value = Text::new(format!(
+			"{} ({})",
+			quote::approx_value(quote, amount),
+			quote::rate(quote)
+		))
+		.size(SMALLER_FONT_SIZE);
+		state_row = state_row
+			.push(Space::with_width(Length::Fixed(10.0)))
+			.push(value);
+	}
 
 	// Sender address
 	let sender_address_label = Text::new(format!("{} ", other_wallet_label))
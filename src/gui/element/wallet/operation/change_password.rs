@@ -0,0 +1,330 @@
+use crate::log_error;
+use grin_gui_core::config::Config;
+
+use {
+	super::super::super::{
+		BUTTON_HEIGHT, BUTTON_WIDTH, DEFAULT_FONT_SIZE, DEFAULT_HEADER_FONT_SIZE, DEFAULT_PADDING,
+	},
+	crate::gui::{GrinGui, Interaction, Message},
+	crate::localization::localized_string,
+	crate::Result,
+	anyhow::Context,
+	grin_gui_core::theme::{Button, Column, Container, Element, Row, Text, TextInput},
+	iced::widget::Space,
+	iced::{alignment, Alignment, Command, Length},
+	std::sync::{Arc, RwLock},
+};
+
+#[derive(Default)]
+pub struct StateContainer {
+	pub old_password_value: String,
+	pub new_password_value: String,
+	pub repeat_password_value: String,
+	pub is_changing: bool,
+	/// Set once the password has been changed, until the form is used again
+	pub changed: bool,
+}
+
+impl StateContainer {
+	pub fn clear(&mut self) {
+		*self = Default::default();
+	}
+
+	fn passwords_match(&self) -> bool {
+		self.new_password_value == self.repeat_password_value
+	}
+
+	fn can_submit(&self) -> bool {
+		!self.is_changing
+			&& !self.old_password_value.is_empty()
+			&& !self.new_password_value.is_empty()
+			&& self.passwords_match()
+	}
+}
+
+#[derive(Debug, Clone)]
+pub enum LocalViewInteraction {
+	Back,
+	OldPassword(String),
+	NewPassword(String),
+	RepeatPassword(String),
+	Submit,
+	PasswordChangedOk,
+	PasswordChangeError(Arc<RwLock<Option<anyhow::Error>>>),
+}
+
+pub fn handle_message<'a>(
+	grin_gui: &mut GrinGui,
+	message: LocalViewInteraction,
+) -> Result<Command<Message>> {
+	let state = &mut grin_gui
+		.wallet_state
+		.operation_state
+		.change_password_state;
+
+	match message {
+		LocalViewInteraction::Back => {
+			log::debug!("Interaction::WalletOperationChangePasswordViewInteraction(Back)");
+			state.clear();
+			grin_gui.wallet_state.operation_state.mode =
+				crate::gui::element::wallet::operation::Mode::Home;
+		}
+		LocalViewInteraction::OldPassword(s) => {
+			state.changed = false;
+			state.old_password_value = s;
+		}
+		LocalViewInteraction::NewPassword(s) => {
+			state.changed = false;
+			state.new_password_value = s;
+		}
+		LocalViewInteraction::RepeatPassword(s) => {
+			state.changed = false;
+			state.repeat_password_value = s;
+		}
+		LocalViewInteraction::Submit => {
+			if !state.can_submit() {
+				return Ok(Command::none());
+			}
+			grin_gui.error.take();
+			state.is_changing = true;
+
+			let old_password = std::mem::take(&mut state.old_password_value);
+			let new_password = state.new_password_value.clone();
+			let w = grin_gui.wallet_backend.clone();
+			let fut = move || w.change_password(old_password, new_password);
+			return Ok(Command::perform(fut(), |r| {
+				match r.context("Failed to change wallet password") {
+					Ok(()) => Message::Interaction(
+						Interaction::WalletOperationChangePasswordViewInteraction(
+							LocalViewInteraction::PasswordChangedOk,
+						),
+					),
+					Err(e) => Message::Interaction(
+						Interaction::WalletOperationChangePasswordViewInteraction(
+							LocalViewInteraction::PasswordChangeError(Arc::new(RwLock::new(
+								Some(e),
+							))),
+						),
+					),
+				}
+			}));
+		}
+		LocalViewInteraction::PasswordChangedOk => {
+			state.clear();
+			state.changed = true;
+		}
+		LocalViewInteraction::PasswordChangeError(err) => {
+			state.is_changing = false;
+			grin_gui.error = err.write().unwrap().take();
+			if let Some(e) = grin_gui.error.as_ref() {
+				log_error(e);
+			}
+		}
+	}
+
+	Ok(Command::none())
+}
+
+fn password_input<'a>(
+	label: &str,
+	value: &'a str,
+	on_input: fn(String) -> LocalViewInteraction,
+) -> Column<'a, Message> {
+	let label = Text::new(localized_string(label)).size(DEFAULT_FONT_SIZE);
+
+	let input = TextInput::new("", value)
+		.on_input(move |s| Interaction::WalletOperationChangePasswordViewInteraction(on_input(s)))
+		.on_submit(Interaction::WalletOperationChangePasswordViewInteraction(
+			LocalViewInteraction::Submit,
+		))
+		.size(DEFAULT_FONT_SIZE)
+		.padding(6)
+		.width(Length::Fixed(200.0))
+		.style(grin_gui_core::theme::TextInputStyle::AddonsQuery)
+		.secure(true);
+	let input: Element<Interaction> = input.into();
+
+	Column::new()
+		.push(label)
+		.push(Space::with_height(Length::Fixed(5.0)))
+		.push(input.map(Message::Interaction))
+}
+
+pub fn data_container<'a>(config: &'a Config, state: &'a StateContainer) -> Container<'a, Message> {
+	// Title row
+	let title = Text::new(localized_string("change-password-title"))
+		.size(DEFAULT_HEADER_FONT_SIZE)
+		.horizontal_alignment(alignment::Horizontal::Center);
+
+	let title_container = Container::new(title)
+		.style(grin_gui_core::theme::ContainerStyle::BrightBackground)
+		.padding(iced::Padding::from([
+			2, // top
+			0, // right
+			2, // bottom
+			5, // left
+		]));
+
+	let header_row = Row::new().push(title_container);
+	let header_container = Container::new(header_row).padding(iced::Padding::from([
+		0,                      // top
+		0,                      // right
+		DEFAULT_PADDING as u16, // bottom
+		0,                      // left
+	]));
+
+	let old_password = password_input(
+		"change-password-old",
+		&state.old_password_value,
+		LocalViewInteraction::OldPassword,
+	);
+	let new_password = password_input(
+		"change-password-new",
+		&state.new_password_value,
+		LocalViewInteraction::NewPassword,
+	);
+	let repeat_password = password_input(
+		"password-repeat",
+		&state.repeat_password_value,
+		LocalViewInteraction::RepeatPassword,
+	);
+
+	let status = if state.changed {
+		localized_string("change-password-success")
+	} else if !state.passwords_match() && !state.repeat_password_value.is_empty() {
+		localized_string("setup-grin-passwords-dont-match")
+	} else {
+		String::new()
+	};
+	let status_text = Text::new(status).size(DEFAULT_FONT_SIZE);
+
+	let button_height = Length::Fixed(BUTTON_HEIGHT);
+	let button_width = Length::Fixed(BUTTON_WIDTH);
+
+	let submit_button_label_container = Container::new(
+		Text::new(localized_string("change-password-submit")).size(DEFAULT_FONT_SIZE),
+	)
+	.width(button_width)
+	.height(button_height)
+	.center_x()
+	.center_y()
+	.align_x(alignment::Horizontal::Center);
+
+	let mut submit_button = Button::new(submit_button_label_container)
+		.style(grin_gui_core::theme::ButtonStyle::Primary);
+	if state.can_submit() {
+		submit_button = submit_button.on_press(
+			Interaction::WalletOperationChangePasswordViewInteraction(LocalViewInteraction::Submit),
+		);
+	}
+	let submit_button: Element<Interaction> = submit_button.into();
+
+	let back_button_label_container =
+		Container::new(Text::new(localized_string("back")).size(DEFAULT_FONT_SIZE))
+			.width(button_width)
+			.height(button_height)
+			.center_x()
+			.center_y()
+			.align_x(alignment::Horizontal::Center);
+
+	let back_button: Element<Interaction> = Button::new(back_button_label_container)
+		.style(grin_gui_core::theme::ButtonStyle::Primary)
+		.on_press(Interaction::WalletOperationChangePasswordViewInteraction(
+			LocalViewInteraction::Back,
+		))
+		.into();
+
+	let submit_container = Container::new(submit_button.map(Message::Interaction)).padding(1);
+	let submit_container = Container::new(submit_container)
+		.style(grin_gui_core::theme::ContainerStyle::Segmented)
+		.padding(1);
+
+	let back_container = Container::new(back_button.map(Message::Interaction)).padding(1);
+	let back_container = Container::new(back_container)
+		.style(grin_gui_core::theme::ContainerStyle::Segmented)
+		.padding(1);
+
+	let button_row = Row::new()
+		.push(submit_container)
+		.push(Space::with_width(Length::Fill))
+		.push(back_container)
+		.align_items(Alignment::Center);
+
+	let unit_spacing = 15.0;
+	let wrapper_column = Column::new()
+		.height(Length::Fill)
+		.push(header_container)
+		.push(old_password)
+		.push(Space::with_height(Length::Fixed(unit_spacing)))
+		.push(new_password)
+		.push(Space::with_height(Length::Fixed(unit_spacing)))
+		.push(repeat_password)
+		.push(Space::with_height(Length::Fixed(unit_spacing)))
+		.push(status_text)
+		.push(Space::with_height(Length::Fill))
+		.push(button_row);
+
+	// Returns the final container.
+	Container::new(wrapper_column).padding(iced::Padding::from([
+		DEFAULT_PADDING, // top
+		DEFAULT_PADDING, // right
+		DEFAULT_PADDING, // bottom
+		DEFAULT_PADDING, // left
+	]))
+}
+
+#[cfg(test)]
+mod test {
+	use super::super::test_util::{gui_with_fake, run_command};
+	use super::{LocalViewInteraction as CP, *};
+	use grin_gui_core::wallet::fake::FakeWalletBackend;
+
+	#[test]
+	fn test_change_password() {
+		let fake = Arc::new(FakeWalletBackend::open_with_balance("password", 0));
+		let mut grin_gui = gui_with_fake(fake.clone());
+
+		// A wrong old password is reported and leaves the wallet open and unchanged
+		handle_message(&mut grin_gui, CP::OldPassword("wrong".to_owned())).unwrap();
+		handle_message(&mut grin_gui, CP::NewPassword("secret".to_owned())).unwrap();
+		handle_message(&mut grin_gui, CP::RepeatPassword("secret".to_owned())).unwrap();
+		let cmd = handle_message(&mut grin_gui, CP::Submit).unwrap();
+		let message = match run_command(cmd).remove(0) {
+			Message::Interaction(Interaction::WalletOperationChangePasswordViewInteraction(l)) => l,
+			m => panic!("unexpected message {:?}", m),
+		};
+		assert!(matches!(message, CP::PasswordChangeError(_)));
+		handle_message(&mut grin_gui, message).unwrap();
+		assert!(grin_gui.error.is_some());
+		assert!(
+			!grin_gui
+				.wallet_state
+				.operation_state
+				.change_password_state
+				.is_changing
+		);
+		assert_eq!(fake.state().password, "password");
+		assert!(fake.state().is_open);
+
+		handle_message(&mut grin_gui, CP::OldPassword("password".to_owned())).unwrap();
+
+		// Nothing happens until the new password is repeated correctly
+		handle_message(&mut grin_gui, CP::RepeatPassword("secre".to_owned())).unwrap();
+		let cmd = handle_message(&mut grin_gui, CP::Submit).unwrap();
+		assert!(run_command(cmd).is_empty());
+
+		handle_message(&mut grin_gui, CP::RepeatPassword("secret".to_owned())).unwrap();
+		let cmd = handle_message(&mut grin_gui, CP::Submit).unwrap();
+		let message = match run_command(cmd).remove(0) {
+			Message::Interaction(Interaction::WalletOperationChangePasswordViewInteraction(l)) => l,
+			m => panic!("unexpected message {:?}", m),
+		};
+		assert!(matches!(message, CP::PasswordChangedOk));
+		handle_message(&mut grin_gui, message).unwrap();
+
+		let state = &grin_gui.wallet_state.operation_state.change_password_state;
+		assert!(state.changed);
+		assert!(state.new_password_value.is_empty());
+		assert_eq!(fake.state().password, "secret");
+	}
+}
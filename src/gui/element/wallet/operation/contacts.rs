@@ -0,0 +1,417 @@
+use crate::log_error;
+use grin_gui_core::config::{Config, Contact, Contacts};
+use grin_gui_core::error::ContactError;
+use grin_gui_core::fs::PersistentData;
+use grin_gui_core::node::ChainTypes;
+use native_dialog::FileDialog;
+use std::fs;
+
+use {
+	super::super::super::{
+		BUTTON_HEIGHT, BUTTON_WIDTH, DEFAULT_FONT_SIZE, DEFAULT_HEADER_FONT_SIZE, DEFAULT_PADDING,
+		SMALLER_FONT_SIZE,
+	},
+	crate::gui::{GrinGui, Interaction, Message},
+	crate::localization::localized_string,
+	crate::Result,
+	anyhow::Context,
+	grin_gui_core::theme::{Button, Column, Container, Element, Row, Scrollable, Text, TextInput},
+	iced::widget::Space,
+	iced::{alignment, Alignment, Command, Length},
+};
+
+#[derive(Default)]
+pub struct StateContainer {
+	/// Address book, loaded at startup
+	pub contacts: Contacts,
+	pub name_value: String,
+	pub address_value: String,
+	pub notes_value: String,
+	/// Why the contact in the form couldn't be saved
+	form_error: Option<ContactError>,
+	/// Result of the last import
+	imported: Option<(usize, usize)>,
+}
+
+impl StateContainer {
+	fn clear_form(&mut self) {
+		self.name_value = String::new();
+		self.address_value = String::new();
+		self.notes_value = String::new();
+		self.form_error = None;
+	}
+}
+
+#[derive(Debug, Clone)]
+pub enum LocalViewInteraction {
+	Back,
+	Name(String),
+	Address(String),
+	Notes(String),
+	Save,
+	Edit(String),
+	Remove(String),
+	Import,
+	Export,
+}
+
+/// Chain the current wallet is on, which contact addresses must match
+fn chain_type(config: &Config) -> ChainTypes {
+	config
+		.current_wallet()
+		.map_or(ChainTypes::Mainnet, |w| w.chain_type)
+}
+
+fn save_contacts(grin_gui: &mut GrinGui) {
+	let contacts = &grin_gui
+		.wallet_state
+		.operation_state
+		.contacts_state
+		.contacts;
+	if let Err(e) = contacts.save().context("Failed to save contacts") {
+		log_error(&e);
+		grin_gui.error = Some(e);
+	}
+}
+
+pub fn handle_message<'a>(
+	grin_gui: &mut GrinGui,
+	message: LocalViewInteraction,
+) -> Result<Command<Message>> {
+	let chain_type = chain_type(&grin_gui.config);
+	let state = &mut grin_gui.wallet_state.operation_state.contacts_state;
+
+	match message {
+		LocalViewInteraction::Back => {
+			log::debug!("Interaction::WalletOperationContactsViewInteraction(Back)");
+			state.clear_form();
+			state.imported = None;
+			grin_gui.wallet_state.operation_state.mode =
+				crate::gui::element::wallet::operation::Mode::Home;
+		}
+		LocalViewInteraction::Name(s) => state.name_value = s,
+		LocalViewInteraction::Address(s) => state.address_value = s,
+		LocalViewInteraction::Notes(s) => state.notes_value = s,
+		LocalViewInteraction::Save => {
+			let contact = Contact {
+				name: state.name_value.clone(),
+				address: state.address_value.clone(),
+				notes: state.notes_value.clone(),
+			};
+			match state.contacts.upsert(contact, chain_type) {
+				Ok(_) => {
+					state.clear_form();
+					save_contacts(grin_gui);
+				}
+				Err(e) => state.form_error = Some(e),
+			}
+		}
+		LocalViewInteraction::Edit(name) => {
+			if let Some(contact) = state.contacts.find(&name).cloned() {
+				state.name_value = contact.name;
+				state.address_value = contact.address;
+				state.notes_value = contact.notes;
+				state.form_error = None;
+			}
+		}
+		LocalViewInteraction::Remove(name) => {
+			if state.contacts.remove(&name).is_some() {
+				save_contacts(grin_gui);
+			}
+		}
+		LocalViewInteraction::Import => {
+			let file_dialog = FileDialog::new().add_filter("JSON", &["json"]);
+			let path = match file_dialog.show_open_single_file() {
+				Ok(Some(path)) => path,
+				Ok(None) => return Ok(Command::none()),
+				Err(e) => {
+					log::debug!("contacts.rs::LocalViewInteraction::Import {}", e);
+					return Ok(Command::none());
+				}
+			};
+			let res = fs::read_to_string(&path)
+				.map_err(anyhow::Error::from)
+				.and_then(|json| Ok(state.contacts.import_json(&json, chain_type)?))
+				.with_context(|| format!("Failed to import contacts from {}", path.display()));
+			match res {
+				Ok(counts) => {
+					state.imported = Some(counts);
+					save_contacts(grin_gui);
+				}
+				Err(e) => {
+					log_error(&e);
+					grin_gui.error = Some(e);
+				}
+			}
+		}
+		LocalViewInteraction::Export => {
+			let file_dialog = FileDialog::new().add_filter("JSON", &["json"]);
+			match file_dialog.show_save_single_file() {
+				Ok(Some(path)) => {
+					let res = state
+						.contacts
+						.to_json()
+						.map_err(anyhow::Error::from)
+						.and_then(|json| Ok(fs::write(&path, json)?))
+						.with_context(|| {
+							format!("Failed to export contacts to {}", path.display())
+						});
+					if let Err(e) = res {
+						log_error(&e);
+						grin_gui.error = Some(e);
+					}
+				}
+				Ok(None) => {}
+				Err(e) => {
+					log::debug!("contacts.rs::LocalViewInteraction::Export {}", e);
+				}
+			}
+		}
+	}
+
+	Ok(Command::none())
+}
+
+/// Contacts matching what's been typed into a recipient field, as buttons that fill it in
+pub fn suggestions<'a>(
+	config: &Config,
+	contacts: &'a Contacts,
+	typed: &str,
+	on_select: impl Fn(String) -> Interaction,
+) -> Option<Element<'a, Message>> {
+	let matches = contacts.suggestions(typed, chain_type(config));
+	if matches.is_empty() {
+		return None;
+	}
+
+	let mut row = Row::new().spacing(5).align_items(Alignment::Center).push(
+		Text::new(format!("{}:", localized_string("contacts-suggestions"))).size(SMALLER_FONT_SIZE),
+	);
+	for contact in matches {
+		let button: Element<Interaction> = Button::new(
+			Container::new(Text::new(contact.name.clone()).size(SMALLER_FONT_SIZE))
+				.center_y()
+				.center_x(),
+		)
+		.style(grin_gui_core::theme::ButtonStyle::Bordered)
+		.on_press(on_select(contact.address.clone()))
+		.into();
+		row = row.push(button.map(Message::Interaction));
+	}
+	Some(row.into())
+}
+
+/// Error text for a contact that couldn't be saved
+fn form_error_string(error: &ContactError) -> String {
+	match error {
+		ContactError::EmptyName => localized_string("contacts-name-error"),
+		ContactError::WrongChain { .. } => localized_string("contacts-chain-error"),
+		_ => localized_string("contacts-address-error"),
+	}
+}
+
+pub fn data_container<'a>(config: &'a Config, state: &'a StateContainer) -> Container<'a, Message> {
+	// Title row
+	let title = Text::new(localized_string("contacts-title"))
+		.size(DEFAULT_HEADER_FONT_SIZE)
+		.horizontal_alignment(alignment::Horizontal::Center);
+
+	let title_container = Container::new(title)
+		.style(grin_gui_core::theme::ContainerStyle::BrightBackground)
+		.padding(iced::Padding::from([
+			2, // top
+			0, // right
+			2, // bottom
+			5, // left
+		]));
+
+	let header_row = Row::new().push(title_container);
+
+	let header_container = Container::new(header_row).padding(iced::Padding::from([
+		0,                      // top
+		0,                      // right
+		DEFAULT_PADDING as u16, // bottom
+		0,                      // left
+	]));
+
+	let unit_spacing = 15.0;
+	let row_spacing = 5.0;
+
+	let button_height = Length::Fixed(BUTTON_HEIGHT);
+	let button_width = Length::Fixed(BUTTON_WIDTH);
+
+	let mut column = Column::new();
+
+	if state.contacts.contacts.is_empty() {
+		column = column.push(Text::new(localized_string("contacts-empty")).size(DEFAULT_FONT_SIZE));
+	}
+
+	for contact in state.contacts.contacts.iter() {
+		let mut details = Column::new()
+			.push(Text::new(contact.name.clone()).size(DEFAULT_FONT_SIZE))
+			.push(Text::new(contact.address.clone()).size(SMALLER_FONT_SIZE));
+		if !contact.notes.is_empty() {
+			details = details.push(Text::new(contact.notes.clone()).size(SMALLER_FONT_SIZE));
+		}
+
+		let edit_button: Element<Interaction> = Button::new(
+			Container::new(Text::new(localized_string("contacts-edit")).size(SMALLER_FONT_SIZE))
+				.center_y()
+				.center_x(),
+		)
+		.style(grin_gui_core::theme::ButtonStyle::Bordered)
+		.on_press(Interaction::WalletOperationContactsViewInteraction(
+			LocalViewInteraction::Edit(contact.name.clone()),
+		))
+		.into();
+
+		let remove_button: Element<Interaction> = Button::new(
+			Container::new(Text::new(localized_string("contacts-remove")).size(SMALLER_FONT_SIZE))
+				.center_y()
+				.center_x(),
+		)
+		.style(grin_gui_core::theme::ButtonStyle::Bordered)
+		.on_press(Interaction::WalletOperationContactsViewInteraction(
+			LocalViewInteraction::Remove(contact.name.clone()),
+		))
+		.into();
+
+		let contact_row = Row::new()
+			.push(details.width(Length::Fill))
+			.push(edit_button.map(Message::Interaction))
+			.push(Space::with_width(Length::Fixed(5.0)))
+			.push(remove_button.map(Message::Interaction))
+			.align_items(Alignment::Center);
+
+		column = column
+			.push(contact_row)
+			.push(Space::new(Length::Fixed(0.0), Length::Fixed(row_spacing)));
+	}
+
+	column = column
+		.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)))
+		.push(
+			Container::new(Text::new(localized_string("contacts-add")).size(DEFAULT_FONT_SIZE))
+				.style(grin_gui_core::theme::ContainerStyle::BrightBackground),
+		)
+		.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)));
+
+	let input = |label: &str, value: &str, on_input: fn(String) -> LocalViewInteraction| {
+		let input: Element<Interaction> = TextInput::new(&localized_string(label), value)
+			.on_input(move |s| Interaction::WalletOperationContactsViewInteraction(on_input(s)))
+			.size(DEFAULT_FONT_SIZE)
+			.padding(6)
+			.width(Length::Fixed(400.0))
+			.style(grin_gui_core::theme::TextInputStyle::AddonsQuery)
+			.into();
+		input.map(Message::Interaction)
+	};
+
+	column = column
+		.push(input(
+			"contacts-name",
+			&state.name_value,
+			LocalViewInteraction::Name,
+		))
+		.push(Space::new(Length::Fixed(0.0), Length::Fixed(row_spacing)))
+		.push(input(
+			"contacts-address",
+			&state.address_value,
+			LocalViewInteraction::Address,
+		))
+		.push(Space::new(Length::Fixed(0.0), Length::Fixed(row_spacing)))
+		.push(input(
+			"contacts-notes",
+			&state.notes_value,
+			LocalViewInteraction::Notes,
+		))
+		.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)));
+
+	if let Some(e) = state.form_error.as_ref() {
+		column = column
+			.push(
+				Text::new(form_error_string(e))
+					.size(DEFAULT_FONT_SIZE)
+					.style(grin_gui_core::theme::text::TextStyle::Warning),
+			)
+			.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)));
+	}
+
+	if let Some((added, skipped)) = state.imported {
+		column = column
+			.push(
+				Text::new(format!(
+					"{}: {}, {}: {}",
+					localized_string("contacts-imported"),
+					added,
+					localized_string("contacts-skipped"),
+					skipped
+				))
+				.size(SMALLER_FONT_SIZE),
+			)
+			.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)));
+	}
+
+	let button = |label: &str, message: LocalViewInteraction| {
+		let label_container =
+			Container::new(Text::new(localized_string(label)).size(DEFAULT_FONT_SIZE))
+				.width(button_width)
+				.height(button_height)
+				.center_x()
+				.center_y()
+				.align_x(alignment::Horizontal::Center);
+
+		let button: Element<Interaction> = Button::new(label_container)
+			.style(grin_gui_core::theme::ButtonStyle::Primary)
+			.on_press(Interaction::WalletOperationContactsViewInteraction(message))
+			.into();
+
+		let container = Container::new(button.map(Message::Interaction)).padding(1);
+		Container::new(container)
+			.style(grin_gui_core::theme::ContainerStyle::Segmented)
+			.padding(1)
+	};
+
+	let button_row = Row::new()
+		.push(button("contacts-save", LocalViewInteraction::Save))
+		.push(Space::new(Length::Fixed(unit_spacing), Length::Fixed(0.0)))
+		.push(button("contacts-import", LocalViewInteraction::Import))
+		.push(Space::new(Length::Fixed(unit_spacing), Length::Fixed(0.0)))
+		.push(button("contacts-export", LocalViewInteraction::Export))
+		.push(Space::new(Length::Fixed(unit_spacing), Length::Fixed(0.0)))
+		.push(button("back", LocalViewInteraction::Back));
+
+	column = column.push(button_row);
+
+	let form_container = Container::new(column)
+		.width(Length::Fill)
+		.padding(iced::Padding::from([
+			0, // top
+			0, // right
+			0, // bottom
+			5, // left
+		]));
+
+	// form container should be scrollable in tiny windows
+	let scrollable = Scrollable::new(form_container)
+		.height(Length::Fill)
+		.style(grin_gui_core::theme::ScrollableStyle::Primary);
+
+	let content = Container::new(scrollable)
+		.width(Length::Fill)
+		.height(Length::Shrink)
+		.style(grin_gui_core::theme::ContainerStyle::NormalBackground);
+
+	let wrapper_column = Column::new()
+		.height(Length::Fill)
+		.push(header_container)
+		.push(content);
+
+	// Returns the final container.
+	Container::new(wrapper_column).padding(iced::Padding::from([
+		DEFAULT_PADDING, // top
+		DEFAULT_PADDING, // right
+		DEFAULT_PADDING, // bottom
+		DEFAULT_PADDING, // left
+	]))
+}
@@ -1,9 +1,11 @@
+use super::quote;
 use super::tx_list::{self, ExpandType};
 use crate::log_error;
 use async_std::prelude::FutureExt;
 use grin_gui_core::{
-	config::Config,
+	config::{Config, Contacts, SendDefaults},
 	error::GrinWalletInterfaceError,
+	price::PriceQuote,
 	wallet::{TxLogEntry, TxLogEntryType},
 };
 use iced_aw::Card;
@@ -26,7 +28,7 @@ use {
 	grin_gui_core::theme::{
 		Button, Column, Container, Element, PickList, Row, Scrollable, TableRow, Text, TextInput,
 	},
-	grin_gui_core::wallet::{InitTxArgs, Slate, StatusMessage, WalletInfo, WalletInterface},
+	grin_gui_core::wallet::{FeeEstimate, InitTxArgs, Slate, StatusMessage, WalletInfo},
 	grin_gui_core::{
 		node::{amount_from_hr_string, amount_to_hr_string},
 		theme::{ButtonStyle, ColorPalette, ContainerStyle},
@@ -41,20 +43,86 @@ pub struct StateContainer {
 	pub recipient_address_value: String,
 	// pub amount_input_state: text_input::State,
 	pub amount_value: String,
+	// whether amount_value is in the configured currency rather than grin
+	pub amount_in_currency: bool,
 	// whether amount has errored
 	amount_error: bool,
 	// slatepack address error
 	slatepack_address_error: bool,
+	// estimate shown for confirmation before anything is locked
+	pub estimate: Option<FeeEstimate>,
+	// advanced send parameters, as entered
+	pub show_advanced: bool,
+	pub min_confirmations_value: String,
+	pub max_outputs_value: String,
+	pub num_change_outputs_value: String,
+	pub use_all: bool,
+	pub late_lock: bool,
+	pub ttl_blocks_value: String,
+	// whether any advanced parameter has errored
+	advanced_error: bool,
+}
+
+impl StateContainer {
+	/// Amount entered in nanogrin, converted at `quote` when it's entered in another currency
+	pub fn amount(&self, quote: Option<&PriceQuote>) -> Option<u64> {
+		quote::parse_amount(&self.amount_value, quote, self.amount_in_currency)
+	}
+
+	/// Fill the advanced section from a wallet's saved defaults
+	pub fn load_send_defaults(&mut self, defaults: &SendDefaults) {
+		self.min_confirmations_value = defaults.minimum_confirmations.to_string();
+		self.max_outputs_value = defaults.max_outputs.to_string();
+		self.num_change_outputs_value = defaults.num_change_outputs.to_string();
+		self.use_all = defaults.selection_strategy_is_use_all;
+		self.late_lock = defaults.late_lock;
+		self.ttl_blocks_value = defaults
+			.ttl_blocks
+			.map(|t| t.to_string())
+			.unwrap_or_default();
+		self.advanced_error = false;
+	}
+
+	/// Advanced parameters as entered, or `None` if any of them is invalid
+	fn send_params(&self) -> Option<SendDefaults> {
+		fn positive<T: std::str::FromStr + Default + PartialOrd>(value: &str) -> Option<T> {
+			value.trim().parse().ok().filter(|v| *v > T::default())
+		}
+		let ttl_blocks = match self.ttl_blocks_value.trim() {
+			"" => None,
+			t => Some(positive(t)?),
+		};
+		Some(SendDefaults {
+			minimum_confirmations: positive(&self.min_confirmations_value)?,
+			max_outputs: positive(&self.max_outputs_value)?,
+			num_change_outputs: positive(&self.num_change_outputs_value)?,
+			selection_strategy_is_use_all: self.use_all,
+			late_lock: self.late_lock,
+			ttl_blocks,
+		})
+	}
 }
 
 impl Default for StateContainer {
 	fn default() -> Self {
-		Self {
+		let mut state = Self {
 			recipient_address_value: Default::default(),
 			amount_value: Default::default(),
+			amount_in_currency: false,
 			amount_error: false,
 			slatepack_address_error: false,
-		}
+			estimate: None,
+			show_advanced: false,
+			min_confirmations_value: Default::default(),
+			max_outputs_value: Default::default(),
+			num_change_outputs_value: Default::default(),
+			use_all: false,
+			late_lock: false,
+			ttl_blocks_value: Default::default(),
+			advanced_error: false,
+		};
+		state.load_send_defaults(&SendDefaults::default());
+		state
 	}
 }
 
@@ -66,8 +134,20 @@ pub enum LocalViewInteraction {
 	Back,
 	RecipientAddress(String),
 	Amount(String),
+	ToggleAmountCurrency,
 	CreateTransaction(),
-
+	ConfirmTransaction,
+	EditTransaction,
+	ToggleAdvanced,
+	MinConfirmations(String),
+	MaxOutputs(String),
+	NumChangeOutputs(String),
+	UseAll(bool),
+	LateLock(bool),
+	TtlBlocks(String),
+	SaveSendDefaults,
+
+	EstimateOk(FeeEstimate),
 	TxCreatedOk(Slate, String),
 	TxCreateError(Arc<RwLock<Option<anyhow::Error>>>),
 	SlatepackAddressError,
@@ -82,45 +162,178 @@ pub fn handle_message<'a>(
 	match message {
 		LocalViewInteraction::Back => {
 			log::debug!("Interaction::WalletOperationCreateTxViewInteraction(Back)");
+			state.estimate = None;
 			grin_gui.wallet_state.operation_state.mode =
 				crate::gui::element::wallet::operation::Mode::Home;
 		}
 		LocalViewInteraction::RecipientAddress(s) => {
 			state.recipient_address_value = s;
+			state.estimate = None;
 		}
 		LocalViewInteraction::Amount(s) => {
 			state.amount_value = s;
+			state.estimate = None;
+		}
+		LocalViewInteraction::ToggleAmountCurrency => {
+			let quote = grin_gui
+				.wallet_state
+				.operation_state
+				.home_state
+				.price_quote(grin_gui.config.currency);
+			if let Some(v) = quote::switch_unit(
+				&state.amount_value,
+				quote.as_ref(),
+				state.amount_in_currency,
+			) {
+				state.amount_value = v;
+			}
+			state.amount_in_currency = !state.amount_in_currency;
+			state.amount_error = false;
+			state.estimate = None;
+		}
+		LocalViewInteraction::ToggleAdvanced => {
+			state.show_advanced = !state.show_advanced;
 		}
+		LocalViewInteraction::MinConfirmations(s) => {
+			state.min_confirmations_value = s;
+			state.advanced_error = false;
+			state.estimate = None;
+		}
+		LocalViewInteraction::MaxOutputs(s) => {
+			state.max_outputs_value = s;
+			state.advanced_error = false;
+			state.estimate = None;
+		}
+		LocalViewInteraction::NumChangeOutputs(s) => {
+			state.num_change_outputs_value = s;
+			state.advanced_error = false;
+			state.estimate = None;
+		}
+		LocalViewInteraction::UseAll(b) => {
+			state.use_all = b;
+			state.estimate = None;
+		}
+		LocalViewInteraction::LateLock(b) => {
+			state.late_lock = b;
+			state.estimate = None;
+		}
+		LocalViewInteraction::TtlBlocks(s) => {
+			state.ttl_blocks_value = s;
+			state.advanced_error = false;
+			state.estimate = None;
+		}
+		LocalViewInteraction::SaveSendDefaults => match state.send_params() {
+			Some(params) => {
+				if let Some(wallet) = grin_gui.config.current_wallet_mut() {
+					wallet.send_defaults = params;
+					let _ = grin_gui.config.save();
+				}
+			}
+			None => state.advanced_error = true,
+		},
 		LocalViewInteraction::CreateTransaction() => {
 			grin_gui.error.take();
 			state.amount_error = false;
 			state.slatepack_address_error = false;
+			state.estimate = None;
 
 			log::debug!("Interaction::WalletOperationCreateTxViewInteraction");
 
-			let w = grin_gui.wallet_interface.clone();
-
-			let amount = match amount_from_hr_string(&state.amount_value) {
-				Ok(0) | Err(_) => {
+			let quote = grin_gui
+				.wallet_state
+				.operation_state
+				.home_state
+				.price_quote(grin_gui.config.currency);
+			let amount = match state.amount(quote.as_ref()) {
+				Some(0) | None => {
+					state.amount_error = true;
+					return Ok(Command::none());
+				}
+				Some(a) if a > std::i64::MAX as u64 => {
 					state.amount_error = true;
 					return Ok(Command::none());
 				}
-				Ok(a) => a,
+				Some(a) => a,
 			};
 
-			// Todo: Amount parsing + validation, just testing the flow for now
-			let args = InitTxArgs {
-				src_acct_name: None,
+			let params = match state.send_params() {
+				Some(p) => p,
+				None => {
+					state.advanced_error = true;
+					state.show_advanced = true;
+					return Ok(Command::none());
+				}
+			};
+
+			let args = init_tx_args(
+				grin_gui
+					.wallet_state
+					.operation_state
+					.home_state
+					.active_account
+					.clone(),
 				amount,
-				minimum_confirmations: 2,
-				max_outputs: 500,
-				num_change_outputs: 1,
-				selection_strategy_is_use_all: false,
-				late_lock: Some(false),
-				..Default::default()
+				&params,
+			);
+			let w = grin_gui.wallet_backend.clone();
+			let fut = move || w.estimate_fee(args);
+
+			return Ok(Command::perform(fut(), |r| {
+				match r.context("Failed to estimate transaction fee") {
+					Ok(estimate) => {
+						Message::Interaction(Interaction::WalletOperationCreateTxViewInteraction(
+							LocalViewInteraction::EstimateOk(estimate),
+						))
+					}
+					Err(e) => {
+						Message::Interaction(Interaction::WalletOperationCreateTxViewInteraction(
+							LocalViewInteraction::TxCreateError(Arc::new(RwLock::new(Some(e)))),
+						))
+					}
+				}
+			}));
+		}
+		LocalViewInteraction::EstimateOk(estimate) => {
+			if estimate.inputs_total < estimate.total_debited() {
+				grin_gui.error = Some(anyhow::anyhow!(
+					"{}",
+					localized_string("create-tx-insufficient-inputs")
+				));
+				return Ok(Command::none());
+			}
+			state.estimate = Some(estimate);
+		}
+		LocalViewInteraction::EditTransaction => {
+			state.estimate = None;
+		}
+		LocalViewInteraction::ConfirmTransaction => {
+			let (amount, params) = match (state.estimate.as_ref(), state.send_params()) {
+				(Some(e), Some(p)) => (e.amount, p),
+				_ => return Ok(Command::none()),
 			};
-			let fut =
-				move || WalletInterface::create_tx(w, args, state.recipient_address_value.clone());
+			grin_gui.error.take();
+
+			log::debug!("Interaction::WalletOperationCreateTxViewInteraction(ConfirmTransaction)");
+
+			let args = init_tx_args(
+				grin_gui
+					.wallet_state
+					.operation_state
+					.home_state
+					.active_account
+					.clone(),
+				amount,
+				&params,
+			);
+			let dest = grin_gui
+				.wallet_state
+				.operation_state
+				.create_tx_state
+				.recipient_address_value
+				.clone();
+			let w = grin_gui.wallet_backend.clone();
+
+			let fut = move || w.create_tx(args, dest);
 
 			return Ok(Command::perform(fut(), |r| match r {
 				Ok((enc_slate, unenc_slate)) => {
@@ -170,6 +383,12 @@ pub fn handle_message<'a>(
 				output.sync_all()?;
 			}
 
+			grin_gui
+				.wallet_state
+				.operation_state
+				.create_tx_state
+				.estimate = None;
+
 			grin_gui.wallet_state.operation_state.mode =
 				crate::gui::element::wallet::operation::Mode::ShowSlatepack;
 		}
@@ -179,13 +398,189 @@ pub fn handle_message<'a>(
 				log_error(e);
 			}
 		}
-		LocalViewInteraction::SlatepackAddressError => state.slatepack_address_error = true,
+		LocalViewInteraction::SlatepackAddressError => {
+			state.slatepack_address_error = true;
+			state.estimate = None;
+		}
 	}
 
 	Ok(Command::none())
 }
 
-pub fn data_container<'a>(config: &'a Config, state: &'a StateContainer) -> Container<'a, Message> {
+/// Transaction arguments for spending `amount` from the given account with `params`
+pub fn init_tx_args(
+	src_acct_name: Option<String>,
+	amount: u64,
+	params: &SendDefaults,
+) -> InitTxArgs {
+	InitTxArgs {
+		src_acct_name,
+		amount,
+		minimum_confirmations: params.minimum_confirmations,
+		max_outputs: params.max_outputs,
+		num_change_outputs: params.num_change_outputs,
+		selection_strategy_is_use_all: params.selection_strategy_is_use_all,
+		late_lock: Some(params.late_lock),
+		ttl_blocks: params.ttl_blocks,
+		..Default::default()
+	}
+}
+
+fn estimate_summary<'a>(estimate: &FeeEstimate, quote: Option<&PriceQuote>) -> Column<'a, Message> {
+	let row = |label: &str, value: String| {
+		Row::new()
+			.push(
+				Text::new(localized_string(label))
+					.size(DEFAULT_FONT_SIZE)
+					.width(Length::Fixed(200.0)),
+			)
+			.push(Text::new(value).size(DEFAULT_FONT_SIZE))
+	};
+
+	let inputs = match estimate.num_inputs {
+		Some(n) => format!(
+			"{} ({})",
+			n,
+			amount_to_hr_string(estimate.inputs_total, true)
+		),
+		None => amount_to_hr_string(estimate.inputs_total, true),
+	};
+	let change = format!(
+		"{} ({})",
+		estimate.num_change_outputs,
+		amount_to_hr_string(estimate.change(), true)
+	);
+	let mut total_debited = amount_to_hr_string(estimate.total_debited(), true);
+	if let Some(quote) = quote {
+		total_debited = format!(
+			"{} {}",
+			total_debited,
+			quote::approx_value(quote, estimate.total_debited())
+		);
+	}
+
+	Column::new()
+		.spacing(5)
+		.push(row(
+			"create-tx-fee",
+			amount_to_hr_string(estimate.fee, true),
+		))
+		.push(row("create-tx-total-debited", total_debited))
+		.push(row("create-tx-inputs-used", inputs))
+		.push(row("create-tx-change-outputs", change))
+		.push(
+			Text::new(localized_string("create-tx-estimate-note"))
+				.size(SMALLER_FONT_SIZE)
+				.horizontal_alignment(alignment::Horizontal::Left),
+		)
+}
+
+fn advanced_section<'a>(state: &'a StateContainer) -> Column<'a, Message> {
+	let toggle_label = if state.show_advanced {
+		format!("▾ {}", localized_string("create-tx-advanced"))
+	} else {
+		format!("▸ {}", localized_string("create-tx-advanced"))
+	};
+	let toggle_button: Element<Interaction> =
+		Button::new(Text::new(toggle_label).size(DEFAULT_FONT_SIZE))
+			.style(grin_gui_core::theme::ButtonStyle::Bordered)
+			.on_press(Interaction::WalletOperationCreateTxViewInteraction(
+				LocalViewInteraction::ToggleAdvanced,
+			))
+			.into();
+
+	let mut column = Column::new().push(toggle_button.map(Message::Interaction));
+	if !state.show_advanced {
+		return column;
+	}
+
+	let input_row = |label: &str, value: &'a str, on_input: fn(String) -> LocalViewInteraction| {
+		let input: Element<Interaction> = TextInput::new("", value)
+			.on_input(move |s| Interaction::WalletOperationCreateTxViewInteraction(on_input(s)))
+			.size(DEFAULT_FONT_SIZE)
+			.padding(6)
+			.width(Length::Fixed(100.0))
+			.style(grin_gui_core::theme::TextInputStyle::AddonsQuery)
+			.into();
+		Row::new()
+			.push(
+				Text::new(localized_string(label))
+					.size(DEFAULT_FONT_SIZE)
+					.width(Length::Fixed(200.0)),
+			)
+			.push(input.map(Message::Interaction))
+			.align_items(Alignment::Center)
+	};
+
+	let checkbox = |label: &str, checked: bool, on_toggle: fn(bool) -> LocalViewInteraction| {
+		let checkbox: Element<Interaction> = Checkbox::new(localized_string(label), checked)
+			.on_toggle(move |b| Interaction::WalletOperationCreateTxViewInteraction(on_toggle(b)))
+			.style(grin_gui_core::theme::CheckboxStyle::Normal)
+			.text_size(DEFAULT_FONT_SIZE)
+			.spacing(10)
+			.into();
+		checkbox.map(Message::Interaction)
+	};
+
+	let save_button: Element<Interaction> =
+		Button::new(Text::new(localized_string("create-tx-save-defaults")).size(DEFAULT_FONT_SIZE))
+			.style(grin_gui_core::theme::ButtonStyle::Bordered)
+			.on_press(Interaction::WalletOperationCreateTxViewInteraction(
+				LocalViewInteraction::SaveSendDefaults,
+			))
+			.into();
+
+	column = column
+		.spacing(5)
+		.push(input_row(
+			"create-tx-min-confirmations",
+			&state.min_confirmations_value,
+			LocalViewInteraction::MinConfirmations,
+		))
+		.push(input_row(
+			"create-tx-max-outputs",
+			&state.max_outputs_value,
+			LocalViewInteraction::MaxOutputs,
+		))
+		.push(input_row(
+			"create-tx-num-change-outputs",
+			&state.num_change_outputs_value,
+			LocalViewInteraction::NumChangeOutputs,
+		))
+		.push(input_row(
+			"create-tx-ttl-blocks",
+			&state.ttl_blocks_value,
+			LocalViewInteraction::TtlBlocks,
+		))
+		.push(checkbox(
+			"create-tx-use-all",
+			state.use_all,
+			LocalViewInteraction::UseAll,
+		))
+		.push(checkbox(
+			"create-tx-late-lock",
+			state.late_lock,
+			LocalViewInteraction::LateLock,
+		));
+
+	if state.advanced_error {
+		column = column.push(
+			Text::new(localized_string("create-tx-advanced-error"))
+				.size(DEFAULT_FONT_SIZE)
+				.style(grin_gui_core::theme::text::TextStyle::Warning),
+		);
+	}
+
+	column.push(save_button.map(Message::Interaction))
+}
+
+pub fn data_container<'a>(
+	config: &'a Config,
+	state: &'a StateContainer,
+	outputs_state: &'a super::outputs::StateContainer,
+	contacts: &'a Contacts,
+	quote: Option<PriceQuote>,
+) -> Container<'a, Message> {
 	// Title row
 	let title = Text::new(localized_string("create-tx"))
 		.size(DEFAULT_HEADER_FONT_SIZE)
@@ -230,6 +625,13 @@ pub fn data_container<'a>(config: &'a Config, state: &'a StateContainer) -> Cont
 
 	let recipient_address_input: Element<Interaction> = recipient_address_input.into();
 
+	let contact_suggestions =
+		super::contacts::suggestions(config, contacts, &state.recipient_address_value, |a| {
+			Interaction::WalletOperationCreateTxViewInteraction(
+				LocalViewInteraction::RecipientAddress(a),
+			)
+		});
+
 	let address_error = Text::new(localized_string("create-tx-address-error"))
 		.size(DEFAULT_FONT_SIZE)
 		.horizontal_alignment(alignment::Horizontal::Left)
@@ -260,6 +662,31 @@ pub fn data_container<'a>(config: &'a Config, state: &'a StateContainer) -> Cont
 
 	let amount_input: Element<Interaction> = amount_input.into();
 
+	// Amounts can be entered in the configured currency while its price is known
+	let mut amount_row = Row::new()
+		.push(amount_input.map(Message::Interaction))
+		.align_items(Alignment::Center);
+	if quote.is_some() || state.amount_in_currency {
+		let unit_button: Element<Interaction> = Button::new(
+			Text::new(quote::unit(config.currency, state.amount_in_currency))
+				.size(DEFAULT_FONT_SIZE),
+		)
+		.style(grin_gui_core::theme::ButtonStyle::Bordered)
+		.on_press(Interaction::WalletOperationCreateTxViewInteraction(
+			LocalViewInteraction::ToggleAmountCurrency,
+		))
+		.into();
+		amount_row = amount_row
+			.push(Space::with_width(Length::Fixed(10.0)))
+			.push(unit_button.map(Message::Interaction));
+	}
+
+	let amount_conversion = quote::amount_conversion(
+		&state.amount_value,
+		quote.as_ref(),
+		state.amount_in_currency,
+	);
+
 	let amount_error = Text::new(localized_string("create-tx-amount-error"))
 		.size(DEFAULT_FONT_SIZE)
 		.horizontal_alignment(alignment::Horizontal::Left)
@@ -276,27 +703,59 @@ pub fn data_container<'a>(config: &'a Config, state: &'a StateContainer) -> Cont
 	let address_instruction_container =
 		Container::new(address_instruction_container).style(ContainerStyle::NormalBackground);
 
+	// InitTxArgs has no input selection, so outputs picked in the outputs view can
+	// only be spent through a contract
+	let selected_outputs = Text::new(format!(
+		"{}: {} ({}). {}",
+		localized_string("outputs-selected"),
+		outputs_state.selected.len(),
+		amount_to_hr_string(outputs_state.selected_amount(), true),
+		localized_string("create-tx-coin-control-contract")
+	))
+	.size(DEFAULT_FONT_SIZE)
+	.horizontal_alignment(alignment::Horizontal::Left);
+
+	let selected_outputs_container =
+		Container::new(selected_outputs).style(ContainerStyle::NormalBackground);
+
 	let button_height = Length::Fixed(BUTTON_HEIGHT);
 	let button_width = Length::Fixed(BUTTON_WIDTH);
 
+	// With an estimate on screen, the buttons confirm it or go back to editing
+	let (submit_label, submit_interaction, cancel_label, cancel_interaction) =
+		if state.estimate.is_some() {
+			(
+				"create-tx-confirm",
+				LocalViewInteraction::ConfirmTransaction,
+				"back",
+				LocalViewInteraction::EditTransaction,
+			)
+		} else {
+			(
+				"create-tx-estimate",
+				LocalViewInteraction::CreateTransaction(),
+				"cancel",
+				LocalViewInteraction::Back,
+			)
+		};
+
 	let submit_button_label_container =
-		Container::new(Text::new(localized_string("tx-create-submit")).size(DEFAULT_FONT_SIZE))
+		Container::new(Text::new(localized_string(submit_label)).size(DEFAULT_FONT_SIZE))
 			.width(button_width)
 			.height(button_height)
 			.center_x()
 			.center_y()
 			.align_x(alignment::Horizontal::Center);
 
-	let mut submit_button = Button::new(submit_button_label_container)
-		.style(grin_gui_core::theme::ButtonStyle::Primary);
-	let submit_button =
-		submit_button.on_press(Interaction::WalletOperationCreateTxViewInteraction(
-			LocalViewInteraction::CreateTransaction(),
-		));
-	let submit_button: Element<Interaction> = submit_button.into();
+	let submit_button: Element<Interaction> = Button::new(submit_button_label_container)
+		.style(grin_gui_core::theme::ButtonStyle::Primary)
+		.on_press(Interaction::WalletOperationCreateTxViewInteraction(
+			submit_interaction,
+		))
+		.into();
 
 	let cancel_button_label_container =
-		Container::new(Text::new(localized_string("cancel")).size(DEFAULT_FONT_SIZE))
+		Container::new(Text::new(localized_string(cancel_label)).size(DEFAULT_FONT_SIZE))
 			.width(button_width)
 			.height(button_height)
 			.center_x()
@@ -306,7 +765,7 @@ pub fn data_container<'a>(config: &'a Config, state: &'a StateContainer) -> Cont
 	let cancel_button: Element<Interaction> = Button::new(cancel_button_label_container)
 		.style(grin_gui_core::theme::ButtonStyle::Primary)
 		.on_press(Interaction::WalletOperationCreateTxViewInteraction(
-			LocalViewInteraction::Back,
+			cancel_interaction,
 		))
 		.into();
 
@@ -334,6 +793,12 @@ pub fn data_container<'a>(config: &'a Config, state: &'a StateContainer) -> Cont
 		.push(recipient_address_input.map(Message::Interaction))
 		.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)));
 
+	if let Some(suggestions) = contact_suggestions {
+		column = column
+			.push(suggestions)
+			.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)));
+	}
+
 	if state.slatepack_address_error {
 		column = column
 			.push(address_error_container)
@@ -343,15 +808,37 @@ pub fn data_container<'a>(config: &'a Config, state: &'a StateContainer) -> Cont
 	column = column
 		.push(amount_container)
 		.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)))
-		.push(amount_input.map(Message::Interaction))
+		.push(amount_row)
 		.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)));
 
+	if let Some(conversion) = amount_conversion {
+		column = column
+			.push(conversion)
+			.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)));
+	}
+
 	if state.amount_error {
 		column = column
 			.push(amount_error_container)
 			.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)));
 	}
 
+	if !outputs_state.selected.is_empty() {
+		column = column
+			.push(selected_outputs_container)
+			.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)));
+	}
+
+	column = column
+		.push(advanced_section(state))
+		.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)));
+
+	if let Some(estimate) = state.estimate.as_ref() {
+		column = column
+			.push(estimate_summary(estimate, quote.as_ref()))
+			.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)));
+	}
+
 	column = column
 		.push(button_row)
 		.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)))
@@ -392,3 +879,49 @@ pub fn data_container<'a>(config: &'a Config, state: &'a StateContainer) -> Cont
 		DEFAULT_PADDING, // left
 	]))
 }
+
+#[cfg(test)]
+mod test {
+	use super::super::test_util::{funded_gui, run_command};
+	use super::{LocalViewInteraction as CT, *};
+	use grin_gui_core::wallet::fake::FAKE_FEE;
+
+	#[test]
+	fn test_fee_estimate_before_create() {
+		let (fake, mut grin_gui) = funded_gui();
+
+		handle_message(&mut grin_gui, CT::Amount("1".to_owned())).unwrap();
+		let cmd = handle_message(&mut grin_gui, CT::CreateTransaction()).unwrap();
+		let message = match run_command(cmd).remove(0) {
+			Message::Interaction(Interaction::WalletOperationCreateTxViewInteraction(l)) => l,
+			m => panic!("unexpected message {:?}", m),
+		};
+		assert!(matches!(message, CT::EstimateOk(_)));
+		handle_message(&mut grin_gui, message).unwrap();
+
+		// Only estimated so far, nothing is locked
+		let estimate = grin_gui
+			.wallet_state
+			.operation_state
+			.create_tx_state
+			.estimate
+			.clone()
+			.unwrap();
+		assert_eq!(estimate.fee, FAKE_FEE);
+		assert_eq!(estimate.total_debited(), 1_000_000_000 + FAKE_FEE);
+		assert_eq!(fake.state().calls, vec!["estimate_fee"]);
+
+		// Changing the amount throws the estimate away,
+		handle_message(&mut grin_gui, CT::Amount("2".to_owned())).unwrap();
+		assert!(grin_gui
+			.wallet_state
+			.operation_state
+			.create_tx_state
+			.estimate
+			.is_none());
+		// so there's nothing to confirm until it's estimated again
+		let cmd = handle_message(&mut grin_gui, CT::ConfirmTransaction).unwrap();
+		assert!(run_command(cmd).is_empty());
+		assert_eq!(fake.state().calls, vec!["estimate_fee"]);
+	}
+}
@@ -1,9 +1,11 @@
+use super::quote;
 use super::tx_list::{self, ExpandType};
 use crate::log_error;
 use async_std::prelude::FutureExt;
 use grin_gui_core::{
-	config::Config,
+	config::{Config, Contacts},
 	error::GrinWalletInterfaceError,
+	price::PriceQuote,
 	wallet::{
 		ContractNewArgsAPI, ContractSetupArgsAPI, Slatepack, SlatepackAddress, TxLogEntry,
 		TxLogEntryType,
@@ -30,7 +32,7 @@ use {
 		Button, Column, Container, Element, PickList, Radio, Row, Scrollable, TableRow, Text,
 		TextInput,
 	},
-	grin_gui_core::wallet::{InitTxArgs, Slate, StatusMessage, WalletInfo, WalletInterface},
+	grin_gui_core::wallet::{InitTxArgs, Slate, StatusMessage, WalletInfo},
 	grin_gui_core::{
 		node::{amount_from_hr_string, amount_to_hr_string},
 		theme::{ButtonStyle, ColorPalette, ContainerStyle},
@@ -51,6 +53,8 @@ pub struct StateContainer {
 	pub recipient_address_value: String,
 	// pub amount_input_state: text_input::State,
 	pub amount_value: String,
+	// whether amount_value is in the configured currency rather than grin
+	pub amount_in_currency: bool,
 	// Choice of contribution to transaction
 	pub contribution_choice: ContributionChoice,
 	// Self-send checkbox
@@ -61,12 +65,20 @@ pub struct StateContainer {
 	slatepack_address_error: bool,
 }
 
+impl StateContainer {
+	/// Amount entered in nanogrin, converted at `quote` when it's entered in another currency
+	pub fn amount(&self, quote: Option<&PriceQuote>) -> Option<u64> {
+		quote::parse_amount(&self.amount_value, quote, self.amount_in_currency)
+	}
+}
+
 impl Default for StateContainer {
 	fn default() -> Self {
 		Self {
 			recipient_address_value: Default::default(),
 			contribution_choice: ContributionChoice::Debit,
 			amount_value: Default::default(),
+			amount_in_currency: false,
 			is_self_send: false,
 			amount_error: false,
 			slatepack_address_error: false,
@@ -83,6 +95,7 @@ pub enum LocalViewInteraction {
 	RecipientAddress(String),
 	ContributionChoice(ContributionChoice),
 	Amount(String),
+	ToggleAmountCurrency,
 	CreateTransaction(),
 	SelfSendSelected(bool),
 
@@ -128,6 +141,22 @@ pub fn handle_message<'a>(
 		LocalViewInteraction::Amount(s) => {
 			state.amount_value = s;
 		}
+		LocalViewInteraction::ToggleAmountCurrency => {
+			let quote = grin_gui
+				.wallet_state
+				.operation_state
+				.home_state
+				.price_quote(grin_gui.config.currency);
+			if let Some(v) = quote::switch_unit(
+				&state.amount_value,
+				quote.as_ref(),
+				state.amount_in_currency,
+			) {
+				state.amount_value = v;
+			}
+			state.amount_in_currency = !state.amount_in_currency;
+			state.amount_error = false;
+		}
 		LocalViewInteraction::SelfSendSelected(v) => {
 			state.is_self_send = v;
 			if let Some(ref a) = grin_gui
@@ -146,10 +175,15 @@ pub fn handle_message<'a>(
 
 			log::debug!("Interaction::WalletOperationCreateTxViewInteraction");
 
-			let w = grin_gui.wallet_interface.clone();
+			let w = grin_gui.wallet_backend.clone();
 
-			let amount = match amount_from_hr_string(&state.amount_value) {
-				Ok(0) | Err(_) => {
+			let quote = grin_gui
+				.wallet_state
+				.operation_state
+				.home_state
+				.price_quote(grin_gui.config.currency);
+			let amount = match state.amount(quote.as_ref()) {
+				Some(0) | None => {
 					if !state.is_self_send {
 						state.amount_error = true;
 						return Ok(Command::none());
@@ -157,7 +191,7 @@ pub fn handle_message<'a>(
 						0i64
 					}
 				}
-				Ok(a) => {
+				Some(a) => {
 					if a > std::i64::MAX as u64 {
 						state.amount_error = true;
 						return Ok(Command::none());
@@ -204,10 +238,16 @@ pub fn handle_message<'a>(
 				if let Some(a) = &grin_gui.wallet_state.operation_state.home_state.address {
 					args.setup_args.proof_args.sender_address = Some(a.pub_key);
 				}
+				// Spend the outputs picked in the outputs view, if any
+				args.setup_args.selection_args.use_inputs = grin_gui
+					.wallet_state
+					.operation_state
+					.outputs_state
+					.selected_inputs();
 			};
 
 			if state.is_self_send {
-				let fut = move || WalletInterface::contract_self_send(w, args);
+				let fut = move || w.contract_self_send(args);
 
 				return Ok(Command::perform(fut(), |r| match r {
 					Ok((unenc_slate, tx_log_entry)) => Message::Interaction(
@@ -231,9 +271,7 @@ pub fn handle_message<'a>(
 					},
 				}));
 			} else {
-				let fut = move || {
-					WalletInterface::contract_new(w, args, state.recipient_address_value.clone())
-				};
+				let fut = move || w.contract_new(args, state.recipient_address_value.clone());
 
 				return Ok(Command::perform(fut(), |r| match r {
 					Ok((enc_slate, unenc_slate)) => Message::Interaction(
@@ -286,6 +324,14 @@ pub fn handle_message<'a>(
 				output.sync_all()?;
 			}
 
+			// Selected outputs are now locked by this transaction
+			grin_gui
+				.wallet_state
+				.operation_state
+				.outputs_state
+				.selected
+				.clear();
+
 			grin_gui
 				.wallet_state
 				.operation_state
@@ -297,6 +343,13 @@ pub fn handle_message<'a>(
 				crate::gui::element::wallet::operation::Mode::ShowSlatepack;
 		}
 		LocalViewInteraction::SelfSendCreatedOk(unencrypted_slate, tx_log_entry) => {
+			grin_gui
+				.wallet_state
+				.operation_state
+				.outputs_state
+				.selected
+				.clear();
+
 			grin_gui
 				.wallet_state
 				.operation_state
@@ -318,7 +371,13 @@ pub fn handle_message<'a>(
 	Ok(Command::none())
 }
 
-pub fn data_container<'a>(config: &'a Config, state: &'a StateContainer) -> Container<'a, Message> {
+pub fn data_container<'a>(
+	config: &'a Config,
+	state: &'a StateContainer,
+	outputs_state: &'a super::outputs::StateContainer,
+	contacts: &'a Contacts,
+	quote: Option<PriceQuote>,
+) -> Container<'a, Message> {
 	let unit_spacing = 15.0;
 
 	// Title row
@@ -365,6 +424,13 @@ pub fn data_container<'a>(config: &'a Config, state: &'a StateContainer) -> Cont
 
 	let recipient_address_input: Element<Interaction> = recipient_address_input.into();
 
+	let contact_suggestions =
+		super::contacts::suggestions(config, contacts, &state.recipient_address_value, |a| {
+			Interaction::WalletOperationCreateTxContractsViewInteraction(
+				LocalViewInteraction::RecipientAddress(a),
+			)
+		});
+
 	/*let self_send_check = Text::new(localized_string("wallet-self-send"))
 		.size(DEFAULT_FONT_SIZE)
 		.horizontal_alignment(alignment::Horizontal::Left);
@@ -460,6 +526,33 @@ pub fn data_container<'a>(config: &'a Config, state: &'a StateContainer) -> Cont
 
 	let amount_input: Element<Interaction> = amount_input.into();
 
+	// Amounts can be entered in the configured currency while its price is known
+	let mut amount_row = Row::new()
+		.push(amount_input.map(Message::Interaction))
+		.align_items(Alignment::Center);
+	if quote.is_some() || state.amount_in_currency {
+		let unit_button: Element<Interaction> = Button::new(
+			Text::new(quote::unit(config.currency, state.amount_in_currency))
+				.size(DEFAULT_FONT_SIZE),
+		)
+		.style(grin_gui_core::theme::ButtonStyle::Bordered)
+		.on_press(
+			Interaction::WalletOperationCreateTxContractsViewInteraction(
+				LocalViewInteraction::ToggleAmountCurrency,
+			),
+		)
+		.into();
+		amount_row = amount_row
+			.push(Space::with_width(Length::Fixed(10.0)))
+			.push(unit_button.map(Message::Interaction));
+	}
+
+	let amount_conversion = quote::amount_conversion(
+		&state.amount_value,
+		quote.as_ref(),
+		state.amount_in_currency,
+	);
+
 	let amount_error = Text::new(localized_string("create-tx-amount-error"))
 		.size(DEFAULT_FONT_SIZE)
 		.horizontal_alignment(alignment::Horizontal::Left)
@@ -476,6 +569,19 @@ pub fn data_container<'a>(config: &'a Config, state: &'a StateContainer) -> Cont
 	let address_instruction_container =
 		Container::new(address_instruction_container).style(ContainerStyle::NormalBackground);
 
+	// Outputs picked in the outputs view are spent instead of letting the wallet choose
+	let selected_outputs = Text::new(format!(
+		"{}: {} ({})",
+		localized_string("outputs-selected"),
+		outputs_state.selected.len(),
+		amount_to_hr_string(outputs_state.selected_amount(), true)
+	))
+	.size(DEFAULT_FONT_SIZE)
+	.horizontal_alignment(alignment::Horizontal::Left);
+
+	let selected_outputs_container =
+		Container::new(selected_outputs).style(ContainerStyle::NormalBackground);
+
 	let button_height = Length::Fixed(BUTTON_HEIGHT);
 	let button_width = Length::Fixed(BUTTON_WIDTH);
 
@@ -536,6 +642,12 @@ pub fn data_container<'a>(config: &'a Config, state: &'a StateContainer) -> Cont
 		.push(address_row)
 		.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)));
 
+	if let Some(suggestions) = contact_suggestions {
+		column = column
+			.push(suggestions)
+			.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)));
+	}
+
 	if state.slatepack_address_error {
 		column = column
 			.push(address_error_container)
@@ -548,8 +660,14 @@ pub fn data_container<'a>(config: &'a Config, state: &'a StateContainer) -> Cont
 			.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)))
 			.push(amount_container)
 			.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)))
-			.push(amount_input.map(Message::Interaction))
+			.push(amount_row)
 			.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)));
+
+		if let Some(conversion) = amount_conversion {
+			column = column
+				.push(conversion)
+				.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)));
+		}
 	}
 
 	if state.amount_error {
@@ -558,6 +676,13 @@ pub fn data_container<'a>(config: &'a Config, state: &'a StateContainer) -> Cont
 			.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)));
 	}
 
+	let is_spending = state.is_self_send || state.contribution_choice == ContributionChoice::Debit;
+	if is_spending && !outputs_state.selected.is_empty() {
+		column = column
+			.push(selected_outputs_container)
+			.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)));
+	}
+
 	column = column
 		.push(button_row)
 		.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)))
@@ -0,0 +1,377 @@
+use crate::log_error;
+use grin_gui_core::{
+	config::Config,
+	wallet::{tx_is_expired, RetrieveTxQueryArgs, TxLogEntry},
+};
+
+use {
+	super::super::super::SMALLER_FONT_SIZE,
+	crate::gui::{GrinGui, Interaction, Message},
+	crate::localization::localized_string,
+	crate::Result,
+	anyhow::Context,
+	grin_gui_core::theme::{Button, Column, Container, Element, Row, Text},
+	iced::widget::{Checkbox, Space},
+	iced::{alignment, Alignment, Command, Length},
+	std::collections::HashSet,
+	std::sync::{Arc, RwLock},
+};
+
+/// How often outstanding transactions are checked against their TTL
+const CHECK_INTERVAL_SECS: i64 = 60;
+
+/// Number of automatic cancellations kept for display
+const MAX_LOG_ENTRIES: usize = 20;
+
+/// Record of a transaction cancelled because its TTL had passed
+#[derive(Debug, Clone)]
+pub struct CancelledTx {
+	pub tx_id: u32,
+	pub slate_id: Option<uuid::Uuid>,
+	pub ttl_cutoff_height: Option<u64>,
+	pub height: u64,
+	pub time: chrono::DateTime<chrono::Local>,
+}
+
+#[derive(Default)]
+pub struct StateContainer {
+	/// Outstanding transactions whose TTL has passed, as of the last check
+	pub expired: Vec<TxLogEntry>,
+	/// Expired transactions cancelled by the wallet, most recent first
+	pub cancelled: Vec<CancelledTx>,
+	/// Ids of transactions with a cancel in flight
+	cancelling: HashSet<u32>,
+	is_checking: bool,
+	last_check: chrono::DateTime<chrono::Local>,
+}
+
+impl StateContainer {
+	pub fn clear(&mut self) {
+		*self = Default::default();
+	}
+}
+
+#[derive(Debug, Clone)]
+pub enum LocalViewInteraction {
+	ExpiryCheckOk(u64, Vec<TxLogEntry>),
+	AutoCancel(bool),
+	CancelExpired,
+	ExpiredTxCancelledOk(u64, TxLogEntry),
+	ExpiredTxCancelError(u32, Arc<RwLock<Option<anyhow::Error>>>),
+	ExpiryError(Arc<RwLock<Option<anyhow::Error>>>),
+}
+
+/// Chain height reported by the node, if it's synched
+fn node_height(grin_gui: &GrinGui) -> Option<u64> {
+	let home_state = &grin_gui.wallet_state.operation_state.home_state;
+	if !home_state.node_synched {
+		return None;
+	}
+	home_state.node_height()
+}
+
+pub fn handle_tick(
+	grin_gui: &mut GrinGui,
+	time: chrono::DateTime<chrono::Local>,
+) -> Command<Message> {
+	if !grin_gui.wallet_backend.wallet_is_open() {
+		return Command::none();
+	}
+	let height = match node_height(grin_gui) {
+		Some(h) => h,
+		None => return Command::none(),
+	};
+	let state = &mut grin_gui
+		.wallet_state
+		.operation_state
+		.home_state
+		.expiry_state;
+	if state.is_checking || (time - state.last_check).num_seconds() < CHECK_INTERVAL_SECS {
+		return Command::none();
+	}
+	state.is_checking = true;
+	state.last_check = time;
+
+	let query_args = RetrieveTxQueryArgs {
+		exclude_cancelled: Some(true),
+		include_outstanding_only: Some(true),
+		..Default::default()
+	};
+	let w = grin_gui.wallet_backend.clone();
+	let fut = move || w.get_txs(Some(query_args));
+	Command::perform(fut(), move |r| {
+		match r.context("Failed to check transactions for expiry") {
+			Ok((_, txs)) => {
+				Message::Interaction(Interaction::WalletOperationHomeExpiryInteraction(
+					LocalViewInteraction::ExpiryCheckOk(height, txs),
+				))
+			}
+			Err(e) => Message::Interaction(Interaction::WalletOperationHomeExpiryInteraction(
+				LocalViewInteraction::ExpiryError(Arc::new(RwLock::new(Some(e)))),
+			)),
+		}
+	})
+}
+
+/// Cancel every transaction currently flagged as expired, unless it's already being cancelled
+fn cancel_expired(grin_gui: &mut GrinGui, height: u64) -> Command<Message> {
+	let wallet_backend = grin_gui.wallet_backend.clone();
+	let state = &mut grin_gui
+		.wallet_state
+		.operation_state
+		.home_state
+		.expiry_state;
+	let cancelling = &mut state.cancelling;
+	let commands = state
+		.expired
+		.iter()
+		.filter_map(|tx| {
+			let slate_id = tx.tx_slate_id?;
+			if !cancelling.insert(tx.id) {
+				return None;
+			}
+			let w = wallet_backend.clone();
+			let tx = tx.clone();
+			let tx_id = tx.id;
+			let fut = move || w.cancel_tx(slate_id);
+			Some(Command::perform(fut(), move |r| {
+				match r.context("Failed to cancel expired transaction") {
+					Ok(_) => {
+						Message::Interaction(Interaction::WalletOperationHomeExpiryInteraction(
+							LocalViewInteraction::ExpiredTxCancelledOk(height, tx),
+						))
+					}
+					Err(e) => {
+						Message::Interaction(Interaction::WalletOperationHomeExpiryInteraction(
+							LocalViewInteraction::ExpiredTxCancelError(
+								tx_id,
+								Arc::new(RwLock::new(Some(e))),
+							),
+						))
+					}
+				}
+			}))
+		})
+		.collect::<Vec<_>>();
+	Command::batch(commands)
+}
+
+pub fn handle_message(
+	grin_gui: &mut GrinGui,
+	message: LocalViewInteraction,
+) -> Result<Command<Message>> {
+	let state = &mut grin_gui
+		.wallet_state
+		.operation_state
+		.home_state
+		.expiry_state;
+
+	match message {
+		LocalViewInteraction::ExpiryCheckOk(height, txs) => {
+			state.is_checking = false;
+			state.expired = txs
+				.into_iter()
+				.filter(|tx| tx_is_expired(tx, height))
+				.collect();
+			let auto_cancel = grin_gui
+				.config
+				.current_wallet()
+				.map_or(false, |w| w.auto_cancel_expired);
+			if auto_cancel && !state.expired.is_empty() {
+				return Ok(cancel_expired(grin_gui, height));
+			}
+		}
+		LocalViewInteraction::AutoCancel(auto_cancel) => {
+			if let Some(wallet) = grin_gui.config.current_wallet_mut() {
+				wallet.auto_cancel_expired = auto_cancel;
+				let _ = grin_gui.config.save();
+			}
+			// Act on the new setting at the next tick
+			state.last_check = Default::default();
+		}
+		LocalViewInteraction::CancelExpired => {
+			if let Some(height) = node_height(grin_gui) {
+				grin_gui.error.take();
+				return Ok(cancel_expired(grin_gui, height));
+			}
+		}
+		LocalViewInteraction::ExpiredTxCancelledOk(height, tx) => {
+			log::info!(
+				"Cancelled expired transaction {} (slate {:?}, TTL cutoff {:?}) at height {}",
+				tx.id,
+				tx.tx_slate_id,
+				tx.ttl_cutoff_height,
+				height
+			);
+			state.cancelling.remove(&tx.id);
+			state.expired.retain(|t| t.id != tx.id);
+			state.cancelled.insert(
+				0,
+				CancelledTx {
+					tx_id: tx.id,
+					slate_id: tx.tx_slate_id,
+					ttl_cutoff_height: tx.ttl_cutoff_height,
+					height,
+					time: chrono::Local::now(),
+				},
+			);
+			state.cancelled.truncate(MAX_LOG_ENTRIES);
+
+			// Clean up the same way a manual cancel does
+			let uuid = tx.tx_slate_id.map(|u| u.to_string()).unwrap_or_default();
+			let fut = move || async {};
+			return Ok(Command::perform(fut(), move |_| {
+				Message::Interaction(Interaction::WalletOperationHomeViewInteraction(
+					super::home::LocalViewInteraction::TxCancelledOk(uuid),
+				))
+			}));
+		}
+		LocalViewInteraction::ExpiredTxCancelError(tx_id, err) => {
+			state.cancelling.remove(&tx_id);
+			grin_gui.error = err.write().unwrap().take();
+			if let Some(e) = grin_gui.error.as_ref() {
+				log_error(e);
+			}
+		}
+		LocalViewInteraction::ExpiryError(err) => {
+			state.is_checking = false;
+			grin_gui.error = err.write().unwrap().take();
+			if let Some(e) = grin_gui.error.as_ref() {
+				log_error(e);
+			}
+		}
+	}
+
+	Ok(Command::none())
+}
+
+/// Row on the wallet home screen flagging expired transactions, with the auto-cancel option
+/// and the most recent automatic cancellation
+pub fn data_container<'a>(config: &'a Config, state: &'a StateContainer) -> Container<'a, Message> {
+	let auto_cancel = config
+		.current_wallet()
+		.map_or(false, |w| w.auto_cancel_expired);
+
+	let auto_cancel_checkbox: Element<Interaction> =
+		Checkbox::new(localized_string("expiry-auto-cancel"), auto_cancel)
+			.on_toggle(|b| {
+				Interaction::WalletOperationHomeExpiryInteraction(LocalViewInteraction::AutoCancel(
+					b,
+				))
+			})
+			.style(grin_gui_core::theme::CheckboxStyle::Normal)
+			.text_size(SMALLER_FONT_SIZE)
+			.spacing(5)
+			.into();
+
+	let mut row = Row::new()
+		.push(auto_cancel_checkbox.map(Message::Interaction))
+		.align_items(Alignment::Center);
+
+	if !state.expired.is_empty() {
+		let expired_text = Text::new(format!(
+			"{}: {}",
+			localized_string("expiry-expired-txs"),
+			state.expired.len()
+		))
+		.size(SMALLER_FONT_SIZE)
+		.style(grin_gui_core::theme::text::TextStyle::Warning);
+
+		let cancel_label_container = Container::new(
+			Text::new(localized_string("expiry-cancel-expired")).size(SMALLER_FONT_SIZE),
+		)
+		.center_y()
+		.center_x();
+		let cancel_button: Element<Interaction> = Button::new(cancel_label_container)
+			.style(grin_gui_core::theme::ButtonStyle::Bordered)
+			.on_press(Interaction::WalletOperationHomeExpiryInteraction(
+				LocalViewInteraction::CancelExpired,
+			))
+			.into();
+
+		row = row
+			.push(Space::with_width(Length::Fixed(10.0)))
+			.push(expired_text)
+			.push(Space::with_width(Length::Fixed(10.0)))
+			.push(cancel_button.map(Message::Interaction));
+	}
+
+	if let Some(last) = state.cancelled.first() {
+		let last_text = Text::new(format!(
+			"{}: {} ({} {}, {})",
+			localized_string("expiry-last-cancelled"),
+			last.tx_id,
+			localized_string("expiry-at-height"),
+			last.height,
+			last.time.format("%H:%M:%S"),
+		))
+		.size(SMALLER_FONT_SIZE)
+		.horizontal_alignment(alignment::Horizontal::Left);
+
+		row = row
+			.push(Space::with_width(Length::Fixed(10.0)))
+			.push(last_text);
+	}
+
+	Container::new(Column::new().push(row))
+}
+
+#[cfg(test)]
+mod test {
+	use super::super::home;
+	use super::super::test_util::{gui_with_pending_send, home_interaction, run_command};
+	use super::{LocalViewInteraction as EX, *};
+
+	#[test]
+	fn test_expired_send_auto_cancelled() {
+		let (fake, mut grin_gui, slate, _) = gui_with_pending_send();
+		let mut wallet = grin_gui_core::config::Wallet::default();
+		wallet.auto_cancel_expired = true;
+		grin_gui.config.current_wallet_index = Some(grin_gui.config.add_wallet(wallet));
+
+		let mut txs = fake.state().txs.clone();
+		txs[0].ttl_cutoff_height = Some(100);
+
+		// An outstanding receive past its TTL is left for the sender to post
+		let mut received = txs[0].clone();
+		received.id += 1;
+		received.tx_type = grin_gui_core::wallet::TxLogEntryType::TxReceived;
+		txs.push(received);
+
+		// Not expired until the cutoff height is reached
+		let cmd = handle_message(&mut grin_gui, EX::ExpiryCheckOk(99, txs.clone())).unwrap();
+		assert!(run_command(cmd).is_empty());
+
+		let cmd = handle_message(&mut grin_gui, EX::ExpiryCheckOk(100, txs.clone())).unwrap();
+		let state = &grin_gui
+			.wallet_state
+			.operation_state
+			.home_state
+			.expiry_state;
+		assert_eq!(state.expired.len(), 1);
+
+		// A check finishing while the cancel is in flight doesn't cancel again
+		let repeat = handle_message(&mut grin_gui, EX::ExpiryCheckOk(100, txs)).unwrap();
+		assert!(run_command(repeat).is_empty());
+
+		let message = match run_command(cmd).remove(0) {
+			Message::Interaction(Interaction::WalletOperationHomeExpiryInteraction(l)) => l,
+			m => panic!("unexpected message {:?}", m),
+		};
+		assert!(matches!(message, EX::ExpiredTxCancelledOk(100, _)));
+		let cmd = handle_message(&mut grin_gui, message).unwrap();
+		match home_interaction(run_command(cmd).remove(0)) {
+			home::LocalViewInteraction::TxCancelledOk(id) => assert_eq!(id, slate.id.to_string()),
+			l => panic!("unexpected interaction {:?}", l),
+		}
+
+		let state = &grin_gui
+			.wallet_state
+			.operation_state
+			.home_state
+			.expiry_state;
+		assert!(state.expired.is_empty());
+		assert_eq!(state.cancelled[0].slate_id, Some(slate.id));
+		assert_eq!(fake.state().calls, vec!["create_tx", "cancel_tx"]);
+	}
+}
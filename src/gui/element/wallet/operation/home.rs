@@ -1,17 +1,22 @@
 use super::tx_list::{HeaderState, TxList, TxLogEntryWrap};
-use super::{action_menu, tx_list_display};
+use super::{action_menu, expiry, inbox, listener, qr::QrCodes, quote, tx_list_display};
 use super::{
 	chart::BalanceChart,
 	tx_list::{self, ExpandType},
 };
 use async_std::{prelude::FutureExt, task::current};
-use chrono::{DateTime, DurationRound, TimeZone, Utc};
-use grin_gui_core::error::GrinWalletInterfaceError;
+use chrono::{TimeZone, Utc};
+use grin_gui_core::error::{GrinWalletInterfaceError, PriceError};
+use grin_gui_core::fs::PersistentData;
 use grin_gui_core::node::SyncStatus;
+use grin_gui_core::price::{PriceCache, PriceHistory, PriceQuote, PriceSchedule};
 use grin_gui_core::wallet::SlatepackAddress;
 use grin_gui_core::{
-	config::{Config, Currency},
-	wallet::{InvoiceProof, RetrieveTxQueryArgs, TxLogEntry, TxLogEntryType},
+	config::{Config, Currency, TxMethod},
+	wallet::{
+		AcctPathMapping, InvoiceProof, PaymentProof, RetrieveTxQueryArgs, TxLogEntry,
+		TxLogEntryType,
+	},
 };
 use iced::Point;
 use iced_aw::Card;
@@ -20,10 +25,8 @@ use plotters::{
 	coord::{types::RangedCoordf32, ReverseCoordTranslate},
 	prelude::*,
 };
-use serde::{Deserialize, Serialize};
 use std::cell::RefCell;
 use std::fs::{self, File};
-use std::io::Read;
 use std::{collections::HashMap, path::PathBuf};
 
 use {
@@ -39,7 +42,7 @@ use {
 	grin_gui_core::theme::{
 		Button, Column, Container, Element, PickList, Row, Scrollable, TableRow, Text, TextInput,
 	},
-	grin_gui_core::wallet::{StatusMessage, WalletInfo, WalletInterface},
+	grin_gui_core::wallet::{StatusMessage, WalletInfo},
 	grin_gui_core::{
 		node::{amount_to_hr_string, ServerStats},
 		theme::ColorPalette,
@@ -53,11 +56,23 @@ use {
 pub struct StateContainer {
 	pub action_menu_state: action_menu::StateContainer,
 	pub tx_list_display_state: tx_list_display::StateContainer,
+	pub expiry_state: expiry::StateContainer,
+	pub inbox_state: inbox::StateContainer,
+	pub listener_state: listener::StateContainer,
 	pub address_value: Option<String>,
 	pub address: Option<SlatepackAddress>,
-
-	wallet_info: Option<WalletInfo>,
+	/// Receive address as a QR code, while shown
+	pub address_qr: Option<QrCodes>,
+	/// Labels of the wallet's accounts, empty until retrieved
+	pub accounts: Vec<String>,
+	/// Account that balances, transactions and sends apply to
+	pub active_account: Option<String>,
+	pub new_account_value: String,
+
+	pub wallet_info: Option<WalletInfo>,
 	wallet_status: String,
+	/// Whether an updater status request is in flight
+	is_checking_status: bool,
 	last_summary_update: chrono::DateTime<chrono::Local>,
 	tx_header_state: HeaderState,
 	node_status: Option<ServerStats>,
@@ -65,7 +80,11 @@ pub struct StateContainer {
 
 	cursor_index: Option<usize>,
 	caption_index: Option<usize>,
-	price_history: HashMap<DateTime<Utc>, f64>,
+	/// Daily prices of grin in the configured currency
+	pub price_history: PriceHistory,
+	/// Prices as last fetched, kept on disk
+	pub price_cache: PriceCache,
+	price_schedule: PriceSchedule,
 }
 
 impl StateContainer {
@@ -80,6 +99,16 @@ impl StateContainer {
 			None => localized_string("unknown"),
 		}
 	}
+	/// Most recent price of grin in `currency`, the configured one, if any are known
+	pub fn price_quote(&self, currency: Currency) -> Option<PriceQuote> {
+		PriceQuote::latest(&self.price_history, currency)
+	}
+
+	/// Chain height from the last node status update
+	pub fn node_height(&self) -> Option<u64> {
+		self.node_status.as_ref().map(|s| s.chain_stats.height)
+	}
+
 	pub fn update_node_status(&mut self, stats: &ServerStats) {
 		self.node_status = Some(stats.clone());
 		match stats.sync_status {
@@ -96,7 +125,25 @@ pub enum LocalViewInteraction {
 	/// was updated from node, info
 	WalletInfoUpdateSuccess(bool, WalletInfo),
 	WalletInfoUpdateFailure(Arc<RwLock<Option<anyhow::Error>>>),
+	/// Latest messages from the wallet's background updater
+	UpdaterStatusOk(Vec<StatusMessage>),
+	UpdaterStatusFailure(Arc<RwLock<Option<anyhow::Error>>>),
 	WalletSlatepackAddressUpdateSuccess((String, SlatepackAddress)),
+	/// accounts, active account
+	AccountsUpdateSuccess(Vec<AcctPathMapping>, String),
+	AccountSelected(String),
+	AccountSwitchedOk(String),
+	NewAccountName(String),
+	CreateAccount,
+	AccountCreatedOk(String),
+	AccountError(Arc<RwLock<Option<anyhow::Error>>>),
+	ShowRecoveryPhrase,
+	ShowChangePassword,
+	ShowVerifyProof,
+	ShowContacts,
+	ShowListener,
+	ToggleAddressQr,
+	SaveAddressQr,
 	WalletCloseError(Arc<RwLock<Option<anyhow::Error>>>),
 	WalletCloseSuccess,
 	CancelTx(u32, String),
@@ -105,6 +152,7 @@ pub enum LocalViewInteraction {
 	TxCancelledOk(String),
 	TxCancelError(Arc<RwLock<Option<anyhow::Error>>>),
 	ProofRetrievedOk(InvoiceProof, TxLogEntryWrap),
+	PaymentProofRetrievedOk(PaymentProof, TxLogEntryWrap),
 	ProofRetrievedError(Arc<RwLock<Option<anyhow::Error>>>),
 	ReloadTxSlate(String),
 
@@ -112,45 +160,43 @@ pub enum LocalViewInteraction {
 	MouseIndex(usize, usize),
 	MouseExit,
 	UpdatePrices,
+	PricesUpdateSuccess(Currency, PriceHistory),
+	PricesUpdateFailure(Currency, Arc<PriceError>),
 }
 
-/// update the historical price data
-fn update_prices(state: &mut StateContainer, currency: Currency) -> Result<()> {
-	// if we are using grin, we don't need to update the price history
-	if currency == Currency::GRIN {
-		return Ok(());
-	}
-
-	#[derive(Deserialize, Serialize, Debug)]
-	struct Price {
-		time: u64,
-		price: f64,
+/// Show cached prices in the configured currency, and fetch them again if they're due
+fn update_prices(grin_gui: &mut GrinGui) -> Command<Message> {
+	let currency = grin_gui.config.currency;
+	let state = &mut grin_gui.wallet_state.operation_state.home_state;
+	if state.price_history.is_empty() {
+		state.price_history = state.price_cache.history(currency);
 	}
 
-	#[derive(Deserialize, Serialize, Debug)]
-	struct PriceHistory {
-		prices: Vec<Price>,
+	if !state
+		.price_schedule
+		.is_due(&state.price_cache, currency, Utc::now())
+	{
+		return Command::none();
 	}
+	state.price_schedule.started();
+
+	let fut = grin_gui.price_provider.fetch_history(currency);
+	Command::perform(fut, move |r| match r {
+		Ok(history) => Message::Interaction(Interaction::WalletOperationHomeViewInteraction(
+			LocalViewInteraction::PricesUpdateSuccess(currency, history),
+		)),
+		Err(e) => Message::Interaction(Interaction::WalletOperationHomeViewInteraction(
+			LocalViewInteraction::PricesUpdateFailure(currency, Arc::new(e)),
+		)),
+	})
+}
 
-	// pull price history from coingecko
-	// TODO this url should not be hardcoded
-	let price_history_url = format!("https://api.coingecko.com/api/v3/coins/grin/market_chart?vs_currency={}&days=11430&interval=daily", currency.shortname());
-	let mut res = reqwest::blocking::get(price_history_url)?;
-	let mut body = String::new();
-	res.read_to_string(&mut body)?;
-
-	//debug!("price history data: {:#?}", body);
-	let history = serde_json::from_str::<PriceHistory>(&body).unwrap();
-
-	let mut prices = std::collections::HashMap::new();
-	for price in history.prices {
-		let date_time = Utc.timestamp_millis_opt(price.time as i64).unwrap();
-		prices.insert(date_time, price.price);
+/// Keep prices up to date while a wallet is open
+pub fn handle_price_tick(grin_gui: &mut GrinGui) -> Command<Message> {
+	if !grin_gui.wallet_backend.wallet_is_open() {
+		return Command::none();
 	}
-
-	// update the price hashmap in the state
-	state.price_history = prices;
-	Ok(())
+	update_prices(grin_gui)
 }
 
 // Okay to modify state and access wallet here
@@ -158,13 +204,43 @@ pub fn handle_tick<'a>(
 	grin_gui: &mut GrinGui,
 	time: chrono::DateTime<chrono::Local>,
 ) -> Result<Command<Message>> {
-	{
-		let w = grin_gui.wallet_interface.read().unwrap();
-		if !w.wallet_is_open() {
-			return Ok(Command::none());
+	if !grin_gui.wallet_backend.wallet_is_open() {
+		return Ok(Command::none());
+	}
+	let status = check_updater_status(grin_gui);
+	Ok(Command::batch(vec![status, refresh_wallet(grin_gui, time)]))
+}
+
+/// Ask the wallet's updater for its latest status, unless a request is still in flight
+fn check_updater_status(grin_gui: &mut GrinGui) -> Command<Message> {
+	let state = &mut grin_gui.wallet_state.operation_state.home_state;
+	if state.is_checking_status {
+		return Command::none();
+	}
+	state.is_checking_status = true;
+
+	let fut = grin_gui.wallet_backend.get_wallet_updater_status();
+	Command::perform(fut, |r| {
+		match r.context("Failed to retrieve wallet updater status") {
+			Ok(messages) => Message::Interaction(Interaction::WalletOperationHomeViewInteraction(
+				LocalViewInteraction::UpdaterStatusOk(messages),
+			)),
+			Err(e) => Message::Interaction(Interaction::WalletOperationHomeViewInteraction(
+				LocalViewInteraction::UpdaterStatusFailure(Arc::new(RwLock::new(Some(e)))),
+			)),
+		}
+	})
+}
+
+/// Show the updater's latest status, and feed scan progress through to the scan view
+fn show_updater_status(grin_gui: &mut GrinGui, messages: Vec<StatusMessage>) {
+	let scan_state = &mut grin_gui.wallet_state.operation_state.scan_state;
+	if scan_state.is_running {
+		if let Some(StatusMessage::Scanning(_, percent)) = messages.get(0) {
+			scan_state.progress = *percent as f32;
 		}
 	}
-	let messages = WalletInterface::get_wallet_updater_status(grin_gui.wallet_interface.clone())?;
+
 	let state = &mut grin_gui.wallet_state.operation_state.home_state;
 	let last_message = messages.get(0);
 	if let Some(m) = last_message {
@@ -182,16 +258,19 @@ pub fn handle_tick<'a>(
 	if !state.node_synched {
 		state.wallet_status = localized_string("awaiting-sync");
 	}
+}
 
-	// calls to API should be limited to once per minute
-	if time - state.last_summary_update
-		> chrono::Duration::from_std(std::time::Duration::from_secs(60)).unwrap()
-	{
-		update_prices(state, grin_gui.config.currency)?;
-	}
-
-	if time - state.last_summary_update
-		> chrono::Duration::from_std(std::time::Duration::from_secs(10)).unwrap()
+/// Refresh the wallet summary, address and accounts when they're due or missing
+fn refresh_wallet(
+	grin_gui: &mut GrinGui,
+	time: chrono::DateTime<chrono::Local>,
+) -> Command<Message> {
+	// Leave the wallet alone while a scan runs
+	let scan_running = grin_gui.wallet_state.operation_state.scan_state.is_running;
+	let state = &mut grin_gui.wallet_state.operation_state.home_state;
+	if !scan_running
+		&& time - state.last_summary_update
+			> chrono::Duration::from_std(std::time::Duration::from_secs(10)).unwrap()
 	{
 		state.last_summary_update = chrono::Local::now();
 
@@ -200,12 +279,12 @@ pub fn handle_tick<'a>(
 		query_args.exclude_cancelled = Some(true);
 		query_args.include_outstanding_only = Some(true);
 
-		let w = grin_gui.wallet_interface.clone();
+		let w = grin_gui.wallet_backend.clone();
 		let node_synched = state.node_synched;
 
-		let fut = move || WalletInterface::get_wallet_info(w.clone(), node_synched); //.join(WalletInterface::get_txs(w, Some(query_args)));
+		let fut = move || w.get_wallet_info(node_synched); //.join(w.get_txs(Some(query_args)));
 
-		return Ok(Command::perform(fut(), |wallet_info_res| {
+		return Command::perform(fut(), |wallet_info_res| {
 			if wallet_info_res.is_err() {
 				let e = wallet_info_res
 					.context("Failed to retrieve wallet info status")
@@ -220,14 +299,14 @@ pub fn handle_tick<'a>(
 				//LocalViewInteraction::WalletInfoUpdateSuccess(node_success, wallet_info, txs),
 				LocalViewInteraction::WalletInfoUpdateSuccess(node_success, wallet_info),
 			))
-		}));
+		});
 	}
 	// If slatepack address is not filled out, go get it
 	if state.address_value.is_none() {
-		let w = grin_gui.wallet_interface.clone();
+		let w = grin_gui.wallet_backend.clone();
 
-		let fut = move || WalletInterface::get_slatepack_address(w.clone());
-		return Ok(Command::perform(fut(), |get_slatepack_address_res| {
+		let fut = move || w.get_slatepack_address();
+		return Command::perform(fut(), |get_slatepack_address_res| {
 			if get_slatepack_address_res.is_err() {
 				let e = get_slatepack_address_res
 					.context("Failed to retrieve wallet slatepack address")
@@ -241,10 +320,50 @@ pub fn handle_tick<'a>(
 					get_slatepack_address_res.unwrap(),
 				),
 			))
-		}));
+		});
+	}
+	// Likewise the account list
+	if state.accounts.is_empty() {
+		return refresh_accounts(grin_gui);
 	}
 
-	Ok(Command::none())
+	Command::none()
+}
+
+fn refresh_accounts(grin_gui: &GrinGui) -> Command<Message> {
+	let w = grin_gui.wallet_backend.clone();
+	let fut = async move {
+		let accounts = w.get_accounts().await?;
+		let active_account = w.get_active_account().await?;
+		Ok::<_, GrinWalletInterfaceError>((accounts, active_account))
+	};
+	Command::perform(fut, |r| {
+		match r.context("Failed to retrieve wallet accounts") {
+			Ok((accounts, active_account)) => {
+				Message::Interaction(Interaction::WalletOperationHomeViewInteraction(
+					LocalViewInteraction::AccountsUpdateSuccess(accounts, active_account),
+				))
+			}
+			Err(e) => Message::Interaction(Interaction::WalletOperationHomeViewInteraction(
+				LocalViewInteraction::AccountError(Arc::new(RwLock::new(Some(e)))),
+			)),
+		}
+	})
+}
+
+fn switch_account(grin_gui: &GrinGui, label: String) -> Command<Message> {
+	let w = grin_gui.wallet_backend.clone();
+	let fut = move || w.set_active_account(label);
+	Command::perform(fut(), |r| match r.context("Failed to switch account") {
+		Ok(label) => {
+			Message::Interaction(Interaction::WalletOperationHomeViewInteraction(
+				LocalViewInteraction::AccountSwitchedOk(label),
+			))
+		}
+		Err(e) => Message::Interaction(Interaction::WalletOperationHomeViewInteraction(
+			LocalViewInteraction::AccountError(Arc::new(RwLock::new(Some(e)))),
+		)),
+	})
 }
 
 pub fn handle_message<'a>(
@@ -254,7 +373,28 @@ pub fn handle_message<'a>(
 	let state = &mut grin_gui.wallet_state.operation_state.home_state;
 	match message {
 		LocalViewInteraction::UpdatePrices => {
-			update_prices(state, grin_gui.config.currency)?;
+			// the currency changed, show what's cached for it until new prices come in
+			state.price_history = state.price_cache.history(grin_gui.config.currency);
+			return Ok(update_prices(grin_gui));
+		}
+		LocalViewInteraction::PricesUpdateSuccess(currency, history) => {
+			state.price_schedule.succeeded();
+			state.price_cache.insert(currency, &history, Utc::now());
+			if let Err(e) = state
+				.price_cache
+				.save()
+				.context("Failed to save price cache")
+			{
+				log_error(&e);
+			}
+			if currency == grin_gui.config.currency {
+				state.price_history = history;
+			}
+		}
+		LocalViewInteraction::PricesUpdateFailure(currency, err) => {
+			// Prices are optional, keep showing cached ones rather than interrupting with an error
+			state.price_schedule.failed(&err, Utc::now());
+			log::warn!("Failed to update {} prices: {}", currency.shortname(), err);
 		}
 		LocalViewInteraction::MouseIndex(index1, index2) => {
 			state.cursor_index = Some(index1);
@@ -265,8 +405,7 @@ pub fn handle_message<'a>(
 			state.caption_index = None;
 		}
 		LocalViewInteraction::Back => {
-			let wallet_interface = grin_gui.wallet_interface.clone();
-			let fut = WalletInterface::close_wallet(wallet_interface);
+			let fut = grin_gui.wallet_backend.close_wallet();
 
 			return Ok(Command::perform(fut, |r| {
 				match r.context("Failed to close wallet") {
@@ -328,6 +467,17 @@ pub fn handle_message<'a>(
 				}
 			}
 		}
+		LocalViewInteraction::UpdaterStatusOk(messages) => {
+			state.is_checking_status = false;
+			show_updater_status(grin_gui, messages);
+		}
+		LocalViewInteraction::UpdaterStatusFailure(err) => {
+			state.is_checking_status = false;
+			grin_gui.error = err.write().unwrap().take();
+			if let Some(e) = grin_gui.error.as_ref() {
+				log_error(e);
+			}
+		}
 		LocalViewInteraction::WalletInfoUpdateFailure(err) => {
 			grin_gui.error = err.write().unwrap().take();
 			if let Some(e) = grin_gui.error.as_ref() {
@@ -340,11 +490,31 @@ pub fn handle_message<'a>(
 			state.wallet_info = None;
 			state.address_value = None;
 			state.address = None;
+			state.address_qr = None;
+			state.accounts.clear();
+			state.active_account = None;
+			state.new_account_value = String::new();
+			state.expiry_state.clear();
+			state.inbox_state.clear();
+			state.listener_state.clear();
 			grin_gui
 				.wallet_state
 				.operation_state
 				.tx_detail_state
 				.current_tx = None;
+			grin_gui.wallet_state.operation_state.scan_state = Default::default();
+			grin_gui
+				.wallet_state
+				.operation_state
+				.recovery_phrase_state
+				.clear();
+			grin_gui
+				.wallet_state
+				.operation_state
+				.change_password_state
+				.clear();
+			grin_gui.wallet_state.operation_state.tx_export_state.clear();
+			grin_gui.wallet_state.operation_state.accounting_state.clear();
 
 			grin_gui.wallet_state.operation_state.mode =
 				crate::gui::element::wallet::operation::Mode::Open;
@@ -359,13 +529,118 @@ pub fn handle_message<'a>(
 			state.address_value = Some(address_string);
 			state.address = Some(address);
 		}
+		LocalViewInteraction::AccountsUpdateSuccess(accounts, active_account) => {
+			state.accounts = accounts.into_iter().map(|a| a.label).collect();
+			state.active_account = Some(active_account);
+		}
+		LocalViewInteraction::AccountSelected(label) => {
+			if state.active_account.as_ref() != Some(&label) {
+				grin_gui.error.take();
+				return Ok(switch_account(grin_gui, label));
+			}
+		}
+		LocalViewInteraction::AccountSwitchedOk(label) => {
+			state.active_account = Some(label);
+			// Balances and transactions are per account, so fetch them again on the next tick
+			state.wallet_info = None;
+			state.last_summary_update = Default::default();
+			state.tx_list_display_state.mode = tx_list_display::Mode::NotInit;
+		}
+		LocalViewInteraction::ShowRecoveryPhrase => {
+			grin_gui.wallet_state.operation_state.mode =
+				crate::gui::element::wallet::operation::Mode::RecoveryPhrase;
+		}
+		LocalViewInteraction::ShowChangePassword => {
+			grin_gui.wallet_state.operation_state.mode =
+				crate::gui::element::wallet::operation::Mode::ChangePassword;
+		}
+		LocalViewInteraction::ShowVerifyProof => {
+			grin_gui.wallet_state.operation_state.mode =
+				crate::gui::element::wallet::operation::Mode::VerifyProof;
+		}
+		LocalViewInteraction::ShowContacts => {
+			grin_gui.wallet_state.operation_state.mode =
+				crate::gui::element::wallet::operation::Mode::Contacts;
+		}
+		LocalViewInteraction::ShowListener => {
+			if let Some(wallet) = grin_gui.config.current_wallet() {
+				state.listener_state.load(&wallet.foreign_listener);
+			}
+			grin_gui.wallet_state.operation_state.mode =
+				crate::gui::element::wallet::operation::Mode::Listener;
+		}
+		LocalViewInteraction::ToggleAddressQr => {
+			if state.address_qr.take().is_some() {
+				return Ok(Command::none());
+			}
+			if let Some(address) = state.address_value.as_ref() {
+				match QrCodes::new(address) {
+					Ok(qr) => state.address_qr = Some(qr),
+					Err(e) => {
+						log_error(&e);
+						grin_gui.error = Some(e);
+					}
+				}
+			}
+		}
+		LocalViewInteraction::SaveAddressQr => {
+			if let Some(qr) = state.address_qr.as_ref() {
+				if let Err(e) = qr.save_png_dialog() {
+					log_error(&e);
+					grin_gui.error = Some(e);
+				}
+			}
+		}
+		LocalViewInteraction::NewAccountName(s) => {
+			state.new_account_value = s;
+		}
+		LocalViewInteraction::CreateAccount => {
+			let label = state.new_account_value.trim().to_owned();
+			if label.is_empty() {
+				return Ok(Command::none());
+			}
+			grin_gui.error.take();
+			let w = grin_gui.wallet_backend.clone();
+			let fut = move || w.create_account_path(label);
+			return Ok(Command::perform(fut(), |r| {
+				match r.context("Failed to create account") {
+					Ok(label) => {
+						Message::Interaction(Interaction::WalletOperationHomeViewInteraction(
+							LocalViewInteraction::AccountCreatedOk(label),
+						))
+					}
+					Err(e) => {
+						Message::Interaction(Interaction::WalletOperationHomeViewInteraction(
+							LocalViewInteraction::AccountError(Arc::new(RwLock::new(Some(e)))),
+						))
+					}
+				}
+			}));
+		}
+		LocalViewInteraction::AccountCreatedOk(label) => {
+			state.new_account_value = String::new();
+			state.accounts.push(label.clone());
+			return Ok(switch_account(grin_gui, label));
+		}
+		LocalViewInteraction::AccountError(err) => {
+			grin_gui.error = err.write().unwrap().take();
+			if let Some(e) = grin_gui.error.as_ref() {
+				log_error(e);
+			}
+		}
 		LocalViewInteraction::TxDetails(tx_log_entry_wrap) => {
 			log::debug!("Interaction::WalletOperationHomeViewInteraction::TxDetails");
-			grin_gui
-				.wallet_state
-				.operation_state
-				.tx_detail_state
-				.current_tx = Some(tx_log_entry_wrap.tx);
+			let tx_note = tx_log_entry_wrap
+				.tx
+				.tx_slate_id
+				.as_ref()
+				.and_then(|id| state.tx_list_display_state.tx_notes.get(id))
+				.cloned()
+				.unwrap_or_default();
+			let tx_detail_state = &mut grin_gui.wallet_state.operation_state.tx_detail_state;
+			tx_detail_state.current_tx = Some(tx_log_entry_wrap.tx);
+			tx_detail_state.label_value = tx_note.label;
+			tx_detail_state.note_value = tx_note.note;
 			grin_gui.wallet_state.operation_state.mode =
 				crate::gui::element::wallet::operation::Mode::TxDetail;
 		}
@@ -373,10 +648,33 @@ pub fn handle_message<'a>(
 			log::debug!("Interaction::WalletOperationHomeViewInteraction::TxProof");
 			grin_gui.error.take();
 
-			let w = grin_gui.wallet_interface.clone();
-			let fut = move || {
-				WalletInterface::retrieve_payment_proof_invoice(w, Some(tx_log_entry_wrap.tx.id))
-			};
+			let w = grin_gui.wallet_backend.clone();
+
+			// Legacy sends carry the proof the recipient signed for the payment
+			if grin_gui.config.tx_method == TxMethod::Legacy {
+				let fut = move || w.retrieve_payment_proof(Some(tx_log_entry_wrap.tx.id));
+				return Ok(Command::perform(fut(), |r| {
+					match r.context("Failed to Retrieve Payment Proof") {
+						Ok(ret) => {
+							Message::Interaction(Interaction::WalletOperationHomeViewInteraction(
+								LocalViewInteraction::PaymentProofRetrievedOk(
+									ret,
+									tx_log_entry_wrap,
+								),
+							))
+						}
+						Err(e) => {
+							Message::Interaction(Interaction::WalletOperationHomeViewInteraction(
+								LocalViewInteraction::ProofRetrievedError(Arc::new(RwLock::new(
+									Some(e),
+								))),
+							))
+						}
+					}
+				}));
+			}
+
+			let fut = move || w.retrieve_payment_proof_invoice(Some(tx_log_entry_wrap.tx.id));
 
 			return Ok(Command::perform(fut(), |r| {
 				match r.context("Failed to Retrieve Invoice Proof") {
@@ -401,10 +699,10 @@ pub fn handle_message<'a>(
 
 			log::debug!("Interaction::WalletOperationHomeViewInteraction::CancelTx");
 
-			let w = grin_gui.wallet_interface.clone();
+			let w = grin_gui.wallet_backend.clone();
 			let in_uuid = uuid.clone();
 
-			let fut = move || WalletInterface::cancel_tx(w, uuid::Uuid::parse_str(&uuid).unwrap());
+			let fut = move || w.cancel_tx(uuid::Uuid::parse_str(&uuid).unwrap());
 
 			return Ok(Command::perform(fut(), |r| {
 				match r.context("Failed to Cancel Transaction") {
@@ -460,6 +758,19 @@ pub fn handle_message<'a>(
 				.operation_state
 				.tx_proof_state
 				.current_proof = Some(proof);
+			grin_gui
+				.wallet_state
+				.operation_state
+				.tx_proof_state
+				.current_payment_proof = None;
+			grin_gui.wallet_state.operation_state.mode =
+				crate::gui::element::wallet::operation::Mode::TxProof;
+		}
+		LocalViewInteraction::PaymentProofRetrievedOk(proof, tx_log_entry_wrap) => {
+			let tx_proof_state = &mut grin_gui.wallet_state.operation_state.tx_proof_state;
+			tx_proof_state.current_tx = Some(tx_log_entry_wrap.tx);
+			tx_proof_state.current_proof = None;
+			tx_proof_state.current_payment_proof = Some(proof);
 			grin_gui.wallet_state.operation_state.mode =
 				crate::gui::element::wallet::operation::Mode::TxProof;
 		}
@@ -531,34 +842,24 @@ pub fn data_container<'a>(config: &'a Config, state: &'a StateContainer) -> Cont
 	};
 
 	let currency = config.currency;
-	let balance = if currency == Currency::GRIN {
-		amount_spendable_string.clone()
-	} else if let Some(info) = state.wallet_info.as_ref() {
-		let today = Utc::now()
-			.duration_trunc(chrono::Duration::days(1))
-			.unwrap();
-
-		// grap latest price if we don't have one for today
-		let price = match state.price_history.get(&today) {
-			Some(price) => *price,
-			None => {
-				let prev = today - chrono::Duration::days(1);
-				*state.price_history.get(&prev).unwrap()
-			}
-		};
-
-		let amount_spendable = info.amount_currently_spendable / grin_gui_core::GRIN_BASE;
-		let price_adjusted = amount_spendable as f64 * price;
-		let trunc = format!("{:.1$}", price_adjusted, currency.precision());
-		format!("{}{}", currency.symbol(), trunc)
-	} else {
-		waiting_string.to_owned()
-	};
-
-	// Title row
-	let title = Text::new(balance).size(DEFAULT_HEADER_FONT_SIZE);
+	let quote = state.price_quote(currency);
+
+	// Title row, with the balance's value once there are prices, which may take a while offline
+	let title = Text::new(amount_spendable_string.clone()).size(DEFAULT_HEADER_FONT_SIZE);
+	let mut title_row = Row::new().push(title).align_items(Alignment::End);
+	if let (Some(quote), Some(info)) = (quote.as_ref(), state.wallet_info.as_ref()) {
+		let value = Text::new(format!(
+			"{} ({})",
+			quote::approx_value(quote, info.amount_currently_spendable),
+			quote::rate(quote)
+		))
+		.size(SMALLER_FONT_SIZE);
+		title_row = title_row
+			.push(Space::with_width(Length::Fixed(10.0)))
+			.push(value);
+	}
 	let title_container =
-		Container::new(title).style(grin_gui_core::theme::ContainerStyle::BrightBackground);
+		Container::new(title_row).style(grin_gui_core::theme::ContainerStyle::BrightBackground);
 
 	let subtitle = Text::new(wallet_name).size(SMALLER_FONT_SIZE);
 	let subtitle_container = Container::new(subtitle)
@@ -583,10 +884,140 @@ pub fn data_container<'a>(config: &'a Config, state: &'a StateContainer) -> Cont
 		.width(Length::Shrink)
 		.into();
 
+	let recovery_phrase_label_container = Container::new(
+		Text::new(localized_string("recovery-phrase-title")).size(SMALLER_FONT_SIZE),
+	)
+	.center_y()
+	.center_x();
+
+	let recovery_phrase_button: Element<Interaction> =
+		Button::new(recovery_phrase_label_container)
+			.style(grin_gui_core::theme::ButtonStyle::Bordered)
+			.on_press(Interaction::WalletOperationHomeViewInteraction(
+				LocalViewInteraction::ShowRecoveryPhrase,
+			))
+			.width(Length::Shrink)
+			.into();
+
+	let change_password_label_container = Container::new(
+		Text::new(localized_string("change-password-title")).size(SMALLER_FONT_SIZE),
+	)
+	.center_y()
+	.center_x();
+
+	let change_password_button: Element<Interaction> =
+		Button::new(change_password_label_container)
+			.style(grin_gui_core::theme::ButtonStyle::Bordered)
+			.on_press(Interaction::WalletOperationHomeViewInteraction(
+				LocalViewInteraction::ShowChangePassword,
+			))
+			.width(Length::Shrink)
+			.into();
+
+	let verify_proof_label_container =
+		Container::new(Text::new(localized_string("verify-proof-title")).size(SMALLER_FONT_SIZE))
+			.center_y()
+			.center_x();
+
+	let verify_proof_button: Element<Interaction> = Button::new(verify_proof_label_container)
+		.style(grin_gui_core::theme::ButtonStyle::Bordered)
+		.on_press(Interaction::WalletOperationHomeViewInteraction(
+			LocalViewInteraction::ShowVerifyProof,
+		))
+		.width(Length::Shrink)
+		.into();
+
+	let contacts_label_container =
+		Container::new(Text::new(localized_string("contacts-title")).size(SMALLER_FONT_SIZE))
+			.center_y()
+			.center_x();
+
+	let contacts_button: Element<Interaction> = Button::new(contacts_label_container)
+		.style(grin_gui_core::theme::ButtonStyle::Bordered)
+		.on_press(Interaction::WalletOperationHomeViewInteraction(
+			LocalViewInteraction::ShowContacts,
+		))
+		.width(Length::Shrink)
+		.into();
+
+	let listener_label_container =
+		Container::new(Text::new(localized_string("listener-title")).size(SMALLER_FONT_SIZE))
+			.center_y()
+			.center_x();
+
+	let listener_button: Element<Interaction> = Button::new(listener_label_container)
+		.style(grin_gui_core::theme::ButtonStyle::Bordered)
+		.on_press(Interaction::WalletOperationHomeViewInteraction(
+			LocalViewInteraction::ShowListener,
+		))
+		.width(Length::Shrink)
+		.into();
+
+	let account_pick_list = PickList::new(&state.accounts[..], state.active_account.clone(), |a| {
+		Message::Interaction(Interaction::WalletOperationHomeViewInteraction(
+			LocalViewInteraction::AccountSelected(a),
+		))
+	})
+	.text_size(SMALLER_FONT_SIZE)
+	.width(Length::Fixed(120.0))
+	.style(grin_gui_core::theme::PickListStyle::Primary);
+
+	let new_account_input = TextInput::new(
+		&localized_string("new-account-name"),
+		&state.new_account_value,
+	)
+	.on_input(|s| {
+		Interaction::WalletOperationHomeViewInteraction(LocalViewInteraction::NewAccountName(s))
+	})
+	.on_submit(Interaction::WalletOperationHomeViewInteraction(
+		LocalViewInteraction::CreateAccount,
+	))
+	.size(SMALLER_FONT_SIZE)
+	.padding(4)
+	.width(Length::Fixed(120.0))
+	.style(grin_gui_core::theme::TextInputStyle::AddonsQuery);
+
+	let new_account_input: Element<Interaction> = new_account_input.into();
+
+	let create_account_label_container =
+		Container::new(Text::new(localized_string("create-account")).size(SMALLER_FONT_SIZE))
+			.center_y()
+			.center_x();
+
+	let create_account_button: Element<Interaction> = Button::new(create_account_label_container)
+		.style(grin_gui_core::theme::ButtonStyle::Bordered)
+		.on_press(Interaction::WalletOperationHomeViewInteraction(
+			LocalViewInteraction::CreateAccount,
+		))
+		.width(Length::Shrink)
+		.into();
+
 	let subtitle_row = Row::new()
 		.push(subtitle_container)
 		.push(Space::with_width(Length::Fixed(10.0)))
-		.push(close_wallet_button.map(Message::Interaction));
+		.push(close_wallet_button.map(Message::Interaction))
+		.push(Space::with_width(Length::Fixed(10.0)))
+		.push(recovery_phrase_button.map(Message::Interaction))
+		.push(Space::with_width(Length::Fixed(10.0)))
+		.push(change_password_button.map(Message::Interaction))
+		.push(Space::with_width(Length::Fixed(10.0)))
+		.push(verify_proof_button.map(Message::Interaction))
+		.push(Space::with_width(Length::Fixed(10.0)))
+		.push(contacts_button.map(Message::Interaction))
+		.push(Space::with_width(Length::Fixed(10.0)))
+		.push(listener_button.map(Message::Interaction))
+		.push(Space::with_width(Length::Fixed(10.0)))
+		.push(
+			Text::new(format!("{}: ", localized_string("account")))
+				.size(SMALLER_FONT_SIZE)
+				.height(Length::Fixed(26.0))
+				.vertical_alignment(alignment::Vertical::Center),
+		)
+		.push(account_pick_list)
+		.push(Space::with_width(Length::Fixed(10.0)))
+		.push(new_account_input.map(Message::Interaction))
+		.push(Space::with_width(Length::Fixed(5.0)))
+		.push(create_account_button.map(Message::Interaction));
 
 	let address_label = Text::new(format!(
 		"{}: ",
@@ -636,20 +1067,82 @@ pub fn data_container<'a>(config: &'a Config, state: &'a StateContainer) -> Cont
 		.width(Length::Shrink)
 		.into();
 
-	let address_row = Row::new()
+	let qr_label = if state.address_qr.is_some() {
+		"qr-hide"
+	} else {
+		"qr-show"
+	};
+	let address_qr_button: Element<Interaction> = Button::new(
+		Container::new(Text::new(localized_string(qr_label)).size(SMALLER_FONT_SIZE))
+			.center_y()
+			.center_x(),
+	)
+	.style(grin_gui_core::theme::ButtonStyle::Bordered)
+	.on_press(Interaction::WalletOperationHomeViewInteraction(
+		LocalViewInteraction::ToggleAddressQr,
+	))
+	.width(Length::Shrink)
+	.into();
+
+	let mut address_row = Row::new()
 		.push(address_label_container)
 		.push(Space::with_width(Length::Fixed(10.0)))
 		.push(address_container)
 		.push(Space::with_width(Length::Fixed(10.0)))
-		.push(copy_address_button.map(Message::Interaction));
+		.push(copy_address_button.map(Message::Interaction))
+		.push(Space::with_width(Length::Fixed(5.0)))
+		.push(address_qr_button.map(Message::Interaction));
+
+	// Listener status, for wallets that use one
+	let listener_enabled = config
+		.current_wallet()
+		.map_or(false, |w| w.foreign_listener.enabled);
+	if listener_enabled || state.listener_state.running_addr.is_some() {
+		let mut listener_status =
+			Text::new(listener::status_string(&state.listener_state)).size(SMALLER_FONT_SIZE);
+		if state.listener_state.running_addr.is_none() {
+			listener_status = listener_status.style(grin_gui_core::theme::text::TextStyle::Warning);
+		}
+		address_row = address_row
+			.push(Space::with_width(Length::Fixed(10.0)))
+			.push(
+				Container::new(listener_status).padding(iced::Padding::from([
+					3, // top
+					0, // right
+					0, // bottom
+					0, // left
+				])),
+			);
+	}
 
-	let title_container = Container::new(
-		Column::new()
-			.push(title_container)
-			.push(subtitle_row)
-			.push(address_row),
-	)
-	.padding(iced::Padding::from([
+	let mut title_column = Column::new()
+		.push(title_container)
+		.push(subtitle_row)
+		.push(address_row);
+
+	if let Some(qr) = state.address_qr.as_ref() {
+		let save_qr_button: Element<Interaction> = Button::new(
+			Container::new(Text::new(localized_string("qr-save-png")).size(SMALLER_FONT_SIZE))
+				.center_y()
+				.center_x(),
+		)
+		.style(grin_gui_core::theme::ButtonStyle::Bordered)
+		.on_press(Interaction::WalletOperationHomeViewInteraction(
+			LocalViewInteraction::SaveAddressQr,
+		))
+		.width(Length::Shrink)
+		.into();
+
+		title_column = title_column.push(
+			Row::new()
+				.spacing(10)
+				.align_items(Alignment::End)
+				.push(qr.view())
+				.push(save_qr_button.map(Message::Interaction)),
+		);
+	}
+
+	let title_container = Container::new(title_column).padding(iced::Padding::from([
 		0, // top
 		0, // right
 		0, // bottom
@@ -874,11 +1367,18 @@ pub fn data_container<'a>(config: &'a Config, state: &'a StateContainer) -> Cont
 		tx_list_display::data_container(config, &state, &state.tx_list_display_state)
 			.height(Length::Fill);
 
+	let expiry_container = expiry::data_container(config, &state.expiry_state);
+	let inbox_container = inbox::data_container(config, &state.inbox_state);
+
 	// Overall Home screen layout column
 	let column = Column::new()
 		.push(header_container)
 		.push(first_row_container)
 		.push(Space::with_height(Length::Fixed(DEFAULT_PADDING * 3.0)))
+		.push(expiry_container)
+		.push(Space::with_height(Length::Fixed(DEFAULT_PADDING)))
+		.push(inbox_container)
+		.push(Space::with_height(Length::Fixed(DEFAULT_PADDING)))
 		.push(tx_list_display)
 		.push(Space::with_height(Length::Fixed(DEFAULT_PADDING)))
 		.push(status_row)
@@ -891,3 +1391,113 @@ pub fn data_container<'a>(config: &'a Config, state: &'a StateContainer) -> Cont
 		DEFAULT_PADDING, // left
 	]))
 }
+
+#[cfg(test)]
+mod test {
+	use super::super::test_util::{
+		funded_gui, gui_with_fake, gui_with_pending_send, home_interaction, run_command,
+		FUNDED_BALANCE, PENDING_AMOUNT,
+	};
+	use super::*;
+	use grin_gui_core::wallet::fake::FakeWalletBackend;
+	use grin_gui_core::wallet::WalletBackend;
+
+	#[test]
+	fn test_close_wallet() {
+		let fake = Arc::new(FakeWalletBackend::open_with_balance("password", 0));
+		let mut grin_gui = gui_with_fake(fake.clone());
+
+		let cmd = handle_message(&mut grin_gui, LocalViewInteraction::Back).unwrap();
+		let mut messages = run_command(cmd);
+		assert_eq!(messages.len(), 1);
+		let next = home_interaction(messages.remove(0));
+		assert!(matches!(next, LocalViewInteraction::WalletCloseSuccess));
+
+		handle_message(&mut grin_gui, next).unwrap();
+		assert!(!fake.wallet_is_open());
+		assert!(grin_gui.wallet_state.operation_state.mode == super::super::Mode::Open);
+	}
+
+	#[test]
+	fn test_cancel_tx() {
+		let (fake, mut grin_gui, slate, _) = gui_with_pending_send();
+		assert_eq!(
+			fake.state().info.amount_currently_spendable,
+			FUNDED_BALANCE - PENDING_AMOUNT
+		);
+
+		let cmd = handle_message(
+			&mut grin_gui,
+			LocalViewInteraction::CancelTx(0, slate.id.to_string()),
+		)
+		.unwrap();
+		let mut messages = run_command(cmd);
+		match home_interaction(messages.remove(0)) {
+			LocalViewInteraction::TxCancelledOk(id) => assert_eq!(id, slate.id.to_string()),
+			l => panic!("unexpected interaction {:?}", l),
+		}
+
+		let s = fake.state();
+		assert_eq!(s.txs[0].tx_type, TxLogEntryType::TxSentCancelled);
+		assert_eq!(s.info.amount_currently_spendable, FUNDED_BALANCE);
+		assert_eq!(s.calls, vec!["create_tx", "cancel_tx"]);
+	}
+
+	#[test]
+	fn test_create_and_switch_account() {
+		let (fake, mut grin_gui) = funded_gui();
+
+		let cmd = refresh_accounts(&grin_gui);
+		let next = home_interaction(run_command(cmd).remove(0));
+		handle_message(&mut grin_gui, next).unwrap();
+		let state = &grin_gui.wallet_state.operation_state.home_state;
+		assert_eq!(state.accounts, vec!["default"]);
+		assert_eq!(state.active_account.as_deref(), Some("default"));
+
+		handle_message(
+			&mut grin_gui,
+			LocalViewInteraction::NewAccountName("savings".to_owned()),
+		)
+		.unwrap();
+		let mut message = LocalViewInteraction::CreateAccount;
+		// create, then switch to the new account
+		for _ in 0..2 {
+			let cmd = handle_message(&mut grin_gui, message).unwrap();
+			message = home_interaction(run_command(cmd).remove(0));
+		}
+		assert!(matches!(message, LocalViewInteraction::AccountSwitchedOk(_)));
+		handle_message(&mut grin_gui, message).unwrap();
+
+		let state = &grin_gui.wallet_state.operation_state.home_state;
+		assert_eq!(state.accounts, vec!["default", "savings"]);
+		assert_eq!(state.active_account.as_deref(), Some("savings"));
+		assert!(state.wallet_info.is_none());
+		assert_eq!(fake.state().info.amount_currently_spendable, 0);
+
+		// Coming back to the wallet shows its active account, not the first one
+		let mut grin_gui = gui_with_fake(fake);
+		let cmd = refresh_accounts(&grin_gui);
+		let next = home_interaction(run_command(cmd).remove(0));
+		handle_message(&mut grin_gui, next).unwrap();
+		let state = &grin_gui.wallet_state.operation_state.home_state;
+		assert_eq!(state.active_account.as_deref(), Some("savings"));
+	}
+
+	#[test]
+	fn test_cancel_unknown_tx() {
+		let fake = Arc::new(FakeWalletBackend::open_with_balance("password", 0));
+		let mut grin_gui = gui_with_fake(fake);
+
+		let cmd = handle_message(
+			&mut grin_gui,
+			LocalViewInteraction::CancelTx(0, "0436430c-2b02-624c-2032-570501212b00".to_owned()),
+		)
+		.unwrap();
+		let mut messages = run_command(cmd);
+		let next = home_interaction(messages.remove(0));
+		assert!(matches!(next, LocalViewInteraction::TxCancelError(_)));
+
+		handle_message(&mut grin_gui, next).unwrap();
+		assert!(grin_gui.error.is_some());
+	}
+}
@@ -0,0 +1,539 @@
+use native_dialog::FileDialog;
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use grin_gui_core::{
+	config::{Config, HandledSlatepacks},
+	fs::PersistentData,
+	wallet::{
+		read_slatepack_file, Slate, SlateState, Slatepack, SlatepackFileFormat, TxLogEntry,
+		TxLogEntryType, WalletBackend, MAX_SLATEPACK_FILE_SIZE,
+	},
+};
+
+use {
+	super::super::super::SMALLER_FONT_SIZE,
+	crate::gui::{GrinGui, Interaction, Message},
+	crate::localization::localized_string,
+	crate::{log_error, Result},
+	anyhow::Context,
+	grin_gui_core::node::amount_to_hr_string,
+	grin_gui_core::theme::{Button, Column, Container, Element, Row, Text},
+	iced::widget::{Checkbox, Space},
+	iced::{alignment, Alignment, Command, Length},
+	std::sync::Arc,
+};
+
+/// How often the watched folders are checked for new slatepacks
+const CHECK_INTERVAL_SECS: i64 = 5;
+
+/// What can be done with an incoming slatepack
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IncomingAction {
+	/// Sign a slatepack sent to this wallet
+	Receive,
+	/// Finalize and post a transaction this wallet started
+	Finalize,
+}
+
+/// Slatepack found in a watched folder that this wallet can act on
+#[derive(Debug, Clone)]
+pub struct IncomingSlatepack {
+	pub path: PathBuf,
+	pub action: IncomingAction,
+	pub slatepack: String,
+	pub parsed: (Slatepack, Slate, Option<TxLogEntry>),
+}
+
+impl IncomingSlatepack {
+	pub fn slate_id(&self) -> uuid::Uuid {
+		self.parsed.1.id
+	}
+}
+
+#[derive(Default)]
+pub struct StateContainer {
+	/// Slatepacks waiting for the user, oldest first
+	pub incoming: Vec<IncomingSlatepack>,
+	/// Files already looked at, with the modification time they had then
+	seen: HashMap<PathBuf, SystemTime>,
+	/// Slates acted on or dismissed, which aren't offered again
+	pub handled: HandledSlatepacks,
+	is_checking: bool,
+	last_check: chrono::DateTime<chrono::Local>,
+}
+
+impl StateContainer {
+	/// Forget the current wallet's folders, keeping the handled slates as slate ids are unique
+	pub fn clear(&mut self) {
+		let handled = std::mem::take(&mut self.handled);
+		*self = Default::default();
+		self.handled = handled;
+	}
+}
+
+#[derive(Debug, Clone)]
+pub enum LocalViewInteraction {
+	InboxCheckOk(Vec<(PathBuf, SystemTime)>, Vec<IncomingSlatepack>),
+	Watch(bool),
+	ChooseInbox,
+	ClearInbox,
+	Accept(uuid::Uuid),
+	Dismiss(uuid::Uuid),
+}
+
+/// Action an incoming slate calls for, given the transaction it matches in this wallet.
+/// Slatepacks this wallet wrote itself match a transaction in a state that needs nothing further.
+pub fn incoming_action(slate: &Slate, tx: Option<&TxLogEntry>) -> Option<IncomingAction> {
+	let pending = |tx: &TxLogEntry, tx_type| tx.tx_type == tx_type && !tx.confirmed;
+	match (slate.state, tx) {
+		(SlateState::Standard1, None) => Some(IncomingAction::Receive),
+		(SlateState::Standard2, Some(tx)) if pending(tx, TxLogEntryType::TxSent) => {
+			Some(IncomingAction::Finalize)
+		}
+		(SlateState::Invoice2, Some(tx)) if pending(tx, TxLogEntryType::TxReceived) => {
+			Some(IncomingAction::Finalize)
+		}
+		_ => None,
+	}
+}
+
+/// Folders watched for the current wallet
+fn watched_dirs(config: &Config) -> Vec<PathBuf> {
+	let mut dirs = vec![];
+	if let Some(dir) = config.get_wallet_slatepack_dir() {
+		dirs.push(PathBuf::from(dir));
+	}
+	if let Some(inbox) = config
+		.current_wallet()
+		.and_then(|w| w.slatepack_inbox.clone())
+	{
+		dirs.push(inbox);
+	}
+	dirs
+}
+
+fn is_slatepack_file(path: &Path) -> bool {
+	let ext = path
+		.extension()
+		.map(|e| e.to_string_lossy().to_lowercase())
+		.unwrap_or_default();
+	ext == "txt" || SlatepackFileFormat::ALL.iter().any(|f| f.file_ext() == ext)
+}
+
+/// Look through the watched folders for files not seen in their current state, and the
+/// slatepacks among them this wallet can act on
+async fn check_dirs(
+	wallet_backend: Arc<dyn WalletBackend>,
+	dirs: Vec<PathBuf>,
+	seen: HashMap<PathBuf, SystemTime>,
+) -> (Vec<(PathBuf, SystemTime)>, Vec<IncomingSlatepack>) {
+	let mut newly_seen = vec![];
+	let mut found = vec![];
+	for dir in dirs {
+		let entries = match fs::read_dir(&dir) {
+			Ok(e) => e,
+			Err(e) => {
+				log::debug!("Can't read slatepack folder {}: {}", dir.display(), e);
+				continue;
+			}
+		};
+		for entry in entries.filter_map(|e| e.ok()) {
+			let path = entry.path();
+			let metadata = match entry.metadata() {
+				Ok(m) => m,
+				Err(_) => continue,
+			};
+			if !metadata.is_file()
+				|| metadata.len() > MAX_SLATEPACK_FILE_SIZE
+				|| !is_slatepack_file(&path)
+			{
+				continue;
+			}
+			let modified = metadata.modified().unwrap_or(SystemTime::UNIX_EPOCH);
+			if seen.get(&path) == Some(&modified) {
+				continue;
+			}
+			newly_seen.push((path.clone(), modified));
+
+			// Anything we can't read or decrypt isn't meant for this wallet
+			let slatepack = match read_slatepack_file(&path) {
+				Ok(s) => s,
+				Err(e) => {
+					log::debug!("Ignoring {}: {}", path.display(), e);
+					continue;
+				}
+			};
+			let parsed = match wallet_backend.decrypt_slatepack(slatepack.clone()).await {
+				Ok(p) => p,
+				Err(e) => {
+					log::debug!("Ignoring {}: {}", path.display(), e);
+					continue;
+				}
+			};
+			if let Some(action) = incoming_action(&parsed.1, parsed.2.as_ref()) {
+				found.push(IncomingSlatepack {
+					path,
+					action,
+					slatepack,
+					parsed,
+				});
+			}
+		}
+	}
+	(newly_seen, found)
+}
+
+pub fn handle_tick(
+	grin_gui: &mut GrinGui,
+	time: chrono::DateTime<chrono::Local>,
+) -> Command<Message> {
+	if !grin_gui.wallet_backend.wallet_is_open() {
+		return Command::none();
+	}
+	let watch = grin_gui
+		.config
+		.current_wallet()
+		.map_or(false, |w| w.watch_slatepacks);
+	let state = &mut grin_gui.wallet_state.operation_state.home_state.inbox_state;
+	if !watch || state.is_checking || (time - state.last_check).num_seconds() < CHECK_INTERVAL_SECS
+	{
+		return Command::none();
+	}
+	state.is_checking = true;
+	state.last_check = time;
+
+	let dirs = watched_dirs(&grin_gui.config);
+	let seen = state.seen.clone();
+	let w = grin_gui.wallet_backend.clone();
+	Command::perform(check_dirs(w, dirs, seen), |(seen, found)| {
+		Message::Interaction(Interaction::WalletOperationHomeInboxInteraction(
+			LocalViewInteraction::InboxCheckOk(seen, found),
+		))
+	})
+}
+
+/// Remember a slate as handled, so it isn't offered again
+fn set_handled(state: &mut StateContainer, id: uuid::Uuid) {
+	state.handled.insert(&id);
+	if let Err(e) = state
+		.handled
+		.save()
+		.context("Failed to save handled slatepacks")
+	{
+		log_error(&e);
+	}
+}
+
+pub fn handle_message(
+	grin_gui: &mut GrinGui,
+	message: LocalViewInteraction,
+) -> Result<Command<Message>> {
+	let state = &mut grin_gui.wallet_state.operation_state.home_state.inbox_state;
+
+	match message {
+		LocalViewInteraction::InboxCheckOk(seen, found) => {
+			state.is_checking = false;
+			// The wallet may have been closed or watching turned off in the meantime
+			let watch = grin_gui
+				.config
+				.current_wallet()
+				.map_or(false, |w| w.watch_slatepacks);
+			if !watch || !grin_gui.wallet_backend.wallet_is_open() {
+				return Ok(Command::none());
+			}
+			state.seen.extend(seen);
+			for incoming in found {
+				let id = incoming.slate_id();
+				if state.handled.contains(&id) {
+					continue;
+				}
+				log::info!(
+					"Incoming slatepack for {} at {} ({:?})",
+					id,
+					incoming.path.display(),
+					incoming.action
+				);
+				state.incoming.retain(|i| i.slate_id() != id);
+				state.incoming.push(incoming);
+			}
+		}
+		LocalViewInteraction::Watch(watch) => {
+			if let Some(wallet) = grin_gui.config.current_wallet_mut() {
+				wallet.watch_slatepacks = watch;
+				let _ = grin_gui.config.save();
+			}
+			if !watch {
+				state.incoming.clear();
+				state.seen.clear();
+			}
+		}
+		LocalViewInteraction::ChooseInbox => match FileDialog::new().show_open_single_dir() {
+			Ok(Some(dir)) => {
+				if let Some(wallet) = grin_gui.config.current_wallet_mut() {
+					wallet.slatepack_inbox = Some(dir);
+					let _ = grin_gui.config.save();
+				}
+				// Look at the new folder at the next tick
+				state.last_check = Default::default();
+			}
+			Ok(None) => {}
+			Err(e) => {
+				log::debug!("inbox.rs::LocalViewInteraction::ChooseInbox {}", e);
+			}
+		},
+		LocalViewInteraction::ClearInbox => {
+			if let Some(wallet) = grin_gui.config.current_wallet_mut() {
+				wallet.slatepack_inbox = None;
+				let _ = grin_gui.config.save();
+			}
+		}
+		LocalViewInteraction::Accept(id) => {
+			let index = match state.incoming.iter().position(|i| i.slate_id() == id) {
+				Some(i) => i,
+				None => return Ok(Command::none()),
+			};
+			let incoming = state.incoming.remove(index);
+			set_handled(state, id);
+
+			// Continue through the apply transaction view, as if the slatepack had been pasted
+			let apply_tx_state = &mut grin_gui.wallet_state.operation_state.apply_tx_state;
+			apply_tx_state.slatepack_read_data_full = incoming.slatepack;
+			apply_tx_state.confirm_state.slatepack_parsed = Some(incoming.parsed);
+			apply_tx_state.can_continue = true;
+			grin_gui.wallet_state.operation_state.mode =
+				crate::gui::element::wallet::operation::Mode::ApplyTx;
+
+			let fut = move || async {};
+			return Ok(Command::perform(fut(), |_| {
+				Message::Interaction(Interaction::WalletOperationApplyTxViewInteraction(
+					super::apply_tx::LocalViewInteraction::Continue,
+				))
+			}));
+		}
+		LocalViewInteraction::Dismiss(id) => {
+			state.incoming.retain(|i| i.slate_id() != id);
+			set_handled(state, id);
+		}
+	}
+
+	Ok(Command::none())
+}
+
+/// Row on the wallet home screen with the watch option, the inbox folder and any slatepacks
+/// waiting to be received or finalized
+pub fn data_container<'a>(config: &'a Config, state: &'a StateContainer) -> Container<'a, Message> {
+	let wallet = config.current_wallet();
+	let watch = wallet.map_or(false, |w| w.watch_slatepacks);
+
+	let watch_checkbox: Element<Interaction> =
+		Checkbox::new(localized_string("inbox-watch"), watch)
+			.on_toggle(|b| {
+				Interaction::WalletOperationHomeInboxInteraction(LocalViewInteraction::Watch(b))
+			})
+			.style(grin_gui_core::theme::CheckboxStyle::Normal)
+			.text_size(SMALLER_FONT_SIZE)
+			.spacing(5)
+			.into();
+
+	let mut row = Row::new()
+		.push(watch_checkbox.map(Message::Interaction))
+		.align_items(Alignment::Center);
+
+	if watch {
+		let inbox = wallet.and_then(|w| w.slatepack_inbox.as_ref());
+		let inbox_text = Text::new(format!(
+			"{}: {}",
+			localized_string("inbox-folder"),
+			inbox.map_or(localized_string("inbox-folder-none"), |p| p
+				.display()
+				.to_string())
+		))
+		.size(SMALLER_FONT_SIZE);
+
+		let choose_label_container = Container::new(
+			Text::new(localized_string("inbox-choose-folder")).size(SMALLER_FONT_SIZE),
+		)
+		.center_y()
+		.center_x();
+		let choose_button: Element<Interaction> = Button::new(choose_label_container)
+			.style(grin_gui_core::theme::ButtonStyle::Bordered)
+			.on_press(Interaction::WalletOperationHomeInboxInteraction(
+				LocalViewInteraction::ChooseInbox,
+			))
+			.into();
+
+		row = row
+			.push(Space::with_width(Length::Fixed(10.0)))
+			.push(inbox_text)
+			.push(Space::with_width(Length::Fixed(10.0)))
+			.push(choose_button.map(Message::Interaction));
+
+		if inbox.is_some() {
+			let clear_label_container = Container::new(
+				Text::new(localized_string("inbox-clear-folder")).size(SMALLER_FONT_SIZE),
+			)
+			.center_y()
+			.center_x();
+			let clear_button: Element<Interaction> = Button::new(clear_label_container)
+				.style(grin_gui_core::theme::ButtonStyle::Bordered)
+				.on_press(Interaction::WalletOperationHomeInboxInteraction(
+					LocalViewInteraction::ClearInbox,
+				))
+				.into();
+			row = row
+				.push(Space::with_width(Length::Fixed(5.0)))
+				.push(clear_button.map(Message::Interaction));
+		}
+	}
+
+	let mut column = Column::new().push(row);
+
+	for incoming in state.incoming.iter() {
+		let (label_key, action_key) = match incoming.action {
+			IncomingAction::Receive => ("inbox-incoming-receive", "inbox-receive"),
+			IncomingAction::Finalize => ("inbox-incoming-finalize", "inbox-finalize"),
+		};
+		let file_name = incoming
+			.path
+			.file_name()
+			.map(|f| f.to_string_lossy().to_string())
+			.unwrap_or_default();
+		let incoming_text = Text::new(format!(
+			"{}: {} ({})",
+			localized_string(label_key),
+			amount_to_hr_string(incoming.parsed.1.amount, true),
+			file_name,
+		))
+		.size(SMALLER_FONT_SIZE)
+		.horizontal_alignment(alignment::Horizontal::Left);
+
+		let accept_label_container =
+			Container::new(Text::new(localized_string(action_key)).size(SMALLER_FONT_SIZE))
+				.center_y()
+				.center_x();
+		let accept_button: Element<Interaction> = Button::new(accept_label_container)
+			.style(grin_gui_core::theme::ButtonStyle::Bordered)
+			.on_press(Interaction::WalletOperationHomeInboxInteraction(
+				LocalViewInteraction::Accept(incoming.slate_id()),
+			))
+			.into();
+
+		let dismiss_label_container =
+			Container::new(Text::new(localized_string("inbox-dismiss")).size(SMALLER_FONT_SIZE))
+				.center_y()
+				.center_x();
+		let dismiss_button: Element<Interaction> = Button::new(dismiss_label_container)
+			.style(grin_gui_core::theme::ButtonStyle::Bordered)
+			.on_press(Interaction::WalletOperationHomeInboxInteraction(
+				LocalViewInteraction::Dismiss(incoming.slate_id()),
+			))
+			.into();
+
+		let incoming_row = Row::new()
+			.push(incoming_text)
+			.push(Space::with_width(Length::Fixed(10.0)))
+			.push(accept_button.map(Message::Interaction))
+			.push(Space::with_width(Length::Fixed(5.0)))
+			.push(dismiss_button.map(Message::Interaction))
+			.align_items(Alignment::Center);
+
+		column = column
+			.push(Space::with_height(Length::Fixed(5.0)))
+			.push(incoming_row);
+	}
+
+	Container::new(column)
+}
+
+#[cfg(test)]
+mod test {
+	use super::super::test_util::{gui_with_pending_send, run_command};
+	use super::super::Mode;
+	use super::{LocalViewInteraction as IN, *};
+
+	#[test]
+	fn test_inbox_offers_finalize_for_returned_slatepack() {
+		// Run a check of the watched folders through to its result
+		fn check(grin_gui: &mut GrinGui, time: chrono::DateTime<chrono::Local>) {
+			let cmd = handle_tick(grin_gui, time);
+			for message in run_command(cmd) {
+				match message {
+					Message::Interaction(Interaction::WalletOperationHomeInboxInteraction(l)) => {
+						handle_message(grin_gui, l).unwrap();
+					}
+					m => panic!("unexpected message {:?}", m),
+				}
+			}
+		}
+
+		let (fake, mut grin_gui, slate, s1) = gui_with_pending_send();
+		let tld = std::env::temp_dir().join(format!("grin-gui-inbox-{}", std::process::id()));
+		let mut wallet = grin_gui_core::config::Wallet::default();
+		wallet.tld = Some(tld.clone());
+		wallet.watch_slatepacks = true;
+		grin_gui.config.current_wallet_index = Some(grin_gui.config.add_wallet(wallet));
+		let slatepack_dir = PathBuf::from(grin_gui.config.get_wallet_slatepack_dir().unwrap());
+
+		// Our own S1 needs nothing further, the counterparty's S2 can be finalized
+		fs::write(slatepack_dir.join("sent.slatepack"), &s1).unwrap();
+		check(&mut grin_gui, chrono::Local::now());
+		assert!(grin_gui
+			.wallet_state
+			.operation_state
+			.home_state
+			.inbox_state
+			.incoming
+			.is_empty());
+
+		let (_, s2) =
+			futures::executor::block_on(fake.receive_tx_from_s1(slate.clone(), String::new()))
+				.unwrap();
+		fs::write(slatepack_dir.join("response.slatepack"), s2.unwrap()).unwrap();
+		fs::write(slatepack_dir.join("other.slatepack"), "not a slatepack").unwrap();
+		check(
+			&mut grin_gui,
+			chrono::Local::now() + chrono::Duration::seconds(10),
+		);
+
+		let state = &grin_gui.wallet_state.operation_state.home_state.inbox_state;
+		assert_eq!(state.incoming.len(), 1);
+		assert_eq!(state.incoming[0].slate_id(), slate.id);
+		assert_eq!(state.incoming[0].action, IncomingAction::Finalize);
+
+		let cmd = handle_message(&mut grin_gui, IN::Accept(slate.id)).unwrap();
+		assert!(grin_gui
+			.wallet_state
+			.operation_state
+			.home_state
+			.inbox_state
+			.incoming
+			.is_empty());
+		assert!(matches!(
+			grin_gui.wallet_state.operation_state.mode,
+			Mode::ApplyTx
+		));
+		assert!(matches!(
+			run_command(cmd).remove(0),
+			Message::Interaction(Interaction::WalletOperationApplyTxViewInteraction(
+				super::super::apply_tx::LocalViewInteraction::Continue
+			))
+		));
+
+		// Not offered again after a restart, once the saved slates are loaded
+		let inbox_state = &mut grin_gui.wallet_state.operation_state.home_state.inbox_state;
+		*inbox_state = Default::default();
+		inbox_state.handled = HandledSlatepacks::load_or_default().unwrap_or_default();
+		check(&mut grin_gui, chrono::Local::now());
+		let _ = fs::remove_dir_all(&tld);
+		assert!(grin_gui
+			.wallet_state
+			.operation_state
+			.home_state
+			.inbox_state
+			.incoming
+			.is_empty());
+	}
+}
@@ -0,0 +1,448 @@
+use crate::log_error;
+use grin_gui_core::{config::Config, error::GrinWalletInterfaceError};
+use std::fs::File;
+use std::io::Write;
+
+use {
+	super::super::super::{
+		BUTTON_HEIGHT, BUTTON_WIDTH, DEFAULT_FONT_SIZE, DEFAULT_HEADER_FONT_SIZE, DEFAULT_PADDING,
+		SMALLER_FONT_SIZE,
+	},
+	crate::gui::{GrinGui, Interaction, Message},
+	crate::localization::localized_string,
+	crate::Result,
+	grin_gui_core::node::amount_from_hr_string,
+	grin_gui_core::theme::{Button, Column, Container, Element, Row, Scrollable, Text, TextInput},
+	grin_gui_core::wallet::{IssueInvoiceTxArgs, Slate},
+	iced::widget::Space,
+	iced::{alignment, Command, Length},
+	std::sync::{Arc, RwLock},
+};
+
+#[derive(Default)]
+pub struct StateContainer {
+	pub payer_address_value: String,
+	pub amount_value: String,
+	// whether amount has errored
+	amount_error: bool,
+	// slatepack address error
+	slatepack_address_error: bool,
+	pub is_issuing: bool,
+}
+
+impl StateContainer {
+	pub fn clear(&mut self) {
+		*self = Default::default();
+	}
+}
+
+#[derive(Debug, Clone)]
+pub enum LocalViewInteraction {
+	Back,
+	PayerAddress(String),
+	Amount(String),
+	IssueInvoice,
+
+	InvoiceIssuedOk(Slate, String),
+	InvoiceIssueError(Arc<RwLock<Option<anyhow::Error>>>),
+	SlatepackAddressError,
+}
+
+pub fn handle_message<'a>(
+	grin_gui: &mut GrinGui,
+	message: LocalViewInteraction,
+) -> Result<Command<Message>> {
+	let state = &mut grin_gui.wallet_state.operation_state.issue_invoice_state;
+
+	match message {
+		LocalViewInteraction::Back => {
+			log::debug!("Interaction::WalletOperationIssueInvoiceViewInteraction(Back)");
+			state.clear();
+			grin_gui.wallet_state.operation_state.mode =
+				crate::gui::element::wallet::operation::Mode::Home;
+		}
+		LocalViewInteraction::PayerAddress(s) => {
+			state.payer_address_value = s;
+			state.slatepack_address_error = false;
+		}
+		LocalViewInteraction::Amount(s) => {
+			state.amount_value = s;
+			state.amount_error = false;
+		}
+		LocalViewInteraction::IssueInvoice => {
+			if state.is_issuing {
+				return Ok(Command::none());
+			}
+			grin_gui.error.take();
+			state.amount_error = false;
+			state.slatepack_address_error = false;
+
+			log::debug!("Interaction::WalletOperationIssueInvoiceViewInteraction(IssueInvoice)");
+
+			let amount = match amount_from_hr_string(&state.amount_value) {
+				Ok(0) | Err(_) => {
+					state.amount_error = true;
+					return Ok(Command::none());
+				}
+				Ok(a) if a > std::i64::MAX as u64 => {
+					state.amount_error = true;
+					return Ok(Command::none());
+				}
+				Ok(a) => a,
+			};
+			state.is_issuing = true;
+
+			let args = IssueInvoiceTxArgs {
+				dest_acct_name: grin_gui
+					.wallet_state
+					.operation_state
+					.home_state
+					.active_account
+					.clone(),
+				amount,
+				..Default::default()
+			};
+			let dest = state.payer_address_value.clone();
+			let w = grin_gui.wallet_backend.clone();
+			let fut = move || w.issue_invoice_tx(args, dest);
+
+			return Ok(Command::perform(fut(), |r| match r {
+				Ok((slate, enc_slate)) => {
+					Message::Interaction(Interaction::WalletOperationIssueInvoiceViewInteraction(
+						LocalViewInteraction::InvoiceIssuedOk(slate, enc_slate),
+					))
+				}
+				Err(e) => match e {
+					GrinWalletInterfaceError::InvalidSlatepackAddress => Message::Interaction(
+						Interaction::WalletOperationIssueInvoiceViewInteraction(
+							LocalViewInteraction::SlatepackAddressError,
+						),
+					),
+					_ => Message::Interaction(
+						Interaction::WalletOperationIssueInvoiceViewInteraction(
+							LocalViewInteraction::InvoiceIssueError(Arc::new(RwLock::new(Some(
+								anyhow::Error::from(e),
+							)))),
+						),
+					),
+				},
+			}));
+		}
+		LocalViewInteraction::InvoiceIssuedOk(slate, encrypted_slate) => {
+			state.clear();
+
+			// The payer's I2 response is applied against the same slate id
+			if let Some(dir) = grin_gui.config.get_wallet_slatepack_dir() {
+				let out_file_name = format!("{}/{}.slatepack", dir, slate.id);
+				let mut output = File::create(out_file_name.clone())?;
+				output.write_all(&encrypted_slate.as_bytes())?;
+				output.sync_all()?;
+			}
+
+			let show_slatepack_state =
+				&mut grin_gui.wallet_state.operation_state.show_slatepack_state;
+			show_slatepack_state.encrypted_slate = Some(encrypted_slate);
+			show_slatepack_state.title_label = localized_string("invoice-issued-title");
+			show_slatepack_state.desc = localized_string("invoice-issued-desc");
+
+			grin_gui.wallet_state.operation_state.mode =
+				crate::gui::element::wallet::operation::Mode::ShowSlatepack;
+		}
+		LocalViewInteraction::InvoiceIssueError(err) => {
+			state.is_issuing = false;
+			grin_gui.error = err.write().unwrap().take();
+			if let Some(e) = grin_gui.error.as_ref() {
+				log_error(e);
+			}
+		}
+		LocalViewInteraction::SlatepackAddressError => {
+			state.is_issuing = false;
+			state.slatepack_address_error = true;
+		}
+	}
+
+	Ok(Command::none())
+}
+
+pub fn data_container<'a>(config: &'a Config, state: &'a StateContainer) -> Container<'a, Message> {
+	// Title row
+	let title = Text::new(localized_string("issue-invoice"))
+		.size(DEFAULT_HEADER_FONT_SIZE)
+		.horizontal_alignment(alignment::Horizontal::Center);
+
+	let title_container = Container::new(title)
+		.style(grin_gui_core::theme::ContainerStyle::BrightBackground)
+		.padding(iced::Padding::from([
+			2, // top
+			0, // right
+			2, // bottom
+			5, // left
+		]));
+
+	let header_row = Row::new().push(title_container);
+
+	let header_container = Container::new(header_row).padding(iced::Padding::from([
+		0,                      // top
+		0,                      // right
+		DEFAULT_PADDING as u16, // bottom
+		0,                      // left
+	]));
+
+	let payer_address = Text::new(localized_string("payer-address"))
+		.size(DEFAULT_FONT_SIZE)
+		.horizontal_alignment(alignment::Horizontal::Left);
+
+	let payer_address_container =
+		Container::new(payer_address).style(grin_gui_core::theme::ContainerStyle::NormalBackground);
+
+	let address_instruction = Text::new(localized_string("payer-address-instruction"))
+		.size(SMALLER_FONT_SIZE)
+		.horizontal_alignment(alignment::Horizontal::Left);
+
+	let address_instruction_container = Container::new(address_instruction)
+		.style(grin_gui_core::theme::ContainerStyle::NormalBackground);
+
+	let payer_address_input: Element<Interaction> = TextInput::new("", &state.payer_address_value)
+		.on_input(|s| {
+			Interaction::WalletOperationIssueInvoiceViewInteraction(
+				LocalViewInteraction::PayerAddress(s),
+			)
+		})
+		.size(DEFAULT_FONT_SIZE)
+		.padding(6)
+		.width(Length::Fixed(400.0))
+		.style(grin_gui_core::theme::TextInputStyle::AddonsQuery)
+		.into();
+
+	let address_error = Text::new(localized_string("create-tx-address-error"))
+		.size(DEFAULT_FONT_SIZE)
+		.horizontal_alignment(alignment::Horizontal::Left)
+		.style(grin_gui_core::theme::text::TextStyle::Warning);
+
+	let address_error_container =
+		Container::new(address_error).style(grin_gui_core::theme::ContainerStyle::NormalBackground);
+
+	let amount = Text::new(localized_string("issue-invoice-amount"))
+		.size(DEFAULT_FONT_SIZE)
+		.horizontal_alignment(alignment::Horizontal::Left);
+
+	let amount_container =
+		Container::new(amount).style(grin_gui_core::theme::ContainerStyle::NormalBackground);
+
+	let amount_input: Element<Interaction> = TextInput::new("", &state.amount_value)
+		.on_input(|s| {
+			Interaction::WalletOperationIssueInvoiceViewInteraction(LocalViewInteraction::Amount(s))
+		})
+		.size(DEFAULT_FONT_SIZE)
+		.padding(6)
+		.width(Length::Fixed(100.0))
+		.style(grin_gui_core::theme::TextInputStyle::AddonsQuery)
+		.into();
+
+	let amount_error = Text::new(localized_string("create-tx-amount-error"))
+		.size(DEFAULT_FONT_SIZE)
+		.horizontal_alignment(alignment::Horizontal::Left)
+		.style(grin_gui_core::theme::text::TextStyle::Warning);
+
+	let amount_error_container =
+		Container::new(amount_error).style(grin_gui_core::theme::ContainerStyle::NormalBackground);
+
+	let button_height = Length::Fixed(BUTTON_HEIGHT);
+	let button_width = Length::Fixed(BUTTON_WIDTH);
+
+	let submit_button_label_container =
+		Container::new(Text::new(localized_string("issue-invoice")).size(DEFAULT_FONT_SIZE))
+			.width(button_width)
+			.height(button_height)
+			.center_x()
+			.center_y()
+			.align_x(alignment::Horizontal::Center);
+
+	let mut submit_button = Button::new(submit_button_label_container)
+		.style(grin_gui_core::theme::ButtonStyle::Primary);
+
+	if !state.is_issuing {
+		submit_button =
+			submit_button.on_press(Interaction::WalletOperationIssueInvoiceViewInteraction(
+				LocalViewInteraction::IssueInvoice,
+			));
+	}
+
+	let submit_button: Element<Interaction> = submit_button.into();
+
+	let cancel_button_label_container =
+		Container::new(Text::new(localized_string("cancel")).size(DEFAULT_FONT_SIZE))
+			.width(button_width)
+			.height(button_height)
+			.center_x()
+			.center_y()
+			.align_x(alignment::Horizontal::Center);
+
+	let cancel_button: Element<Interaction> = Button::new(cancel_button_label_container)
+		.style(grin_gui_core::theme::ButtonStyle::Primary)
+		.on_press(Interaction::WalletOperationIssueInvoiceViewInteraction(
+			LocalViewInteraction::Back,
+		))
+		.into();
+
+	let submit_container = Container::new(submit_button.map(Message::Interaction)).padding(1);
+	let submit_container = Container::new(submit_container)
+		.style(grin_gui_core::theme::ContainerStyle::Segmented)
+		.padding(1);
+
+	let cancel_container = Container::new(cancel_button.map(Message::Interaction)).padding(1);
+	let cancel_container = Container::new(cancel_container)
+		.style(grin_gui_core::theme::ContainerStyle::Segmented)
+		.padding(1);
+
+	let unit_spacing = 15.0;
+	let button_row = Row::new()
+		.push(submit_container)
+		.push(Space::new(Length::Fixed(unit_spacing), Length::Fixed(0.0)))
+		.push(cancel_container);
+
+	let mut column = Column::new()
+		.push(payer_address_container)
+		.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)))
+		.push(address_instruction_container)
+		.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)))
+		.push(payer_address_input.map(Message::Interaction))
+		.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)));
+
+	if state.slatepack_address_error {
+		column = column
+			.push(address_error_container)
+			.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)));
+	}
+
+	column = column
+		.push(amount_container)
+		.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)))
+		.push(amount_input.map(Message::Interaction))
+		.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)));
+
+	if state.amount_error {
+		column = column
+			.push(amount_error_container)
+			.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)));
+	}
+
+	column = column
+		.push(button_row)
+		.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)));
+
+	let form_container = Container::new(column)
+		.width(Length::Fill)
+		.padding(iced::Padding::from([
+			0, // top
+			0, // right
+			0, // bottom
+			5, // left
+		]));
+
+	// form container should be scrollable in tiny windows
+	let scrollable = Scrollable::new(form_container)
+		.height(Length::Fill)
+		.style(grin_gui_core::theme::ScrollableStyle::Primary);
+
+	let content = Container::new(scrollable)
+		.width(Length::Fill)
+		.height(Length::Shrink)
+		.style(grin_gui_core::theme::ContainerStyle::NormalBackground);
+
+	let wrapper_column = Column::new()
+		.height(Length::Fill)
+		.push(header_container)
+		.push(content);
+
+	// Returns the final container.
+	Container::new(wrapper_column).padding(iced::Padding::from([
+		DEFAULT_PADDING, // top
+		DEFAULT_PADDING, // right
+		DEFAULT_PADDING, // bottom
+		DEFAULT_PADDING, // left
+	]))
+}
+
+#[cfg(test)]
+mod test {
+	use super::super::apply_tx_confirm::{self, LocalViewInteraction as AC};
+	use super::super::test_util::{gui_with_fake, run_command};
+	use super::super::Mode;
+	use super::{LocalViewInteraction as II, *};
+	use grin_gui_core::config::TxMethod;
+	use grin_gui_core::wallet::fake::FakeWalletBackend;
+	use grin_gui_core::wallet::{SlateState, Slatepack};
+
+	#[test]
+	fn test_legacy_invoice_issue_pay_finalize() {
+		fn accept(grin_gui: &mut GrinGui, slate: Slate) -> AC {
+			grin_gui.config.tx_method = TxMethod::Legacy;
+			let confirm_state = &mut grin_gui
+				.wallet_state
+				.operation_state
+				.apply_tx_state
+				.confirm_state;
+			confirm_state.slatepack_parsed = Some((Slatepack::default(), slate, None));
+			let cmd = apply_tx_confirm::handle_message(grin_gui, AC::Accept).unwrap();
+			match run_command(cmd).remove(0) {
+				Message::Interaction(
+					Interaction::WalletOperationApplyTxConfirmViewInteraction(l),
+				) => l,
+				m => panic!("unexpected message {:?}", m),
+			}
+		}
+
+		let issuer = Arc::new(FakeWalletBackend::open_with_balance("password", 0));
+		let payer = Arc::new(FakeWalletBackend::open_with_balance(
+			"password",
+			5_000_000_000,
+		));
+		let mut issuer_gui = gui_with_fake(issuer.clone());
+		let mut payer_gui = gui_with_fake(payer.clone());
+
+		handle_message(&mut issuer_gui, II::Amount("2".to_owned())).unwrap();
+		let cmd = handle_message(&mut issuer_gui, II::IssueInvoice).unwrap();
+		let slate = match run_command(cmd).remove(0) {
+			Message::Interaction(Interaction::WalletOperationIssueInvoiceViewInteraction(
+				II::InvoiceIssuedOk(slate, enc_slate),
+			)) => {
+				let cmd = handle_message(
+					&mut issuer_gui,
+					II::InvoiceIssuedOk(slate.clone(), enc_slate),
+				)
+				.unwrap();
+				assert!(run_command(cmd).is_empty());
+				slate
+			}
+			m => panic!("unexpected message {:?}", m),
+		};
+		assert_eq!(slate.state, SlateState::Invoice1);
+		assert_eq!(slate.amount, 2_000_000_000);
+		assert_eq!(
+			issuer_gui.wallet_state.operation_state.mode,
+			Mode::ShowSlatepack
+		);
+
+		// The payer pays the requested amount and hands back an I2 slate
+		let paid = match accept(&mut payer_gui, slate) {
+			AC::TxAcceptSuccess(slate, Some(_), false) => slate,
+			l => panic!("unexpected interaction {:?}", l),
+		};
+		assert_eq!(paid.state, SlateState::Invoice2);
+		assert_eq!(payer.state().info.amount_locked, 2_000_000_000);
+
+		match accept(&mut issuer_gui, paid) {
+			AC::TxAcceptSuccess(slate, None, true) => assert_eq!(slate.state, SlateState::Invoice3),
+			l => panic!("unexpected interaction {:?}", l),
+		}
+		assert_eq!(
+			issuer.state().calls,
+			vec!["issue_invoice_tx", "finalize_invoice_tx"]
+		);
+		assert_eq!(
+			issuer.state().info.amount_awaiting_confirmation,
+			2_000_000_000
+		);
+	}
+}
@@ -0,0 +1,525 @@
+use crate::log_error;
+use chrono::{DateTime, Utc};
+use grin_gui_core::config::{Config, ForeignListenerConfig};
+use grin_gui_core::wallet::{TxLogEntry, TxLogEntryType};
+use std::net::IpAddr;
+
+use {
+	super::super::super::{
+		BUTTON_HEIGHT, BUTTON_WIDTH, DEFAULT_FONT_SIZE, DEFAULT_HEADER_FONT_SIZE, DEFAULT_PADDING,
+		SMALLER_FONT_SIZE,
+	},
+	crate::gui::{GrinGui, Interaction, Message},
+	crate::localization::localized_string,
+	crate::Result,
+	anyhow::Context,
+	grin_gui_core::node::amount_to_hr_string,
+	grin_gui_core::theme::{Button, Column, Container, Element, Row, Scrollable, Text, TextInput},
+	iced::widget::{Checkbox, Space},
+	iced::{alignment, Alignment, Command, Length},
+	std::sync::{Arc, RwLock},
+};
+
+/// How often the transaction log is checked for receives while the listener runs
+const CHECK_INTERVAL_SECS: i64 = 10;
+
+#[derive(Default)]
+pub struct StateContainer {
+	pub bind_address_value: String,
+	pub port_value: String,
+	pub secret_value: String,
+	/// Address the listener is serving on, while running
+	pub running_addr: Option<String>,
+	/// Transactions received since the listener was started, newest first
+	pub received: Vec<TxLogEntry>,
+	/// Why the settings in the form couldn't be applied
+	form_error: Option<String>,
+	/// Set when starting failed, so it isn't retried every tick
+	start_failed: bool,
+	started_at: Option<DateTime<Utc>>,
+	last_check: chrono::DateTime<chrono::Local>,
+}
+
+impl StateContainer {
+	pub fn clear(&mut self) {
+		*self = Default::default();
+	}
+
+	/// Fill the form from the wallet's saved settings
+	pub fn load(&mut self, config: &ForeignListenerConfig) {
+		self.bind_address_value = config.bind_address.clone();
+		self.port_value = config.port.to_string();
+		self.secret_value = config.read_secret().ok().flatten().unwrap_or_default();
+		self.form_error = None;
+	}
+}
+
+#[derive(Debug, Clone)]
+pub enum LocalViewInteraction {
+	Back,
+	Enabled(bool),
+	BindAddress(String),
+	Port(String),
+	Secret(String),
+	Apply,
+	TxsUpdated(Vec<TxLogEntry>),
+	TxsUpdateError(Arc<RwLock<Option<anyhow::Error>>>),
+}
+
+fn listener_config(config: &Config) -> Option<ForeignListenerConfig> {
+	config.current_wallet().map(|w| w.foreign_listener.clone())
+}
+
+/// Start the listener with the wallet's settings, reporting any failure
+fn start_listener(grin_gui: &mut GrinGui) {
+	let config = match listener_config(&grin_gui.config) {
+		Some(c) => c,
+		None => return,
+	};
+	let res = grin_gui
+		.wallet_backend
+		.start_foreign_listener(config)
+		.context("Failed to start foreign listener");
+	let state = &mut grin_gui
+		.wallet_state
+		.operation_state
+		.home_state
+		.listener_state;
+	match res {
+		Ok(addr) => {
+			log::info!("Foreign listener running on {}", addr);
+			state.running_addr = Some(addr);
+			state.started_at = Some(Utc::now());
+			state.received.clear();
+			state.start_failed = false;
+		}
+		Err(e) => {
+			state.start_failed = true;
+			log_error(&e);
+			grin_gui.error = Some(e);
+		}
+	}
+}
+
+fn stop_listener(grin_gui: &mut GrinGui) {
+	grin_gui.wallet_backend.stop_foreign_listener();
+	let state = &mut grin_gui
+		.wallet_state
+		.operation_state
+		.home_state
+		.listener_state;
+	state.running_addr = None;
+	state.started_at = None;
+}
+
+/// Starts the listener once a wallet that wants it is open, and keeps the log of received
+/// transactions up to date while it runs
+pub fn handle_tick(
+	grin_gui: &mut GrinGui,
+	time: chrono::DateTime<chrono::Local>,
+) -> Command<Message> {
+	if !grin_gui.wallet_backend.wallet_is_open() {
+		return Command::none();
+	}
+	let enabled = listener_config(&grin_gui.config).map_or(false, |c| c.enabled);
+	let state = &grin_gui
+		.wallet_state
+		.operation_state
+		.home_state
+		.listener_state;
+	if enabled && state.running_addr.is_none() && !state.start_failed {
+		start_listener(grin_gui);
+	}
+
+	let state = &mut grin_gui
+		.wallet_state
+		.operation_state
+		.home_state
+		.listener_state;
+	if state.running_addr.is_none() || (time - state.last_check).num_seconds() < CHECK_INTERVAL_SECS
+	{
+		return Command::none();
+	}
+	state.last_check = time;
+
+	let w = grin_gui.wallet_backend.clone();
+	let fut = move || w.get_txs(None);
+	Command::perform(fut(), |r| {
+		match r.context("Failed to retrieve transactions") {
+			Ok((_, txs)) => {
+				Message::Interaction(Interaction::WalletOperationListenerViewInteraction(
+					LocalViewInteraction::TxsUpdated(txs),
+				))
+			}
+			Err(e) => Message::Interaction(Interaction::WalletOperationListenerViewInteraction(
+				LocalViewInteraction::TxsUpdateError(Arc::new(RwLock::new(Some(e)))),
+			)),
+		}
+	})
+}
+
+pub fn handle_message<'a>(
+	grin_gui: &mut GrinGui,
+	message: LocalViewInteraction,
+) -> Result<Command<Message>> {
+	let state = &mut grin_gui
+		.wallet_state
+		.operation_state
+		.home_state
+		.listener_state;
+
+	match message {
+		LocalViewInteraction::Back => {
+			log::debug!("Interaction::WalletOperationListenerViewInteraction(Back)");
+			state.form_error = None;
+			grin_gui.wallet_state.operation_state.mode =
+				crate::gui::element::wallet::operation::Mode::Home;
+		}
+		LocalViewInteraction::Enabled(enabled) => {
+			if let Some(wallet) = grin_gui.config.current_wallet_mut() {
+				wallet.foreign_listener.enabled = enabled;
+				let _ = grin_gui.config.save();
+			}
+			if enabled {
+				grin_gui.error.take();
+				start_listener(grin_gui);
+			} else {
+				stop_listener(grin_gui);
+			}
+		}
+		LocalViewInteraction::BindAddress(s) => state.bind_address_value = s,
+		LocalViewInteraction::Port(s) => state.port_value = s,
+		LocalViewInteraction::Secret(s) => state.secret_value = s,
+		LocalViewInteraction::Apply => {
+			if state.bind_address_value.trim().parse::<IpAddr>().is_err() {
+				state.form_error = Some(localized_string("listener-address-error"));
+				return Ok(Command::none());
+			}
+			let port = match state.port_value.trim().parse::<u16>() {
+				Ok(p) if p > 0 => p,
+				_ => {
+					state.form_error = Some(localized_string("listener-port-error"));
+					return Ok(Command::none());
+				}
+			};
+			state.form_error = None;
+			let bind_address = state.bind_address_value.trim().to_owned();
+			let secret = Some(state.secret_value.trim().to_owned()).filter(|s| !s.is_empty());
+
+			let mut enabled = false;
+			if let Some(wallet) = grin_gui.config.current_wallet_mut() {
+				wallet.foreign_listener.bind_address = bind_address;
+				wallet.foreign_listener.port = port;
+				if let Err(e) = wallet
+					.set_foreign_listener_secret(secret.as_deref())
+					.context("Failed to save foreign listener secret")
+				{
+					log_error(&e);
+					grin_gui.error = Some(e);
+					return Ok(Command::none());
+				}
+				enabled = wallet.foreign_listener.enabled;
+				let _ = grin_gui.config.save();
+			}
+			// Restart with the new settings
+			if enabled {
+				grin_gui.error.take();
+				stop_listener(grin_gui);
+				start_listener(grin_gui);
+			}
+		}
+		LocalViewInteraction::TxsUpdated(txs) => {
+			let started_at = match state.started_at {
+				Some(t) => t,
+				None => return Ok(Command::none()),
+			};
+			state.received = txs
+				.into_iter()
+				.filter(|tx| {
+					tx.tx_type == TxLogEntryType::TxReceived && tx.creation_ts >= started_at
+				})
+				.collect();
+			state
+				.received
+				.sort_by(|a, b| b.creation_ts.cmp(&a.creation_ts));
+		}
+		LocalViewInteraction::TxsUpdateError(err) => {
+			grin_gui.error = err.write().unwrap().take();
+			if let Some(e) = grin_gui.error.as_ref() {
+				log_error(e);
+			}
+		}
+	}
+
+	Ok(Command::none())
+}
+
+/// Listener status shown on the wallet home screen
+pub fn status_string(state: &StateContainer) -> String {
+	match state.running_addr.as_ref() {
+		Some(addr) => format!("{} {}", localized_string("listener-running"), addr),
+		None => localized_string("listener-stopped"),
+	}
+}
+
+pub fn data_container<'a>(config: &'a Config, state: &'a StateContainer) -> Container<'a, Message> {
+	// Title row
+	let title = Text::new(localized_string("listener-title"))
+		.size(DEFAULT_HEADER_FONT_SIZE)
+		.horizontal_alignment(alignment::Horizontal::Center);
+
+	let title_container = Container::new(title)
+		.style(grin_gui_core::theme::ContainerStyle::BrightBackground)
+		.padding(iced::Padding::from([
+			2, // top
+			0, // right
+			2, // bottom
+			5, // left
+		]));
+
+	let header_row = Row::new().push(title_container);
+
+	let header_container = Container::new(header_row).padding(iced::Padding::from([
+		0,                      // top
+		0,                      // right
+		DEFAULT_PADDING as u16, // bottom
+		0,                      // left
+	]));
+
+	let unit_spacing = 15.0;
+	let row_spacing = 5.0;
+
+	let button_height = Length::Fixed(BUTTON_HEIGHT);
+	let button_width = Length::Fixed(BUTTON_WIDTH);
+
+	let enabled = listener_config(config).map_or(false, |c| c.enabled);
+
+	let instruction = Text::new(localized_string("listener-instruction"))
+		.size(SMALLER_FONT_SIZE)
+		.horizontal_alignment(alignment::Horizontal::Left);
+
+	let enabled_checkbox: Element<Interaction> =
+		Checkbox::new(localized_string("listener-enabled"), enabled)
+			.on_toggle(|b| {
+				Interaction::WalletOperationListenerViewInteraction(LocalViewInteraction::Enabled(
+					b,
+				))
+			})
+			.style(grin_gui_core::theme::CheckboxStyle::Normal)
+			.text_size(DEFAULT_FONT_SIZE)
+			.spacing(10)
+			.into();
+
+	let mut status = Text::new(status_string(state)).size(DEFAULT_FONT_SIZE);
+	if enabled && state.running_addr.is_none() {
+		status = status.style(grin_gui_core::theme::text::TextStyle::Warning);
+	}
+
+	let mut column = Column::new()
+		.push(
+			Container::new(instruction)
+				.style(grin_gui_core::theme::ContainerStyle::NormalBackground),
+		)
+		.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)))
+		.push(enabled_checkbox.map(Message::Interaction))
+		.push(Space::new(Length::Fixed(0.0), Length::Fixed(row_spacing)))
+		.push(status)
+		.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)));
+
+	let input =
+		|label: &str, value: &str, secure: bool, on_input: fn(String) -> LocalViewInteraction| {
+			let input: Element<Interaction> = TextInput::new(&localized_string(label), value)
+				.on_input(move |s| Interaction::WalletOperationListenerViewInteraction(on_input(s)))
+				.size(DEFAULT_FONT_SIZE)
+				.padding(6)
+				.width(Length::Fixed(400.0))
+				.style(grin_gui_core::theme::TextInputStyle::AddonsQuery)
+				.secure(secure)
+				.into();
+			Column::new()
+				.push(Text::new(localized_string(label)).size(SMALLER_FONT_SIZE))
+				.push(Space::new(Length::Fixed(0.0), Length::Fixed(row_spacing)))
+				.push(input.map(Message::Interaction))
+		};
+
+	column = column
+		.push(input(
+			"listener-bind-address",
+			&state.bind_address_value,
+			false,
+			LocalViewInteraction::BindAddress,
+		))
+		.push(Space::new(Length::Fixed(0.0), Length::Fixed(row_spacing)))
+		.push(input(
+			"listener-port",
+			&state.port_value,
+			false,
+			LocalViewInteraction::Port,
+		))
+		.push(Space::new(Length::Fixed(0.0), Length::Fixed(row_spacing)))
+		.push(input(
+			"listener-secret",
+			&state.secret_value,
+			true,
+			LocalViewInteraction::Secret,
+		))
+		.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)));
+
+	if let Some(e) = state.form_error.as_ref() {
+		column = column
+			.push(
+				Text::new(e)
+					.size(DEFAULT_FONT_SIZE)
+					.style(grin_gui_core::theme::text::TextStyle::Warning),
+			)
+			.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)));
+	}
+
+	if state.running_addr.is_some() {
+		column = column
+			.push(
+				Container::new(
+					Text::new(localized_string("listener-received")).size(DEFAULT_FONT_SIZE),
+				)
+				.style(grin_gui_core::theme::ContainerStyle::BrightBackground),
+			)
+			.push(Space::new(Length::Fixed(0.0), Length::Fixed(row_spacing)));
+
+		if state.received.is_empty() {
+			column = column.push(
+				Text::new(localized_string("listener-received-none")).size(SMALLER_FONT_SIZE),
+			);
+		}
+		for tx in state.received.iter() {
+			let tx_text = Text::new(format!(
+				"{}  {}  {}",
+				tx.creation_ts
+					.with_timezone(&chrono::Local)
+					.format("%Y-%m-%d %H:%M:%S"),
+				amount_to_hr_string(tx.amount_credited, true),
+				tx.tx_slate_id.map(|id| id.to_string()).unwrap_or_default(),
+			))
+			.size(SMALLER_FONT_SIZE);
+			column = column
+				.push(tx_text)
+				.push(Space::new(Length::Fixed(0.0), Length::Fixed(row_spacing)));
+		}
+		column = column.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)));
+	}
+
+	let button = |label: &str, message: LocalViewInteraction| {
+		let label_container =
+			Container::new(Text::new(localized_string(label)).size(DEFAULT_FONT_SIZE))
+				.width(button_width)
+				.height(button_height)
+				.center_x()
+				.center_y()
+				.align_x(alignment::Horizontal::Center);
+
+		let button: Element<Interaction> = Button::new(label_container)
+			.style(grin_gui_core::theme::ButtonStyle::Primary)
+			.on_press(Interaction::WalletOperationListenerViewInteraction(message))
+			.into();
+
+		let container = Container::new(button.map(Message::Interaction)).padding(1);
+		Container::new(container)
+			.style(grin_gui_core::theme::ContainerStyle::Segmented)
+			.padding(1)
+	};
+
+	let button_row = Row::new()
+		.push(button("listener-apply", LocalViewInteraction::Apply))
+		.push(Space::new(Length::Fixed(unit_spacing), Length::Fixed(0.0)))
+		.push(button("back", LocalViewInteraction::Back))
+		.align_items(Alignment::Center);
+
+	column = column.push(button_row);
+
+	let form_container = Container::new(column)
+		.width(Length::Fill)
+		.padding(iced::Padding::from([
+			0, // top
+			0, // right
+			0, // bottom
+			5, // left
+		]));
+
+	// form container should be scrollable in tiny windows
+	let scrollable = Scrollable::new(form_container)
+		.height(Length::Fill)
+		.style(grin_gui_core::theme::ScrollableStyle::Primary);
+
+	let content = Container::new(scrollable)
+		.width(Length::Fill)
+		.height(Length::Shrink)
+		.style(grin_gui_core::theme::ContainerStyle::NormalBackground);
+
+	let wrapper_column = Column::new()
+		.height(Length::Fill)
+		.push(header_container)
+		.push(content);
+
+	// Returns the final container.
+	Container::new(wrapper_column).padding(iced::Padding::from([
+		DEFAULT_PADDING, // top
+		DEFAULT_PADDING, // right
+		DEFAULT_PADDING, // bottom
+		DEFAULT_PADDING, // left
+	]))
+}
+
+#[cfg(test)]
+mod test {
+	use super::super::home;
+	use super::super::test_util::{funded_gui, run_command};
+	use super::{LocalViewInteraction as LI, *};
+	use grin_gui_core::wallet::WalletBackend;
+
+	#[test]
+	fn test_listener_starts_on_open_and_logs_receives() {
+		let (fake, mut grin_gui) = funded_gui();
+		let mut wallet = grin_gui_core::config::Wallet::default();
+		wallet.foreign_listener.enabled = true;
+		wallet.foreign_listener.port = 13415;
+		grin_gui.config.current_wallet_index = Some(grin_gui.config.add_wallet(wallet));
+
+		let cmd = handle_tick(&mut grin_gui, chrono::Local::now());
+		assert_eq!(
+			fake.state().listener_addr.as_deref(),
+			Some("127.0.0.1:13415")
+		);
+
+		// A receive recorded while the listener runs turns up at the next check
+		let tx = fake.record_listener_receive(1_000_000_000).unwrap();
+		let message = match run_command(cmd).remove(0) {
+			Message::Interaction(Interaction::WalletOperationListenerViewInteraction(m)) => m,
+			_ => panic!("Unexpected message"),
+		};
+		assert!(matches!(message, LI::TxsUpdated(_)));
+		handle_message(&mut grin_gui, message).unwrap();
+		let state = &grin_gui
+			.wallet_state
+			.operation_state
+			.home_state
+			.listener_state;
+		assert_eq!(state.running_addr.as_deref(), Some("127.0.0.1:13415"));
+		assert_eq!(state.received.len(), 1);
+		assert_eq!(state.received[0].tx_slate_id, tx.tx_slate_id);
+
+		// Closing the wallet stops the listener
+		home::handle_message(
+			&mut grin_gui,
+			home::LocalViewInteraction::WalletCloseSuccess,
+		)
+		.unwrap();
+		futures::executor::block_on(fake.close_wallet()).unwrap();
+		assert!(fake.foreign_listener_addr().is_none());
+		assert!(grin_gui
+			.wallet_state
+			.operation_state
+			.home_state
+			.listener_state
+			.running_addr
+			.is_none());
+	}
+}
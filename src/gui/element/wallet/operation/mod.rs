@@ -1,17 +1,33 @@
+pub mod accounting;
 pub mod action_menu;
 pub mod apply_tx;
 pub mod apply_tx_confirm;
+pub mod change_password;
 pub mod chart;
+pub mod contacts;
 pub mod create_tx;
 pub mod create_tx_contracts;
+pub mod expiry;
 pub mod home;
+pub mod inbox;
+pub mod issue_invoice;
+pub mod listener;
 pub mod open;
+pub mod outputs;
+pub mod qr;
+pub mod quote;
+pub mod recovery_phrase;
+pub mod scan;
 pub mod show_slatepack;
+#[cfg(test)]
+mod test_util;
 pub mod tx_detail;
 pub mod tx_done;
+pub mod tx_export;
 pub mod tx_list;
 pub mod tx_list_display;
 pub mod tx_proof;
+pub mod verify_proof;
 
 use {
 	crate::gui::{GrinGui, Message},
@@ -35,6 +51,15 @@ pub struct StateContainer {
 	pub tx_detail_state: tx_detail::StateContainer,
 	pub tx_proof_state: tx_proof::StateContainer,
 	pub tx_done_state: tx_done::StateContainer,
+	pub tx_export_state: tx_export::StateContainer,
+	pub accounting_state: accounting::StateContainer,
+	pub outputs_state: outputs::StateContainer,
+	pub scan_state: scan::StateContainer,
+	pub recovery_phrase_state: recovery_phrase::StateContainer,
+	pub change_password_state: change_password::StateContainer,
+	pub issue_invoice_state: issue_invoice::StateContainer,
+	pub verify_proof_state: verify_proof::StateContainer,
+	pub contacts_state: contacts::StateContainer,
 	// When changed to true, this should stay false until a wallet is opened with a password
 	has_wallet_open_check_failed_one_time: bool,
 }
@@ -49,6 +74,16 @@ pub enum Mode {
 	TxDetail,
 	TxProof,
 	TxDone,
+	Outputs,
+	Scan,
+	RecoveryPhrase,
+	ChangePassword,
+	IssueInvoice,
+	VerifyProof,
+	Contacts,
+	Listener,
+	TxExport,
+	Accounting,
 }
 
 impl Default for StateContainer {
@@ -64,6 +99,15 @@ impl Default for StateContainer {
 			tx_detail_state: Default::default(),
 			tx_proof_state: Default::default(),
 			tx_done_state: Default::default(),
+			tx_export_state: Default::default(),
+			accounting_state: Default::default(),
+			outputs_state: Default::default(),
+			scan_state: Default::default(),
+			recovery_phrase_state: Default::default(),
+			change_password_state: Default::default(),
+			issue_invoice_state: Default::default(),
+			verify_proof_state: Default::default(),
+			contacts_state: Default::default(),
 			has_wallet_open_check_failed_one_time: false,
 		}
 	}
@@ -95,20 +139,51 @@ pub fn handle_message(
 }
 
 pub fn data_container<'a>(state: &'a StateContainer, config: &'a Config) -> Container<'a, Message> {
+	let quote = state.home_state.price_quote(config.currency);
 	let content = match state.mode {
 		Mode::Open => open::data_container(&state.open_state, config),
 		Mode::Home => home::data_container(config, &state.home_state),
 		Mode::CreateTx => match config.tx_method {
-			TxMethod::Legacy => create_tx::data_container(config, &state.create_tx_state),
-			TxMethod::Contracts => {
-				create_tx_contracts::data_container(config, &state.create_tx_contracts_state)
-			}
+			TxMethod::Legacy => create_tx::data_container(
+				config,
+				&state.create_tx_state,
+				&state.outputs_state,
+				&state.contacts_state.contacts,
+				quote,
+			),
+			TxMethod::Contracts => create_tx_contracts::data_container(
+				config,
+				&state.create_tx_contracts_state,
+				&state.outputs_state,
+				&state.contacts_state.contacts,
+				quote,
+			),
 		},
 		Mode::ShowSlatepack => show_slatepack::data_container(config, &state.show_slatepack_state),
-		Mode::ApplyTx => apply_tx::data_container(config, &state.apply_tx_state),
+		Mode::ApplyTx => apply_tx::data_container(config, &state.apply_tx_state, quote),
 		Mode::TxDetail => tx_detail::data_container(config, &state.tx_detail_state),
 		Mode::TxProof => tx_proof::data_container(config, &state.tx_proof_state),
 		Mode::TxDone => tx_done::data_container(config, &state.tx_done_state),
+		Mode::Outputs => outputs::data_container(config, &state.outputs_state),
+		Mode::Scan => scan::data_container(config, &state.scan_state),
+		Mode::RecoveryPhrase => {
+			recovery_phrase::data_container(config, &state.recovery_phrase_state)
+		}
+		Mode::ChangePassword => {
+			change_password::data_container(config, &state.change_password_state)
+		}
+		Mode::IssueInvoice => issue_invoice::data_container(config, &state.issue_invoice_state),
+		Mode::VerifyProof => verify_proof::data_container(config, &state.verify_proof_state),
+		Mode::Contacts => contacts::data_container(config, &state.contacts_state),
+		Mode::Listener => listener::data_container(config, &state.home_state.listener_state),
+		Mode::TxExport => tx_export::data_container(
+			config,
+			&state.tx_export_state,
+			&state.home_state.tx_list_display_state,
+		),
+		Mode::Accounting => {
+			accounting::data_container(config, &state.accounting_state, &state.home_state)
+		}
 	};
 
 	let column = Column::new().push(content);
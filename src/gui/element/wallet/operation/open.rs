@@ -100,6 +100,38 @@ pub fn handle_message<'a>(
 			let current_wallet = &grin_gui.config.wallets[wallet_index];
 			let wallet_chain_type = current_wallet.chain_type;
 
+			if let Some(owner_api_url) = current_wallet.owner_api_url.clone() {
+				let owner_api_secret_path = current_wallet.owner_api_secret_path.clone();
+				let foreign_api_url = current_wallet.foreign_api_url.clone();
+				let fut = move || {
+					WalletInterface::open_remote_wallet(
+						wallet_interface,
+						password,
+						owner_api_url,
+						owner_api_secret_path,
+						foreign_api_url,
+						wallet_chain_type,
+					)
+				};
+
+				return Ok(Command::perform(fut(), |r| {
+					match r.context("Failed to Connect to Remote Wallet") {
+						Ok(()) => {
+							Message::Interaction(Interaction::WalletOperationOpenViewInteraction(
+								LocalViewInteraction::WalletOpenedOkay,
+							))
+						}
+						Err(e) => {
+							Message::Interaction(Interaction::WalletOperationOpenViewInteraction(
+								LocalViewInteraction::WalletOpenError(Arc::new(RwLock::new(Some(
+									e,
+								)))),
+							))
+						}
+					}
+				}));
+			}
+
 			if current_wallet.use_embedded_node {
 				// restart embedded server is chain types differ
 				if running_chain_type != wallet_chain_type {
@@ -118,14 +150,8 @@ pub fn handle_message<'a>(
 				}
 			}
 			let tld = current_wallet.tld.clone().unwrap();
-			let fut = move || {
-				WalletInterface::open_wallet(
-					wallet_interface,
-					password.clone(),
-					tld,
-					current_wallet.chain_type,
-				)
-			};
+			let w = grin_gui.wallet_backend.clone();
+			let fut = move || w.open_wallet(password.clone(), tld, wallet_chain_type);
 
 			return Ok(Command::perform(fut(), |r| {
 				match r.context("Failed to Open Wallet") {
@@ -0,0 +1,473 @@
+use crate::log_error;
+use grin_gui_core::{
+	config::Config,
+	wallet::{OutputCommitMapping, OutputStatus, RetrieveTxQueryArgs, TxLogEntry},
+};
+
+use {
+	super::super::super::{
+		BUTTON_HEIGHT, BUTTON_WIDTH, DEFAULT_FONT_SIZE, DEFAULT_HEADER_FONT_SIZE, DEFAULT_PADDING,
+		SMALLER_FONT_SIZE,
+	},
+	crate::gui::{GrinGui, Interaction, Message},
+	crate::localization::localized_string,
+	crate::Result,
+	anyhow::Context,
+	grin_gui_core::node::amount_to_hr_string,
+	grin_gui_core::theme::{
+		Button, Column, Container, Element, Row, Scrollable, TableRow, TableRowStyle, Text,
+	},
+	iced::widget::{Checkbox, Space},
+	iced::{alignment, Alignment, Command, Length},
+	std::sync::{Arc, RwLock},
+};
+
+#[derive(Default)]
+pub struct StateContainer {
+	pub outputs: Vec<OutputCommitMapping>,
+	pub include_spent: bool,
+	/// Commitments of outputs picked to be spent by the next transaction
+	pub selected: Vec<String>,
+	/// Chain height the outputs were last checked against, for coinbase maturity
+	pub current_height: u64,
+}
+
+impl StateContainer {
+	/// Comma separated commitment list of the selected outputs, as expected by the
+	/// contract API's `use_inputs`, or `None` to let the wallet choose
+	pub fn selected_inputs(&self) -> Option<String> {
+		if self.selected.is_empty() {
+			None
+		} else {
+			Some(self.selected.join(","))
+		}
+	}
+
+	pub fn selected_amount(&self) -> u64 {
+		self.selected_values().iter().sum()
+	}
+
+	/// Values of the selected outputs
+	pub fn selected_values(&self) -> Vec<u64> {
+		self.outputs
+			.iter()
+			.filter(|o| self.selected.contains(&commit_string(o)))
+			.map(|o| o.output.value)
+			.collect()
+	}
+
+	fn is_immature(&self, o: &OutputCommitMapping) -> bool {
+		o.output.is_coinbase && o.output.lock_height > self.current_height
+	}
+
+	fn is_selectable(&self, o: &OutputCommitMapping) -> bool {
+		o.output.status == OutputStatus::Unspent && !self.is_immature(o)
+	}
+}
+
+fn commit_string(o: &OutputCommitMapping) -> String {
+	o.output.commit.clone().unwrap_or_default()
+}
+
+#[derive(Debug, Clone)]
+pub enum LocalViewInteraction {
+	Back,
+	Refresh,
+	IncludeSpent(bool),
+	OutputsUpdateSuccess(bool, Vec<OutputCommitMapping>),
+	OutputsUpdateFailure(Arc<RwLock<Option<anyhow::Error>>>),
+	OutputSelected(String, bool),
+	ClearSelection,
+	ViewTx(u32),
+	TxRetrievedOk(Vec<TxLogEntry>),
+}
+
+pub fn handle_message<'a>(
+	grin_gui: &mut GrinGui,
+	message: LocalViewInteraction,
+) -> Result<Command<Message>> {
+	let state = &mut grin_gui.wallet_state.operation_state.outputs_state;
+
+	match message {
+		LocalViewInteraction::Back => {
+			log::debug!("Interaction::WalletOperationOutputsViewInteraction(Back)");
+			grin_gui.wallet_state.operation_state.mode =
+				crate::gui::element::wallet::operation::Mode::Home;
+		}
+		LocalViewInteraction::Refresh => {
+			let home_state = &grin_gui.wallet_state.operation_state.home_state;
+			if let Some(info) = home_state.wallet_info.as_ref() {
+				state.current_height = info.last_confirmed_height;
+			}
+
+			let w = grin_gui.wallet_backend.clone();
+			let include_spent = state.include_spent;
+			let refresh_from_node = home_state.node_synched;
+
+			let fut = move || w.get_outputs(include_spent, refresh_from_node);
+			return Ok(Command::perform(fut(), |r| {
+				match r.context("Failed to retrieve wallet outputs") {
+					Ok((node_success, outputs)) => {
+						Message::Interaction(Interaction::WalletOperationOutputsViewInteraction(
+							LocalViewInteraction::OutputsUpdateSuccess(node_success, outputs),
+						))
+					}
+					Err(e) => {
+						Message::Interaction(Interaction::WalletOperationOutputsViewInteraction(
+							LocalViewInteraction::OutputsUpdateFailure(Arc::new(RwLock::new(
+								Some(e),
+							))),
+						))
+					}
+				}
+			}));
+		}
+		LocalViewInteraction::IncludeSpent(b) => {
+			state.include_spent = b;
+			let fut = move || async {};
+			return Ok(Command::perform(fut(), |_| {
+				Message::Interaction(Interaction::WalletOperationOutputsViewInteraction(
+					LocalViewInteraction::Refresh,
+				))
+			}));
+		}
+		LocalViewInteraction::OutputsUpdateSuccess(node_success, outputs) => {
+			debug!("Outputs updated ({}): {}", node_success, outputs.len());
+			state.outputs = outputs;
+			// Drop any selection that can no longer be spent
+			let selectable: Vec<String> = state
+				.outputs
+				.iter()
+				.filter(|o| state.is_selectable(o))
+				.map(commit_string)
+				.collect();
+			state.selected.retain(|c| selectable.contains(c));
+		}
+		LocalViewInteraction::OutputsUpdateFailure(err) => {
+			grin_gui.error = err.write().unwrap().take();
+			if let Some(e) = grin_gui.error.as_ref() {
+				log_error(e);
+			}
+		}
+		LocalViewInteraction::OutputSelected(commit, selected) => {
+			state.selected.retain(|c| *c != commit);
+			if selected {
+				state.selected.push(commit);
+			}
+		}
+		LocalViewInteraction::ClearSelection => {
+			state.selected.clear();
+		}
+		LocalViewInteraction::ViewTx(id) => {
+			let w = grin_gui.wallet_backend.clone();
+			let query_args = RetrieveTxQueryArgs {
+				min_id: Some(id),
+				max_id: Some(id),
+				..Default::default()
+			};
+
+			let fut = move || w.get_txs(Some(query_args));
+			return Ok(Command::perform(fut(), |r| {
+				match r.context("Failed to retrieve transaction") {
+					Ok((_, txs)) => {
+						Message::Interaction(Interaction::WalletOperationOutputsViewInteraction(
+							LocalViewInteraction::TxRetrievedOk(txs),
+						))
+					}
+					Err(e) => {
+						Message::Interaction(Interaction::WalletOperationOutputsViewInteraction(
+							LocalViewInteraction::OutputsUpdateFailure(Arc::new(RwLock::new(
+								Some(e),
+							))),
+						))
+					}
+				}
+			}));
+		}
+		LocalViewInteraction::TxRetrievedOk(txs) => {
+			if let Some(tx) = txs.into_iter().next() {
+				grin_gui
+					.wallet_state
+					.operation_state
+					.tx_detail_state
+					.current_tx = Some(tx);
+				grin_gui.wallet_state.operation_state.mode =
+					crate::gui::element::wallet::operation::Mode::TxDetail;
+			}
+		}
+	}
+
+	Ok(Command::none())
+}
+
+pub fn data_container<'a>(config: &'a Config, state: &'a StateContainer) -> Container<'a, Message> {
+	// Title row
+	let title = Text::new(localized_string("outputs-title"))
+		.size(DEFAULT_HEADER_FONT_SIZE)
+		.horizontal_alignment(alignment::Horizontal::Center);
+
+	let title_container = Container::new(title)
+		.style(grin_gui_core::theme::ContainerStyle::BrightBackground)
+		.padding(iced::Padding::from([
+			2, // top
+			0, // right
+			2, // bottom
+			5, // left
+		]));
+
+	let include_spent_checkbox: Element<Interaction> =
+		Checkbox::new(localized_string("outputs-include-spent"), state.include_spent)
+			.on_toggle(|b| {
+				Interaction::WalletOperationOutputsViewInteraction(
+					LocalViewInteraction::IncludeSpent(b),
+				)
+			})
+			.style(grin_gui_core::theme::CheckboxStyle::Normal)
+			.text_size(DEFAULT_FONT_SIZE)
+			.spacing(10)
+			.into();
+
+	let refresh_container =
+		Container::new(Text::new(localized_string("refresh")).size(DEFAULT_FONT_SIZE))
+			.align_y(alignment::Vertical::Center)
+			.align_x(alignment::Horizontal::Center);
+
+	let refresh_button: Element<Interaction> = Button::new(refresh_container)
+		.style(grin_gui_core::theme::ButtonStyle::Primary)
+		.on_press(Interaction::WalletOperationOutputsViewInteraction(
+			LocalViewInteraction::Refresh,
+		))
+		.into();
+
+	let refresh_container = Container::new(refresh_button.map(Message::Interaction)).padding(1);
+	let refresh_container = Container::new(refresh_container)
+		.style(grin_gui_core::theme::ContainerStyle::Segmented)
+		.padding(1);
+
+	let header_row = Row::new()
+		.push(title_container)
+		.push(Space::with_width(Length::Fill))
+		.push(include_spent_checkbox.map(Message::Interaction))
+		.push(Space::with_width(Length::Fixed(DEFAULT_PADDING)))
+		.push(refresh_container)
+		.align_items(Alignment::Center);
+
+	let header_container = Container::new(header_row).padding(iced::Padding::from([
+		0,                      // top
+		0,                      // right
+		DEFAULT_PADDING as u16, // bottom
+		0,                      // left
+	]));
+
+	// (label, width portion) of each column
+	let columns = [
+		("outputs-commitment", 4),
+		("outputs-value", 2),
+		("outputs-height", 1),
+		("outputs-lock-height", 1),
+		("outputs-status", 1),
+		("outputs-coinbase", 1),
+		("outputs-tx", 1),
+	];
+
+	let mut table_header_row = Row::new().push(Space::with_width(Length::Fixed(30.0)));
+	for (label, portion) in columns.iter() {
+		let header = Text::new(localized_string(label)).size(DEFAULT_FONT_SIZE);
+		let header_container = Container::new(header)
+			.style(grin_gui_core::theme::ContainerStyle::BrightForeground)
+			.width(Length::FillPortion(*portion));
+		table_header_row = table_header_row.push(header_container);
+	}
+
+	let table_header_container = Container::new(table_header_row)
+		.padding(iced::Padding::from([
+			9,                      // top
+			DEFAULT_PADDING as u16, // right - should roughly match width of content scroll bar to align table headers
+			9,                      // bottom
+			9,                      // left
+		]))
+		.style(grin_gui_core::theme::ContainerStyle::PanelForeground);
+
+	let mut output_rows: Vec<Element<Interaction>> = vec![];
+	for (pos, o) in state.outputs.iter().enumerate() {
+		let commit = commit_string(o);
+		let is_selected = state.selected.contains(&commit);
+
+		// Only outputs the wallet could spend right now can be picked
+		let checkbox: Element<Interaction> = if state.is_selectable(o) {
+			let commit = commit.clone();
+			Checkbox::new("", is_selected)
+				.on_toggle(move |b| {
+					Interaction::WalletOperationOutputsViewInteraction(
+						LocalViewInteraction::OutputSelected(commit.clone(), b),
+					)
+				})
+				.style(grin_gui_core::theme::CheckboxStyle::Normal)
+				.into()
+		} else {
+			Space::with_width(Length::Fixed(20.0)).into()
+		};
+
+		// Truncate a bit for compact display purposes
+		let display_commit = if commit.len() > 24 {
+			format!("{}...{}", &commit[..12], &commit[commit.len() - 12..])
+		} else {
+			commit.clone()
+		};
+
+		let coinbase = if !o.output.is_coinbase {
+			"".to_owned()
+		} else if state.is_immature(o) {
+			localized_string("outputs-immature")
+		} else {
+			localized_string("yes")
+		};
+
+		let tx = match o.output.tx_log_entry {
+			Some(id) => format!("{}", id),
+			None => "".to_owned(),
+		};
+
+		let values = vec![
+			display_commit,
+			amount_to_hr_string(o.output.value, true),
+			format!("{}", o.output.height),
+			format!("{}", o.output.lock_height),
+			format!("{}", o.output.status),
+			coinbase,
+			tx,
+		];
+
+		let row_content = values
+			.into_iter()
+			.zip(columns.iter())
+			.map(|(v, (_, portion))| {
+				Container::new(Text::new(v).size(SMALLER_FONT_SIZE))
+					.style(grin_gui_core::theme::ContainerStyle::HoverableForeground)
+					.width(Length::FillPortion(*portion))
+			})
+			.collect();
+
+		let mut table_row = TableRow::new(row_content, 0)
+			.padding(iced::Padding::from(6))
+			.width(Length::Fill);
+
+		// Clicking through to the transaction that created the output
+		if let Some(id) = o.output.tx_log_entry {
+			table_row = table_row.on_press(move |_| {
+				Interaction::WalletOperationOutputsViewInteraction(LocalViewInteraction::ViewTx(id))
+			});
+		}
+
+		if is_selected {
+			table_row = table_row.style(TableRowStyle::TableRowSelected);
+		} else if pos % 2 == 0 {
+			table_row = table_row.style(TableRowStyle::TableRowLowlight);
+		} else {
+			table_row = table_row.style(TableRowStyle::TableRowHighlight);
+		}
+
+		let row = Row::new()
+			.push(Container::new(checkbox).width(Length::Fixed(30.0)).center_x())
+			.push(table_row)
+			.align_items(Alignment::Center);
+
+		output_rows.push(row.into());
+	}
+
+	let output_column = Column::with_children(
+		output_rows
+			.into_iter()
+			.map(|row| row.map(Message::Interaction)),
+	);
+
+	let scrollable = Scrollable::new(output_column)
+		.height(Length::Fill)
+		.style(grin_gui_core::theme::ScrollableStyle::Primary);
+
+	let table_column = Column::new()
+		.push(table_header_container)
+		.push(scrollable);
+
+	let table_container = Container::new(table_column)
+		.style(grin_gui_core::theme::ContainerStyle::PanelBordered)
+		.height(Length::Fill)
+		.padding(1);
+
+	let selection_text = Text::new(format!(
+		"{}: {} ({})",
+		localized_string("outputs-selected"),
+		state.selected.len(),
+		amount_to_hr_string(state.selected_amount(), true)
+	))
+	.size(DEFAULT_FONT_SIZE);
+
+	let button_height = Length::Fixed(BUTTON_HEIGHT);
+	let button_width = Length::Fixed(BUTTON_WIDTH);
+
+	let clear_button_label = Text::new(localized_string("outputs-clear-selection"));
+	let clear_button_label_container = Container::new(clear_button_label.size(DEFAULT_FONT_SIZE))
+		.width(button_width)
+		.height(button_height)
+		.center_x()
+		.center_y()
+		.align_x(alignment::Horizontal::Center);
+
+	let mut clear_button = Button::new(clear_button_label_container)
+		.style(grin_gui_core::theme::ButtonStyle::Primary);
+	if !state.selected.is_empty() {
+		clear_button = clear_button.on_press(Interaction::WalletOperationOutputsViewInteraction(
+			LocalViewInteraction::ClearSelection,
+		));
+	}
+	let clear_button: Element<Interaction> = clear_button.into();
+
+	let back_button_label_container =
+		Container::new(Text::new(localized_string("back")).size(DEFAULT_FONT_SIZE))
+			.width(button_width)
+			.height(button_height)
+			.center_x()
+			.center_y()
+			.align_x(alignment::Horizontal::Center);
+
+	let back_button: Element<Interaction> = Button::new(back_button_label_container)
+		.style(grin_gui_core::theme::ButtonStyle::Primary)
+		.on_press(Interaction::WalletOperationOutputsViewInteraction(
+			LocalViewInteraction::Back,
+		))
+		.into();
+
+	let clear_container = Container::new(clear_button.map(Message::Interaction)).padding(1);
+	let clear_container = Container::new(clear_container)
+		.style(grin_gui_core::theme::ContainerStyle::Segmented)
+		.padding(1);
+
+	let back_container = Container::new(back_button.map(Message::Interaction)).padding(1);
+	let back_container = Container::new(back_container)
+		.style(grin_gui_core::theme::ContainerStyle::Segmented)
+		.padding(1);
+
+	let unit_spacing = 15.0;
+	let button_row = Row::new()
+		.push(selection_text)
+		.push(Space::with_width(Length::Fill))
+		.push(clear_container)
+		.push(Space::new(Length::Fixed(unit_spacing), Length::Fixed(0.0)))
+		.push(back_container)
+		.align_items(Alignment::Center);
+
+	let wrapper_column = Column::new()
+		.height(Length::Fill)
+		.push(header_container)
+		.push(table_container)
+		.push(Space::with_height(Length::Fixed(DEFAULT_PADDING)))
+		.push(button_row);
+
+	// Returns the final container.
+	Container::new(wrapper_column).padding(iced::Padding::from([
+		DEFAULT_PADDING, // top
+		DEFAULT_PADDING, // right
+		DEFAULT_PADDING, // bottom
+		DEFAULT_PADDING, // left
+	]))
+}
@@ -0,0 +1,127 @@
+use grin_gui_core::wallet::{decode_qr, encode_qr, join_qr_parts, qr_parts, QrImage};
+use native_dialog::FileDialog;
+use std::path::{Path, PathBuf};
+
+use {
+	super::super::super::SMALLER_FONT_SIZE,
+	crate::gui::Message,
+	crate::localization::localized_string,
+	anyhow::Context,
+	grin_gui_core::theme::{Column, Element, Text},
+	iced::widget::image::{Handle, Image},
+	iced::{alignment, Alignment, Length},
+};
+
+/// Text rendered as one QR code, or as a sequence of them when it's too long for one
+pub struct QrCodes {
+	images: Vec<QrImage>,
+	handles: Vec<Handle>,
+	current: usize,
+}
+
+impl QrCodes {
+	pub fn new(text: &str) -> anyhow::Result<Self> {
+		let images = qr_parts(text)
+			.iter()
+			.map(|part| encode_qr(part))
+			.collect::<Result<Vec<_>, _>>()
+			.context("Failed to create QR code")?;
+		let handles = images
+			.iter()
+			.map(|i| Handle::from_pixels(i.width, i.height, i.to_rgba()))
+			.collect();
+		Ok(Self {
+			images,
+			handles,
+			current: 0,
+		})
+	}
+
+	/// Show the next part of a multi-part sequence
+	pub fn advance(&mut self) {
+		self.current = (self.current + 1) % self.images.len();
+	}
+
+	/// Save every part as a PNG, numbering the files when there's more than one part
+	pub fn save_png(&self, path: &Path) -> anyhow::Result<()> {
+		let total = self.images.len();
+		for (i, image) in self.images.iter().enumerate() {
+			let path = part_path(path, i, total);
+			image::save_buffer_with_format(
+				&path,
+				&image.pixels,
+				image.width,
+				image.height,
+				image::ColorType::L8,
+				image::ImageFormat::Png,
+			)
+			.with_context(|| format!("Failed to save QR code to {}", path.display()))?;
+		}
+		Ok(())
+	}
+
+	/// Ask where to save the QR codes, returning the chosen path once saved
+	pub fn save_png_dialog(&self) -> anyhow::Result<Option<PathBuf>> {
+		let file_dialog = FileDialog::new().add_filter("PNG", &["png"]);
+		match file_dialog.show_save_single_file() {
+			Ok(Some(mut path)) => {
+				path.set_extension("png");
+				self.save_png(&path)?;
+				Ok(Some(path))
+			}
+			Ok(None) => Ok(None),
+			Err(e) => {
+				log::debug!("qr.rs::QrCodes::save_png_dialog {}", e);
+				Ok(None)
+			}
+		}
+	}
+
+	pub fn view(&self) -> Element<Message> {
+		let mut column = Column::new()
+			.spacing(5)
+			.align_items(Alignment::Center)
+			.push(Image::new(self.handles[self.current].clone()).width(Length::Shrink));
+
+		if self.images.len() > 1 {
+			column = column.push(
+				Text::new(format!(
+					"{} {}/{}",
+					localized_string("qr-part"),
+					self.current + 1,
+					self.images.len()
+				))
+				.size(SMALLER_FONT_SIZE)
+				.horizontal_alignment(alignment::Horizontal::Center),
+			);
+		}
+
+		column.into()
+	}
+}
+
+/// Decode the QR codes in one or more images, e.g. phone screenshots, putting multi-part
+/// sequences back together
+pub fn read_qr_images(paths: &[PathBuf]) -> anyhow::Result<String> {
+	let mut codes = vec![];
+	for path in paths {
+		let image = image::open(path)
+			.with_context(|| format!("Failed to open image {}", path.display()))?
+			.to_luma8();
+		let (width, height) = image.dimensions();
+		codes.extend(decode_qr(width as usize, height as usize, image.as_raw()));
+	}
+	Ok(join_qr_parts(&codes)?)
+}
+
+/// File name for one part of a sequence, e.g. `tx-2of3.png`
+fn part_path(path: &Path, index: usize, total: usize) -> PathBuf {
+	if total == 1 {
+		return path.to_owned();
+	}
+	let stem = path
+		.file_stem()
+		.map(|s| s.to_string_lossy().to_string())
+		.unwrap_or_default();
+	path.with_file_name(format!("{}-{}of{}.png", stem, index + 1, total))
+}
@@ -0,0 +1,174 @@
+use grin_gui_core::node::{amount_from_hr_string, amount_to_hr_string};
+use grin_gui_core::price::PriceQuote;
+use std::collections::HashMap;
+use strfmt::strfmt;
+
+use {
+	super::super::super::SMALLER_FONT_SIZE,
+	crate::localization::{localized_number_format, localized_string},
+	grin_gui_core::config::{Currency, NumberFormat},
+	grin_gui_core::theme::Text,
+};
+
+/// `amount` nanogrin in the quote's currency, as shown next to grin amounts, e.g. `≈ $5.9800`
+pub fn approx_value(quote: &PriceQuote, amount: u64) -> String {
+	format!(
+		"≈ {}",
+		quote.format_value(amount, &localized_number_format())
+	)
+}
+
+/// The quote's rate and when it was quoted, e.g. `1 GRIN = $0.0598 as of 2023-03-03 01:00`
+pub fn rate(quote: &PriceQuote) -> String {
+	let fmt = localized_string("price-rate");
+	let mut vars = HashMap::new();
+	vars.insert(
+		"price".to_string(),
+		quote
+			.currency
+			.format_value(quote.price, &localized_number_format()),
+	);
+	vars.insert(
+		"time".to_string(),
+		quote
+			.time
+			.with_timezone(&chrono::Local)
+			.format("%Y-%m-%d %H:%M")
+			.to_string(),
+	);
+	strfmt(&fmt, &vars).unwrap()
+}
+
+/// Nanogrin entered in an amount field, either in grin or in the quote's currency
+pub fn parse_amount(value: &str, quote: Option<&PriceQuote>, in_currency: bool) -> Option<u64> {
+	if in_currency {
+		quote?.parse(value, &localized_number_format())
+	} else {
+		amount_from_hr_string(value).ok()
+	}
+}
+
+/// What's entered in an amount field, rewritten in the other unit for when the unit is switched
+pub fn switch_unit(value: &str, quote: Option<&PriceQuote>, in_currency: bool) -> Option<String> {
+	let quote = quote?;
+	let amount = parse_amount(value, Some(quote), in_currency)?;
+	Some(if in_currency {
+		amount_to_hr_string(amount, true)
+	} else {
+		// ungrouped, so it reads back as entered
+		let format = NumberFormat {
+			group_separator: None,
+			..localized_number_format()
+		};
+		format.format(quote.value(amount), quote.currency.precision())
+	})
+}
+
+/// Unit an amount field is entered in
+pub fn unit(currency: Currency, in_currency: bool) -> String {
+	if in_currency {
+		currency.code()
+	} else {
+		Currency::GRIN.code()
+	}
+}
+
+/// Line under an amount field with what the amount comes to in the other unit, and at which rate
+pub fn amount_conversion<'a>(
+	value: &str,
+	quote: Option<&PriceQuote>,
+	in_currency: bool,
+) -> Option<Text<'a>> {
+	let quote = match quote {
+		Some(q) => q,
+		None if in_currency => {
+			return Some(
+				Text::new(localized_string("create-tx-amount-no-price"))
+					.size(SMALLER_FONT_SIZE)
+					.style(grin_gui_core::theme::text::TextStyle::Warning),
+			)
+		}
+		None => return None,
+	};
+	let line = match parse_amount(value, Some(quote), in_currency) {
+		Some(amount) if in_currency => format!(
+			"= {} {} ({})",
+			amount_to_hr_string(amount, true),
+			Currency::GRIN.code(),
+			rate(quote)
+		),
+		Some(amount) => format!("{} ({})", approx_value(quote, amount), rate(quote)),
+		None => rate(quote),
+	};
+	Some(Text::new(line).size(SMALLER_FONT_SIZE))
+}
+
+#[cfg(test)]
+mod test {
+	use super::super::create_tx::{self, LocalViewInteraction as CT};
+	use super::super::home;
+	use super::super::test_util::{funded_gui, home_interaction, run_command};
+	use super::*;
+	use chrono::Utc;
+	use grin_gui_core::price::fixture::{FixturePriceProvider, FIXTURE_USD_MARKET_CHART};
+	use std::sync::Arc;
+
+	#[test]
+	fn test_cached_prices_shown_while_provider_fails() {
+		let (_, mut grin_gui) = funded_gui();
+		let provider = Arc::new(FixturePriceProvider::new());
+		grin_gui.price_provider = provider.clone();
+		grin_gui.config.currency = Currency::USD;
+
+		// Prices cached by an earlier run, due to be fetched again
+		let cached = grin_gui_core::price::parse_market_chart(FIXTURE_USD_MARKET_CHART).unwrap();
+		let fetched = Utc::now() - chrono::Duration::days(1);
+		let state = &mut grin_gui.wallet_state.operation_state.home_state;
+		state.price_cache.insert(Currency::USD, &cached, fetched);
+
+		// The provider has nothing, as if offline
+		let messages = run_command(home::handle_price_tick(&mut grin_gui));
+		assert_eq!(messages.len(), 1);
+		for message in messages {
+			home::handle_message(&mut grin_gui, home_interaction(message)).unwrap();
+		}
+		assert_eq!(provider.fetches(), 1);
+		assert!(grin_gui.error.is_none());
+		let state = &grin_gui.wallet_state.operation_state.home_state;
+		assert_eq!(state.price_history, cached);
+
+		// Backing off, so the next tick doesn't fetch
+		assert!(run_command(home::handle_price_tick(&mut grin_gui)).is_empty());
+		assert_eq!(provider.fetches(), 1);
+	}
+
+	#[test]
+	fn test_send_amount_entered_in_currency() {
+		let (_, mut grin_gui) = funded_gui();
+		grin_gui.config.currency = Currency::USD;
+		let state = &mut grin_gui.wallet_state.operation_state.home_state;
+		state.price_history =
+			grin_gui_core::price::parse_market_chart(FIXTURE_USD_MARKET_CHART).unwrap();
+		let quote = state.price_quote(Currency::USD).unwrap();
+		assert_eq!(quote.price, 0.0598);
+
+		// $5.98 at the latest price is 100 grin
+		create_tx::handle_message(&mut grin_gui, CT::ToggleAmountCurrency).unwrap();
+		create_tx::handle_message(&mut grin_gui, CT::Amount("5.98".to_owned())).unwrap();
+		let state = &grin_gui.wallet_state.operation_state.create_tx_state;
+		assert!(state.amount_in_currency);
+		assert_eq!(state.amount(Some(&quote)), Some(100_000_000_000));
+
+		// Switching back to grin keeps the amount
+		create_tx::handle_message(&mut grin_gui, CT::ToggleAmountCurrency).unwrap();
+		let state = &grin_gui.wallet_state.operation_state.create_tx_state;
+		assert!(!state.amount_in_currency);
+		assert_eq!(state.amount(None), Some(100_000_000_000));
+
+		// Without a price, amounts in the currency can't be sent
+		create_tx::handle_message(&mut grin_gui, CT::ToggleAmountCurrency).unwrap();
+		grin_gui.config.currency = Currency::GRIN;
+		let cmd = create_tx::handle_message(&mut grin_gui, CT::CreateTransaction()).unwrap();
+		assert!(run_command(cmd).is_empty());
+	}
+}
@@ -0,0 +1,336 @@
+use crate::log_error;
+use grin_gui_core::{config::Config, wallet::ZeroingString};
+
+use {
+	super::super::super::{
+		BUTTON_HEIGHT, BUTTON_WIDTH, DEFAULT_FONT_SIZE, DEFAULT_HEADER_FONT_SIZE, DEFAULT_PADDING,
+	},
+	crate::gui::{GrinGui, Interaction, Message},
+	crate::localization::localized_string,
+	crate::Result,
+	anyhow::Context,
+	grin_gui_core::theme::{Button, Card, Column, Container, Element, Row, Text, TextInput},
+	iced::widget::Space,
+	iced::{alignment, Alignment, Command, Length},
+	std::sync::{Arc, RwLock},
+};
+
+/// How long the recovery phrase stays on screen once revealed
+const REVEAL_SECONDS: i64 = 60;
+
+/// Recovery phrase on its way from the wallet to this view. Zeroed when dropped and
+/// never written out in debug logs.
+#[derive(Clone)]
+pub struct RecoveryPhrase(ZeroingString);
+
+impl std::fmt::Debug for RecoveryPhrase {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "RecoveryPhrase(..)")
+	}
+}
+
+#[derive(Default)]
+pub struct StateContainer {
+	pub password_value: String,
+	pub phrase: Option<RecoveryPhrase>,
+	/// When the phrase was revealed, to hide it again after `REVEAL_SECONDS`
+	pub revealed_at: Option<chrono::DateTime<chrono::Local>>,
+	pub is_checking: bool,
+}
+
+impl StateContainer {
+	/// Forget the phrase and the password used to retrieve it
+	pub fn clear(&mut self) {
+		self.phrase = None;
+		self.revealed_at = None;
+		self.password_value = String::new();
+		self.is_checking = false;
+	}
+
+	fn seconds_left(&self, time: chrono::DateTime<chrono::Local>) -> i64 {
+		match self.revealed_at {
+			Some(t) => REVEAL_SECONDS - (time - t).num_seconds(),
+			None => 0,
+		}
+	}
+}
+
+#[derive(Debug, Clone)]
+pub enum LocalViewInteraction {
+	Back,
+	Password(String),
+	Reveal,
+	Hide,
+	PhraseRetrievedOk(RecoveryPhrase),
+	PhraseRetrievedError(Arc<RwLock<Option<anyhow::Error>>>),
+}
+
+pub fn handle_tick<'a>(grin_gui: &mut GrinGui, time: chrono::DateTime<chrono::Local>) {
+	let state = &mut grin_gui
+		.wallet_state
+		.operation_state
+		.recovery_phrase_state;
+	if state.phrase.is_some() && state.seconds_left(time) <= 0 {
+		state.clear();
+	}
+}
+
+pub fn handle_message<'a>(
+	grin_gui: &mut GrinGui,
+	message: LocalViewInteraction,
+) -> Result<Command<Message>> {
+	let state = &mut grin_gui
+		.wallet_state
+		.operation_state
+		.recovery_phrase_state;
+
+	match message {
+		LocalViewInteraction::Back => {
+			log::debug!("Interaction::WalletOperationRecoveryPhraseViewInteraction(Back)");
+			state.clear();
+			grin_gui.wallet_state.operation_state.mode =
+				crate::gui::element::wallet::operation::Mode::Home;
+		}
+		LocalViewInteraction::Password(s) => {
+			state.password_value = s;
+		}
+		LocalViewInteraction::Reveal => {
+			if state.is_checking || state.password_value.is_empty() {
+				return Ok(Command::none());
+			}
+			grin_gui.error.take();
+			state.is_checking = true;
+
+			// Don't keep the password around any longer than needed
+			let password = std::mem::take(&mut state.password_value);
+			let w = grin_gui.wallet_backend.clone();
+			let fut = move || w.get_recovery_phrase(password);
+			return Ok(Command::perform(fut(), |r| {
+				match r.context("Failed to retrieve recovery phrase") {
+					Ok(phrase) => Message::Interaction(
+						Interaction::WalletOperationRecoveryPhraseViewInteraction(
+							LocalViewInteraction::PhraseRetrievedOk(RecoveryPhrase(phrase)),
+						),
+					),
+					Err(e) => Message::Interaction(
+						Interaction::WalletOperationRecoveryPhraseViewInteraction(
+							LocalViewInteraction::PhraseRetrievedError(Arc::new(RwLock::new(
+								Some(e),
+							))),
+						),
+					),
+				}
+			}));
+		}
+		LocalViewInteraction::Hide => {
+			state.clear();
+		}
+		LocalViewInteraction::PhraseRetrievedOk(phrase) => {
+			// The user may have left the screen while the password was being checked
+			if !state.is_checking {
+				return Ok(Command::none());
+			}
+			state.is_checking = false;
+			state.phrase = Some(phrase);
+			state.revealed_at = Some(chrono::Local::now());
+		}
+		LocalViewInteraction::PhraseRetrievedError(err) => {
+			state.is_checking = false;
+			grin_gui.error = err.write().unwrap().take();
+			if let Some(e) = grin_gui.error.as_ref() {
+				log_error(e);
+			}
+		}
+	}
+
+	Ok(Command::none())
+}
+
+pub fn data_container<'a>(config: &'a Config, state: &'a StateContainer) -> Container<'a, Message> {
+	// Title row
+	let title = Text::new(localized_string("recovery-phrase-title"))
+		.size(DEFAULT_HEADER_FONT_SIZE)
+		.horizontal_alignment(alignment::Horizontal::Center);
+
+	let title_container = Container::new(title)
+		.style(grin_gui_core::theme::ContainerStyle::BrightBackground)
+		.padding(iced::Padding::from([
+			2, // top
+			0, // right
+			2, // bottom
+			5, // left
+		]));
+
+	let header_row = Row::new().push(title_container);
+	let header_container = Container::new(header_row).padding(iced::Padding::from([
+		0,                      // top
+		0,                      // right
+		DEFAULT_PADDING as u16, // bottom
+		0,                      // left
+	]));
+
+	let description =
+		Text::new(localized_string("recovery-phrase-description")).size(DEFAULT_FONT_SIZE);
+
+	let button_height = Length::Fixed(BUTTON_HEIGHT);
+	let button_width = Length::Fixed(BUTTON_WIDTH);
+
+	let content: Element<Message> = match state.phrase.as_ref() {
+		Some(phrase) => {
+			let seconds_left = state.seconds_left(chrono::Local::now()).max(0);
+			Card::new(
+				Text::new(localized_string("setup-grin-wallet-recovery-phrase-title"))
+					.size(DEFAULT_HEADER_FONT_SIZE),
+				Text::new(&*phrase.0).size(DEFAULT_FONT_SIZE),
+			)
+			.foot(
+				Text::new(format!(
+					"{} {}s",
+					localized_string("recovery-phrase-hidden-in"),
+					seconds_left
+				))
+				.size(DEFAULT_FONT_SIZE),
+			)
+			.max_width(400.0)
+			.style(grin_gui_core::theme::CardStyle::Normal)
+			.into()
+		}
+		None => {
+			let password_input = TextInput::new(
+				&localized_string("password")[..],
+				&state.password_value,
+			)
+			.on_input(|s| {
+				Interaction::WalletOperationRecoveryPhraseViewInteraction(
+					LocalViewInteraction::Password(s),
+				)
+			})
+			.on_submit(Interaction::WalletOperationRecoveryPhraseViewInteraction(
+				LocalViewInteraction::Reveal,
+			))
+			.size(DEFAULT_FONT_SIZE)
+			.padding(6)
+			.width(Length::Fixed(200.0))
+			.style(grin_gui_core::theme::TextInputStyle::AddonsQuery)
+			.secure(true);
+
+			let password_input: Element<Interaction> = password_input.into();
+			password_input.map(Message::Interaction)
+		}
+	};
+
+	// Reveal or hide depending on whether the phrase is showing
+	let (button_label, button_interaction) = if state.phrase.is_some() {
+		("recovery-phrase-hide", Some(LocalViewInteraction::Hide))
+	} else if state.is_checking || state.password_value.is_empty() {
+		("recovery-phrase-reveal", None)
+	} else {
+		("recovery-phrase-reveal", Some(LocalViewInteraction::Reveal))
+	};
+
+	let reveal_button_label_container =
+		Container::new(Text::new(localized_string(button_label)).size(DEFAULT_FONT_SIZE))
+			.width(button_width)
+			.height(button_height)
+			.center_x()
+			.center_y()
+			.align_x(alignment::Horizontal::Center);
+
+	let mut reveal_button = Button::new(reveal_button_label_container)
+		.style(grin_gui_core::theme::ButtonStyle::Primary);
+	if let Some(i) = button_interaction {
+		reveal_button =
+			reveal_button.on_press(Interaction::WalletOperationRecoveryPhraseViewInteraction(i));
+	}
+	let reveal_button: Element<Interaction> = reveal_button.into();
+
+	let back_button_label_container =
+		Container::new(Text::new(localized_string("back")).size(DEFAULT_FONT_SIZE))
+			.width(button_width)
+			.height(button_height)
+			.center_x()
+			.center_y()
+			.align_x(alignment::Horizontal::Center);
+
+	let back_button: Element<Interaction> = Button::new(back_button_label_container)
+		.style(grin_gui_core::theme::ButtonStyle::Primary)
+		.on_press(Interaction::WalletOperationRecoveryPhraseViewInteraction(
+			LocalViewInteraction::Back,
+		))
+		.into();
+
+	let reveal_container = Container::new(reveal_button.map(Message::Interaction)).padding(1);
+	let reveal_container = Container::new(reveal_container)
+		.style(grin_gui_core::theme::ContainerStyle::Segmented)
+		.padding(1);
+
+	let back_container = Container::new(back_button.map(Message::Interaction)).padding(1);
+	let back_container = Container::new(back_container)
+		.style(grin_gui_core::theme::ContainerStyle::Segmented)
+		.padding(1);
+
+	let button_row = Row::new()
+		.push(reveal_container)
+		.push(Space::with_width(Length::Fill))
+		.push(back_container)
+		.align_items(Alignment::Center);
+
+	let wrapper_column = Column::new()
+		.height(Length::Fill)
+		.push(header_container)
+		.push(description)
+		.push(Space::with_height(Length::Fixed(DEFAULT_PADDING)))
+		.push(content)
+		.push(Space::with_height(Length::Fill))
+		.push(button_row);
+
+	// Returns the final container.
+	Container::new(wrapper_column).padding(iced::Padding::from([
+		DEFAULT_PADDING, // top
+		DEFAULT_PADDING, // right
+		DEFAULT_PADDING, // bottom
+		DEFAULT_PADDING, // left
+	]))
+}
+
+#[cfg(test)]
+mod test {
+	use super::super::test_util::{gui_with_fake, run_command};
+	use super::*;
+	use grin_gui_core::wallet::fake::{FakeWalletBackend, FAKE_RECOVERY_PHRASE};
+
+	#[test]
+	fn test_reveal_recovery_phrase() {
+		let fake = Arc::new(FakeWalletBackend::open_with_balance("password", 0));
+		let mut grin_gui = gui_with_fake(fake);
+
+		handle_message(
+			&mut grin_gui,
+			LocalViewInteraction::Password("password".to_owned()),
+		)
+		.unwrap();
+		let cmd = handle_message(&mut grin_gui, LocalViewInteraction::Reveal).unwrap();
+		let message = match run_command(cmd).remove(0) {
+			Message::Interaction(Interaction::WalletOperationRecoveryPhraseViewInteraction(l)) => l,
+			m => panic!("unexpected message {:?}", m),
+		};
+		handle_message(&mut grin_gui, message).unwrap();
+
+		let state = &grin_gui.wallet_state.operation_state.recovery_phrase_state;
+		assert!(state.password_value.is_empty());
+		assert!(format!("{:?}", state.phrase)
+			.find(FAKE_RECOVERY_PHRASE)
+			.is_none());
+		assert!(state.phrase.is_some());
+
+		// Hidden again once the timer runs out
+		let later = chrono::Local::now() + chrono::Duration::seconds(61);
+		handle_tick(&mut grin_gui, later);
+		assert!(grin_gui
+			.wallet_state
+			.operation_state
+			.recovery_phrase_state
+			.phrase
+			.is_none());
+	}
+}
@@ -0,0 +1,421 @@
+use crate::log_error;
+use grin_gui_core::{
+	config::Config,
+	wallet::{ScanSummary, WalletInfo},
+};
+
+use {
+	super::super::super::{
+		BUTTON_HEIGHT, BUTTON_WIDTH, DEFAULT_FONT_SIZE, DEFAULT_HEADER_FONT_SIZE, DEFAULT_PADDING,
+	},
+	crate::gui::{GrinGui, Interaction, Message},
+	crate::localization::localized_string,
+	crate::Result,
+	anyhow::Context,
+	grin_gui_core::node::amount_to_hr_string,
+	grin_gui_core::theme::{
+		Button, Column, Container, Element, ProgressBar, ProgressBarStyle, Row, Text, TextInput,
+	},
+	iced::widget::{Checkbox, Space},
+	iced::{alignment, Alignment, Command, Length},
+	std::sync::{Arc, RwLock},
+};
+
+#[derive(Default)]
+pub struct StateContainer {
+	pub start_height_value: String,
+	pub delete_unconfirmed: bool,
+	pub is_running: bool,
+	/// Percent complete, as reported by the wallet's updater status messages
+	pub progress: f32,
+	/// Set when the user hid a scan. The wallet can't interrupt one, so it carries on in the
+	/// background and another can't start until it finishes.
+	pub hidden: bool,
+	pub summary: Option<ScanSummary>,
+}
+
+#[derive(Debug, Clone)]
+pub enum LocalViewInteraction {
+	Back,
+	StartHeight(String),
+	DeleteUnconfirmed(bool),
+	StartScan,
+	/// libwallet's scan takes no stop state and the updater's only stops between
+	/// updates, so a running scan can be hidden but not cancelled
+	Hide,
+	ScanComplete(ScanSummary),
+	ScanError(Arc<RwLock<Option<anyhow::Error>>>),
+}
+
+pub fn handle_message<'a>(
+	grin_gui: &mut GrinGui,
+	message: LocalViewInteraction,
+) -> Result<Command<Message>> {
+	let state = &mut grin_gui.wallet_state.operation_state.scan_state;
+
+	match message {
+		LocalViewInteraction::Back => {
+			log::debug!("Interaction::WalletOperationScanViewInteraction(Back)");
+			grin_gui.wallet_state.operation_state.mode =
+				crate::gui::element::wallet::operation::Mode::Home;
+		}
+		LocalViewInteraction::StartHeight(s) => {
+			if s.chars().all(|c| c.is_ascii_digit()) {
+				state.start_height_value = s;
+			}
+		}
+		LocalViewInteraction::DeleteUnconfirmed(b) => {
+			state.delete_unconfirmed = b;
+		}
+		LocalViewInteraction::StartScan => {
+			// Includes a hidden scan, which still holds the wallet
+			if state.is_running {
+				return Ok(Command::none());
+			}
+			// Blank scans from the wallet's first block
+			let start_height = state.start_height_value.parse::<u64>().ok();
+			let delete_unconfirmed = state.delete_unconfirmed;
+
+			grin_gui.error.take();
+			state.is_running = true;
+			state.hidden = false;
+			state.progress = 0.0;
+			state.summary = None;
+
+			let w = grin_gui.wallet_backend.clone();
+			let fut = move || w.scan(start_height, delete_unconfirmed);
+			return Ok(Command::perform(fut(), |r| {
+				match r.context("Failed to scan wallet") {
+					Ok(summary) => {
+						Message::Interaction(Interaction::WalletOperationScanViewInteraction(
+							LocalViewInteraction::ScanComplete(summary),
+						))
+					}
+					Err(e) => {
+						Message::Interaction(Interaction::WalletOperationScanViewInteraction(
+							LocalViewInteraction::ScanError(Arc::new(RwLock::new(Some(e)))),
+						))
+					}
+				}
+			}));
+		}
+		LocalViewInteraction::Hide => {
+			if state.is_running {
+				state.hidden = true;
+			}
+		}
+		LocalViewInteraction::ScanComplete(summary) => {
+			state.is_running = false;
+			if !state.hidden {
+				state.progress = 100.0;
+				state.summary = Some(summary);
+			}
+
+			// Even an untracked scan may have changed balances and transactions,
+			// so refresh them on the next tick
+			let home_state = &mut grin_gui.wallet_state.operation_state.home_state;
+			home_state.wallet_info = None;
+			home_state.last_summary_update = Default::default();
+			home_state.tx_list_display_state.mode = super::tx_list_display::Mode::NotInit;
+		}
+		LocalViewInteraction::ScanError(err) => {
+			state.is_running = false;
+			grin_gui.error = err.write().unwrap().take();
+			if let Some(e) = grin_gui.error.as_ref() {
+				log_error(e);
+			}
+		}
+	}
+
+	Ok(Command::none())
+}
+
+fn summary_row<'a>(label: &str, before: String, after: String) -> Row<'a, Message> {
+	let changed = before != after;
+	let after = if changed {
+		format!("{} *", after)
+	} else {
+		after
+	};
+	Row::new()
+		.push(
+			Container::new(Text::new(localized_string(label)).size(DEFAULT_FONT_SIZE))
+				.width(Length::FillPortion(2)),
+		)
+		.push(
+			Container::new(Text::new(before).size(DEFAULT_FONT_SIZE))
+				.width(Length::FillPortion(2)),
+		)
+		.push(
+			Container::new(Text::new(after).size(DEFAULT_FONT_SIZE))
+				.width(Length::FillPortion(2)),
+		)
+}
+
+fn amount_rows<'a>(before: &WalletInfo, after: &WalletInfo) -> Vec<Row<'a, Message>> {
+	vec![
+		summary_row(
+			"info-confirmed-total",
+			amount_to_hr_string(before.total, false),
+			amount_to_hr_string(after.total, false),
+		),
+		summary_row(
+			"info-amount-spendable",
+			amount_to_hr_string(before.amount_currently_spendable, false),
+			amount_to_hr_string(after.amount_currently_spendable, false),
+		),
+		summary_row(
+			"info-awaiting-confirmation",
+			amount_to_hr_string(before.amount_awaiting_confirmation, false),
+			amount_to_hr_string(after.amount_awaiting_confirmation, false),
+		),
+		summary_row(
+			"info-locked",
+			amount_to_hr_string(before.amount_locked, false),
+			amount_to_hr_string(after.amount_locked, false),
+		),
+	]
+}
+
+pub fn data_container<'a>(config: &'a Config, state: &'a StateContainer) -> Container<'a, Message> {
+	// Title row
+	let title = Text::new(localized_string("scan-title"))
+		.size(DEFAULT_HEADER_FONT_SIZE)
+		.horizontal_alignment(alignment::Horizontal::Center);
+
+	let title_container = Container::new(title)
+		.style(grin_gui_core::theme::ContainerStyle::BrightBackground)
+		.padding(iced::Padding::from([
+			2, // top
+			0, // right
+			2, // bottom
+			5, // left
+		]));
+
+	let header_row = Row::new().push(title_container);
+	let header_container = Container::new(header_row).padding(iced::Padding::from([
+		0,                      // top
+		0,                      // right
+		DEFAULT_PADDING as u16, // bottom
+		0,                      // left
+	]));
+
+	let description = Text::new(localized_string("scan-description")).size(DEFAULT_FONT_SIZE);
+
+	let start_height_label =
+		Text::new(localized_string("scan-start-height")).size(DEFAULT_FONT_SIZE);
+
+	let mut start_height_input = TextInput::new(
+		&localized_string("scan-start-height-placeholder"),
+		&state.start_height_value,
+	)
+	.size(DEFAULT_FONT_SIZE)
+	.padding(6)
+	.width(Length::Fixed(200.0))
+	.style(grin_gui_core::theme::TextInputStyle::AddonsQuery);
+
+	let mut delete_unconfirmed_checkbox = Checkbox::new(
+		localized_string("scan-delete-unconfirmed"),
+		state.delete_unconfirmed,
+	)
+	.style(grin_gui_core::theme::CheckboxStyle::Normal)
+	.text_size(DEFAULT_FONT_SIZE)
+	.spacing(10);
+
+	// Options are fixed while a scan is running
+	if !state.is_running {
+		start_height_input = start_height_input.on_input(|s| {
+			Interaction::WalletOperationScanViewInteraction(LocalViewInteraction::StartHeight(s))
+		});
+		delete_unconfirmed_checkbox = delete_unconfirmed_checkbox.on_toggle(|b| {
+			Interaction::WalletOperationScanViewInteraction(
+				LocalViewInteraction::DeleteUnconfirmed(b),
+			)
+		});
+	}
+
+	let start_height_input: Element<Interaction> = start_height_input.into();
+	let delete_unconfirmed_checkbox: Element<Interaction> = delete_unconfirmed_checkbox.into();
+
+	let options_column = Column::new()
+		.push(start_height_label)
+		.push(Space::with_height(Length::Fixed(5.0)))
+		.push(start_height_input.map(Message::Interaction))
+		.push(Space::with_height(Length::Fixed(DEFAULT_PADDING)))
+		.push(delete_unconfirmed_checkbox.map(Message::Interaction));
+
+	let progress_bar = ProgressBar::new(0.0..=100.0, state.progress)
+		.height(Length::Fixed(10.0))
+		.style(ProgressBarStyle::Normal);
+
+	let status = if state.is_running && state.hidden {
+		localized_string("scan-hidden")
+	} else if state.is_running {
+		format!("{} - {}%", localized_string("scan-running"), state.progress as u8)
+	} else if state.hidden {
+		localized_string("scan-hidden-complete")
+	} else if state.summary.is_some() {
+		localized_string("scan-complete")
+	} else {
+		String::new()
+	};
+	let status_text = Text::new(status).size(DEFAULT_FONT_SIZE);
+
+	let mut summary_column = Column::new().spacing(5);
+	if let Some(summary) = state.summary.as_ref() {
+		let heading = Row::new()
+			.push(Space::with_width(Length::FillPortion(2)))
+			.push(
+				Container::new(Text::new(localized_string("scan-before")).size(DEFAULT_FONT_SIZE))
+					.style(grin_gui_core::theme::ContainerStyle::BrightForeground)
+					.width(Length::FillPortion(2)),
+			)
+			.push(
+				Container::new(Text::new(localized_string("scan-after")).size(DEFAULT_FONT_SIZE))
+					.style(grin_gui_core::theme::ContainerStyle::BrightForeground)
+					.width(Length::FillPortion(2)),
+			);
+		summary_column = summary_column.push(heading);
+		for row in amount_rows(&summary.info_before, &summary.info_after) {
+			summary_column = summary_column.push(row);
+		}
+		summary_column = summary_column.push(summary_row(
+			"scan-outputs",
+			format!("{}", summary.outputs_before),
+			format!("{}", summary.outputs_after),
+		));
+	}
+
+	let button_height = Length::Fixed(BUTTON_HEIGHT);
+	let button_width = Length::Fixed(BUTTON_WIDTH);
+
+	let scan_button_label_container =
+		Container::new(Text::new(localized_string("scan-start")).size(DEFAULT_FONT_SIZE))
+			.width(button_width)
+			.height(button_height)
+			.center_x()
+			.center_y()
+			.align_x(alignment::Horizontal::Center);
+
+	let mut scan_button = Button::new(scan_button_label_container)
+		.style(grin_gui_core::theme::ButtonStyle::Primary);
+	if !state.is_running {
+		scan_button = scan_button.on_press(Interaction::WalletOperationScanViewInteraction(
+			LocalViewInteraction::StartScan,
+		));
+	}
+	let scan_button: Element<Interaction> = scan_button.into();
+
+	let hide_button_label_container =
+		Container::new(Text::new(localized_string("scan-hide")).size(DEFAULT_FONT_SIZE))
+			.width(button_width)
+			.height(button_height)
+			.center_x()
+			.center_y()
+			.align_x(alignment::Horizontal::Center);
+
+	let mut hide_button = Button::new(hide_button_label_container)
+		.style(grin_gui_core::theme::ButtonStyle::Primary);
+	if state.is_running && !state.hidden {
+		hide_button = hide_button.on_press(Interaction::WalletOperationScanViewInteraction(
+			LocalViewInteraction::Hide,
+		));
+	}
+	let hide_button: Element<Interaction> = hide_button.into();
+
+	let back_button_label_container =
+		Container::new(Text::new(localized_string("back")).size(DEFAULT_FONT_SIZE))
+			.width(button_width)
+			.height(button_height)
+			.center_x()
+			.center_y()
+			.align_x(alignment::Horizontal::Center);
+
+	let back_button: Element<Interaction> = Button::new(back_button_label_container)
+		.style(grin_gui_core::theme::ButtonStyle::Primary)
+		.on_press(Interaction::WalletOperationScanViewInteraction(
+			LocalViewInteraction::Back,
+		))
+		.into();
+
+	let scan_container = Container::new(scan_button.map(Message::Interaction)).padding(1);
+	let scan_container = Container::new(scan_container)
+		.style(grin_gui_core::theme::ContainerStyle::Segmented)
+		.padding(1);
+
+	let hide_container = Container::new(hide_button.map(Message::Interaction)).padding(1);
+	let hide_container = Container::new(hide_container)
+		.style(grin_gui_core::theme::ContainerStyle::Segmented)
+		.padding(1);
+
+	let back_container = Container::new(back_button.map(Message::Interaction)).padding(1);
+	let back_container = Container::new(back_container)
+		.style(grin_gui_core::theme::ContainerStyle::Segmented)
+		.padding(1);
+
+	let unit_spacing = 15.0;
+	let button_row = Row::new()
+		.push(scan_container)
+		.push(Space::new(Length::Fixed(unit_spacing), Length::Fixed(0.0)))
+		.push(hide_container)
+		.push(Space::with_width(Length::Fill))
+		.push(back_container)
+		.align_items(Alignment::Center);
+
+	let wrapper_column = Column::new()
+		.height(Length::Fill)
+		.push(header_container)
+		.push(description)
+		.push(Space::with_height(Length::Fixed(DEFAULT_PADDING)))
+		.push(options_column)
+		.push(Space::with_height(Length::Fixed(DEFAULT_PADDING)))
+		.push(progress_bar)
+		.push(Space::with_height(Length::Fixed(5.0)))
+		.push(status_text)
+		.push(Space::with_height(Length::Fixed(DEFAULT_PADDING)))
+		.push(summary_column)
+		.push(Space::with_height(Length::Fill))
+		.push(button_row);
+
+	// Returns the final container.
+	Container::new(wrapper_column).padding(iced::Padding::from([
+		DEFAULT_PADDING, // top
+		DEFAULT_PADDING, // right
+		DEFAULT_PADDING, // bottom
+		DEFAULT_PADDING, // left
+	]))
+}
+
+#[cfg(test)]
+mod test {
+	use super::super::test_util::{funded_gui, run_command};
+	use super::*;
+
+	#[test]
+	fn test_hidden_scan_blocks_new_scan() {
+		let (fake, mut grin_gui) = funded_gui();
+
+		let cmd = handle_message(&mut grin_gui, LocalViewInteraction::StartScan).unwrap();
+		assert!(grin_gui.wallet_state.operation_state.scan_state.is_running);
+		handle_message(&mut grin_gui, LocalViewInteraction::Hide).unwrap();
+
+		// The hidden scan still holds the wallet, so another can't start
+		let again = handle_message(&mut grin_gui, LocalViewInteraction::StartScan).unwrap();
+		assert!(run_command(again).is_empty());
+
+		// It still completes, but isn't reported
+		let message = match run_command(cmd).remove(0) {
+			Message::Interaction(Interaction::WalletOperationScanViewInteraction(l)) => l,
+			m => panic!("unexpected message {:?}", m),
+		};
+		assert!(matches!(message, LocalViewInteraction::ScanComplete(..)));
+		handle_message(&mut grin_gui, message).unwrap();
+
+		let state = &grin_gui.wallet_state.operation_state.scan_state;
+		assert!(state.hidden);
+		assert!(!state.is_running);
+		assert!(state.summary.is_none());
+		let scans = fake.state().calls.iter().filter(|c| *c == "scan").count();
+		assert_eq!(scans, 1);
+	}
+}
@@ -1,3 +1,8 @@
+use super::qr::QrCodes;
+use crate::log_error;
+use grin_gui_core::wallet::{write_slatepack_file, SlatepackFileFormat};
+use native_dialog::FileDialog;
+
 use {
 	super::super::super::{
 		BUTTON_HEIGHT, BUTTON_WIDTH, DEFAULT_FONT_SIZE, DEFAULT_HEADER_FONT_SIZE, DEFAULT_PADDING,
@@ -6,6 +11,7 @@ use {
 	crate::gui::{GrinGui, Interaction, Message},
 	crate::localization::localized_string,
 	crate::Result,
+	anyhow::Context,
 	grin_gui_core::config::Config,
 	grin_gui_core::theme::ColorPalette,
 	grin_gui_core::theme::{
@@ -25,6 +31,10 @@ pub struct StateContainer {
 	pub title_label: String,
 	// description
 	pub desc: String,
+	// Where the slatepack was last saved to
+	pub saved_path: Option<String>,
+	// Slatepack as QR codes, while shown
+	pub qr_codes: Option<QrCodes>,
 }
 
 impl Default for StateContainer {
@@ -34,6 +44,8 @@ impl Default for StateContainer {
 			submit_mode: None,
 			title_label: localized_string("tx-view"),
 			desc: localized_string("tx-view-desc"),
+			saved_path: None,
+			qr_codes: None,
 		}
 	}
 }
@@ -42,12 +54,35 @@ impl StateContainer {
 	pub fn reset_defaults(&mut self) {
 		self.title_label = localized_string("tx-view");
 		self.desc = localized_string("tx-view-desc");
+		self.saved_path = None;
+		self.qr_codes = None;
 	}
 }
 
 #[derive(Debug, Clone)]
 pub enum LocalViewInteraction {
 	Submit,
+	SaveAs,
+	ToggleQr,
+	SaveQr,
+}
+
+/// Cycles through the parts of a multi-part QR code
+pub fn handle_tick(grin_gui: &mut GrinGui, _time: chrono::DateTime<chrono::Local>) {
+	if grin_gui.wallet_state.operation_state.mode
+		!= crate::gui::element::wallet::operation::Mode::ShowSlatepack
+	{
+		return;
+	}
+	if let Some(qr_codes) = grin_gui
+		.wallet_state
+		.operation_state
+		.show_slatepack_state
+		.qr_codes
+		.as_mut()
+	{
+		qr_codes.advance();
+	}
 }
 
 pub fn handle_message(
@@ -67,6 +102,67 @@ pub fn handle_message(
 			}
 			state.submit_mode = None;
 		}
+		LocalViewInteraction::SaveAs => {
+			let slatepack = match state.encrypted_slate.as_ref() {
+				Some(s) => s.clone(),
+				None => return Ok(Command::none()),
+			};
+			let file_dialog = FileDialog::new()
+				.add_filter("Slatepack", &["slatepack"])
+				.add_filter("Binary", &["bin"])
+				.add_filter("JSON", &["json"]);
+			match file_dialog.show_save_single_file() {
+				Ok(Some(mut path)) => {
+					if path.extension().is_none() {
+						path.set_extension(SlatepackFileFormat::Armored.file_ext());
+					}
+					let format = SlatepackFileFormat::from_path(&path);
+					match write_slatepack_file(&path, &slatepack, format)
+						.with_context(|| format!("Failed to save slatepack to {}", path.display()))
+					{
+						Ok(_) => {
+							state.saved_path = Some(path.display().to_string());
+						}
+						Err(e) => {
+							state.saved_path = None;
+							log_error(&e);
+							grin_gui.error = Some(e);
+						}
+					}
+				}
+				Ok(None) => {}
+				Err(e) => {
+					log::debug!("show_slatepack.rs::LocalViewInteraction::SaveAs {}", e);
+				}
+			}
+		}
+		LocalViewInteraction::ToggleQr => {
+			if state.qr_codes.take().is_some() {
+				return Ok(Command::none());
+			}
+			if let Some(slatepack) = state.encrypted_slate.as_ref() {
+				match QrCodes::new(slatepack) {
+					Ok(qr_codes) => state.qr_codes = Some(qr_codes),
+					Err(e) => {
+						log_error(&e);
+						grin_gui.error = Some(e);
+					}
+				}
+			}
+		}
+		LocalViewInteraction::SaveQr => {
+			if let Some(qr_codes) = state.qr_codes.as_ref() {
+				match qr_codes.save_png_dialog() {
+					Ok(Some(path)) => state.saved_path = Some(path.display().to_string()),
+					Ok(None) => {}
+					Err(e) => {
+						state.saved_path = None;
+						log_error(&e);
+						grin_gui.error = Some(e);
+					}
+				}
+			}
+		}
 	}
 	Ok(Command::none())
 }
@@ -110,6 +206,12 @@ pub fn data_container<'a>(
 		None => "".to_owned(),
 	};
 
+	let qr_label = if state.qr_codes.is_some() {
+		"qr-hide"
+	} else {
+		"qr-show"
+	};
+
 	let encrypted_slate_card = Card::new(
 		Text::new(localized_string("tx-paste-success-title")).size(DEFAULT_HEADER_FONT_SIZE),
 		Text::new(card_contents.clone()).size(DEFAULT_FONT_SIZE),
@@ -121,15 +223,45 @@ pub fn data_container<'a>(
 			.width(Length::Fill)
 			.align_items(Alignment::Center)
 			.push(
-				Button::new(
-					Text::new(localized_string("copy-to-clipboard"))
-						.size(SMALLER_FONT_SIZE)
-						.horizontal_alignment(alignment::Horizontal::Center),
-				)
-				.style(grin_gui_core::theme::ButtonStyle::NormalText)
-				.on_press(Message::Interaction(Interaction::WriteToClipboard(
-					card_contents.clone(),
-				))),
+				Row::new()
+					.spacing(10)
+					.push(
+						Button::new(
+							Text::new(localized_string("copy-to-clipboard"))
+								.size(SMALLER_FONT_SIZE)
+								.horizontal_alignment(alignment::Horizontal::Center),
+						)
+						.style(grin_gui_core::theme::ButtonStyle::NormalText)
+						.on_press(Message::Interaction(Interaction::WriteToClipboard(
+							card_contents.clone(),
+						))),
+					)
+					.push(
+						Button::new(
+							Text::new(localized_string("tx-save-as"))
+								.size(SMALLER_FONT_SIZE)
+								.horizontal_alignment(alignment::Horizontal::Center),
+						)
+						.style(grin_gui_core::theme::ButtonStyle::NormalText)
+						.on_press(Message::Interaction(
+							Interaction::WalletOperationShowSlatepackViewInteraction(
+								LocalViewInteraction::SaveAs,
+							),
+						)),
+					)
+					.push(
+						Button::new(
+							Text::new(localized_string(qr_label))
+								.size(SMALLER_FONT_SIZE)
+								.horizontal_alignment(alignment::Horizontal::Center),
+						)
+						.style(grin_gui_core::theme::ButtonStyle::NormalText)
+						.on_press(Message::Interaction(
+							Interaction::WalletOperationShowSlatepackViewInteraction(
+								LocalViewInteraction::ToggleQr,
+							),
+						)),
+					),
 			),
 	)
 	.max_width(400.0)
@@ -163,13 +295,48 @@ pub fn data_container<'a>(
 	let unit_spacing = 15.0;
 	let button_row = Row::new().push(cancel_container);
 
-	let column = Column::new()
+	let mut column = Column::new()
 		.push(description_container)
 		.push(Space::new(
 			Length::Fixed(0.0),
 			Length::Fixed(unit_spacing + 5.0),
 		))
-		.push(encrypted_slate_card)
+		.push(encrypted_slate_card);
+
+	if let Some(qr_codes) = state.qr_codes.as_ref() {
+		let save_qr_button = Button::new(
+			Text::new(localized_string("qr-save-png"))
+				.size(SMALLER_FONT_SIZE)
+				.horizontal_alignment(alignment::Horizontal::Center),
+		)
+		.style(grin_gui_core::theme::ButtonStyle::Bordered)
+		.on_press(Message::Interaction(
+			Interaction::WalletOperationShowSlatepackViewInteraction(LocalViewInteraction::SaveQr),
+		));
+
+		column = column
+			.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)))
+			.push(
+				Column::new()
+					.spacing(10)
+					.max_width(400.0)
+					.width(Length::Fill)
+					.align_items(Alignment::Center)
+					.push(qr_codes.view())
+					.push(save_qr_button),
+			);
+	}
+
+	if let Some(path) = state.saved_path.as_ref() {
+		column = column
+			.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)))
+			.push(
+				Text::new(format!("{}: {}", localized_string("tx-saved-to"), path))
+					.size(SMALLER_FONT_SIZE),
+			);
+	}
+
+	column = column
 		.push(Space::new(
 			Length::Fixed(0.0),
 			Length::Fixed(unit_spacing + 10.0),
@@ -0,0 +1,72 @@
+//! Helpers shared by the wallet operation view tests
+use {
+	super::home::LocalViewInteraction,
+	crate::gui::{GrinGui, Interaction, Message},
+	crate::localization::LANG,
+	futures::executor::block_on,
+	grin_gui_core::config::Config,
+	grin_gui_core::price::fixture::FixturePriceProvider,
+	grin_gui_core::wallet::fake::FakeWalletBackend,
+	grin_gui_core::wallet::{InitTxArgs, Slate, WalletBackend},
+	iced::Command,
+	std::sync::{Arc, RwLock},
+};
+
+pub fn gui_with_fake(fake: Arc<FakeWalletBackend>) -> GrinGui {
+	let _ = LANG.set(RwLock::new("en_US"));
+	let mut grin_gui = GrinGui::from_config(&Config::default());
+	grin_gui.wallet_backend = fake;
+	grin_gui.price_provider = Arc::new(FixturePriceProvider::new());
+	grin_gui
+}
+
+/// Balance of the wallet behind `funded_gui`
+pub const FUNDED_BALANCE: u64 = 5_000_000_000;
+
+/// Amount of the send `gui_with_pending_send` starts
+pub const PENDING_AMOUNT: u64 = 2_000_000_000;
+
+/// GUI over an open fake wallet holding `FUNDED_BALANCE`
+pub fn funded_gui() -> (Arc<FakeWalletBackend>, GrinGui) {
+	let fake = Arc::new(FakeWalletBackend::open_with_balance(
+		"password",
+		FUNDED_BALANCE,
+	));
+	let grin_gui = gui_with_fake(fake.clone());
+	(fake, grin_gui)
+}
+
+/// Start a send of `amount` from the fake wallet, returning its slate and S1 slatepack
+pub fn start_send(fake: &FakeWalletBackend, amount: u64) -> (Slate, String) {
+	let args = InitTxArgs {
+		amount,
+		..Default::default()
+	};
+	block_on(fake.create_tx(args, String::new())).unwrap()
+}
+
+/// `funded_gui` with one pending send of `PENDING_AMOUNT`, returning its slate and S1 slatepack
+pub fn gui_with_pending_send() -> (Arc<FakeWalletBackend>, GrinGui, Slate, String) {
+	let (fake, grin_gui) = funded_gui();
+	let (slate, s1) = start_send(&fake, PENDING_AMOUNT);
+	(fake, grin_gui, slate, s1)
+}
+
+/// Run the futures in a command to completion, collecting the messages they produce
+pub fn run_command(command: Command<Message>) -> Vec<Message> {
+	command
+		.actions()
+		.into_iter()
+		.filter_map(|a| match a {
+			iced_runtime::command::Action::Future(f) => Some(block_on(f)),
+			_ => None,
+		})
+		.collect()
+}
+
+pub fn home_interaction(message: Message) -> LocalViewInteraction {
+	match message {
+		Message::Interaction(Interaction::WalletOperationHomeViewInteraction(l)) => l,
+		m => panic!("unexpected message {:?}", m),
+	}
+}
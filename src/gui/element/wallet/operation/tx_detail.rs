@@ -2,8 +2,9 @@ use super::tx_list::{self, ExpandType};
 use crate::log_error;
 use async_std::prelude::FutureExt;
 use grin_gui_core::{
-	config::Config,
+	config::{Config, TxNote},
 	error::GrinWalletInterfaceError,
+	fs::PersistentData,
 	wallet::{TxLogEntry, TxLogEntryType},
 };
 use iced_aw::Card;
@@ -40,12 +41,17 @@ use {
 pub struct StateContainer {
 	// Transaction that we're viewing
 	pub current_tx: Option<TxLogEntry>,
+	// Local label and note being edited
+	pub label_value: String,
+	pub note_value: String,
 }
 
 impl Default for StateContainer {
 	fn default() -> Self {
 		Self {
 			current_tx: Default::default(),
+			label_value: Default::default(),
+			note_value: Default::default(),
 		}
 	}
 }
@@ -56,13 +62,16 @@ pub enum Action {}
 #[derive(Debug, Clone)]
 pub enum LocalViewInteraction {
 	Back,
+	Label(String),
+	Note(String),
+	SaveNote,
 }
 
 pub fn handle_message<'a>(
 	grin_gui: &mut GrinGui,
 	message: LocalViewInteraction,
 ) -> Result<Command<Message>> {
-	let state = &mut grin_gui.wallet_state.operation_state.create_tx_state;
+	let state = &mut grin_gui.wallet_state.operation_state.tx_detail_state;
 
 	match message {
 		LocalViewInteraction::Back => {
@@ -70,6 +79,39 @@ pub fn handle_message<'a>(
 			grin_gui.wallet_state.operation_state.mode =
 				crate::gui::element::wallet::operation::Mode::Home;
 		}
+		LocalViewInteraction::Label(s) => state.label_value = s,
+		LocalViewInteraction::Note(s) => state.note_value = s,
+		LocalViewInteraction::SaveNote => {
+			log::debug!("Interaction::WalletOperationTxDetailViewInteraction(SaveNote)");
+			let slate_id = match state.current_tx.as_ref().and_then(|tx| tx.tx_slate_id) {
+				Some(id) => id,
+				None => return Ok(Command::none()),
+			};
+			let tx_note = TxNote {
+				label: state.label_value.clone(),
+				note: state.note_value.clone(),
+			};
+
+			let list_state = &mut grin_gui
+				.wallet_state
+				.operation_state
+				.home_state
+				.tx_list_display_state;
+			list_state.tx_notes.set(&slate_id, tx_note);
+			// Edited labels should turn up in a search straight away
+			list_state.query_and_sort_txs();
+			if let Err(e) = list_state
+				.tx_notes
+				.save()
+				.context("Failed to save transaction notes")
+			{
+				log_error(&e);
+				grin_gui.error = Some(e);
+				return Ok(Command::none());
+			}
+			grin_gui.wallet_state.operation_state.mode =
+				crate::gui::element::wallet::operation::Mode::Home;
+		}
 	}
 
 	Ok(Command::none())
@@ -433,6 +475,43 @@ pub fn data_container<'a>(config: &'a Config, state: &'a StateContainer) -> Cont
 		column = column
 			.push(tx_net_difference_row)
 			.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)));
+
+		// Label and note, only kept on this machine
+		if tx.tx_slate_id.is_some() {
+			let input = |label: &str, value: &str, on_input: fn(String) -> LocalViewInteraction| {
+				let input: Element<Interaction> = TextInput::new(&localized_string(label), value)
+					.on_input(move |s| {
+						Interaction::WalletOperationTxDetailViewInteraction(on_input(s))
+					})
+					.size(DEFAULT_FONT_SIZE)
+					.padding(6)
+					.width(Length::Fixed(400.0))
+					.style(grin_gui_core::theme::TextInputStyle::AddonsQuery)
+					.into();
+				Column::new()
+					.push(Text::new(localized_string(label)).size(DEFAULT_FONT_SIZE))
+					.push(Space::new(Length::Fixed(0.0), Length::Fixed(row_spacing)))
+					.push(input.map(Message::Interaction))
+					.push(Space::new(Length::Fixed(0.0), Length::Fixed(row_spacing)))
+			};
+			column = column
+				.push(input(
+					"tx-label",
+					&state.label_value,
+					LocalViewInteraction::Label,
+				))
+				.push(input(
+					"tx-note",
+					&state.note_value,
+					LocalViewInteraction::Note,
+				))
+				.push(
+					Text::new(localized_string("tx-note-instruction"))
+						.size(SMALLER_FONT_SIZE)
+						.horizontal_alignment(alignment::Horizontal::Left),
+				)
+				.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)));
+		}
 	}
 
 	let cancel_button_label_container =
@@ -455,7 +534,39 @@ pub fn data_container<'a>(config: &'a Config, state: &'a StateContainer) -> Cont
 		.style(grin_gui_core::theme::ContainerStyle::Segmented)
 		.padding(1);
 
-	let button_row = Row::new()
+	let mut button_row = Row::new();
+
+	if state
+		.current_tx
+		.as_ref()
+		.map_or(false, |tx| tx.tx_slate_id.is_some())
+	{
+		let save_button_label_container =
+			Container::new(Text::new(localized_string("tx-note-save")).size(DEFAULT_FONT_SIZE))
+				.width(button_width)
+				.height(button_height)
+				.center_x()
+				.center_y()
+				.align_x(alignment::Horizontal::Center);
+
+		let save_button: Element<Interaction> = Button::new(save_button_label_container)
+			.style(grin_gui_core::theme::ButtonStyle::Primary)
+			.on_press(Interaction::WalletOperationTxDetailViewInteraction(
+				LocalViewInteraction::SaveNote,
+			))
+			.into();
+
+		let save_container = Container::new(save_button.map(Message::Interaction)).padding(1);
+		let save_container = Container::new(save_container)
+			.style(grin_gui_core::theme::ContainerStyle::Segmented)
+			.padding(1);
+
+		button_row = button_row
+			.push(save_container)
+			.push(Space::new(Length::Fixed(unit_spacing), Length::Fixed(0.0)));
+	}
+
+	button_row = button_row
 		.push(cancel_container)
 		.push(Space::new(Length::Fixed(unit_spacing), Length::Fixed(0.0)));
 
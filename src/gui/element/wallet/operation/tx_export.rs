@@ -0,0 +1,305 @@
+use crate::log_error;
+use chrono::NaiveDate;
+use grin_gui_core::config::Config;
+use grin_gui_core::wallet::{tx_export_records, write_tx_export, TxExportFormat};
+use native_dialog::FileDialog;
+use std::collections::HashMap;
+use std::path::Path;
+use strfmt::strfmt;
+
+use {
+	super::super::super::{
+		BUTTON_HEIGHT, BUTTON_WIDTH, DEFAULT_FONT_SIZE, DEFAULT_HEADER_FONT_SIZE, DEFAULT_PADDING,
+		SMALLER_FONT_SIZE,
+	},
+	super::tx_list_display,
+	crate::gui::{GrinGui, Interaction, Message},
+	crate::localization::localized_string,
+	crate::Result,
+	anyhow::Context,
+	grin_gui_core::theme::{Button, Column, Container, Element, Row, Scrollable, Text, TextInput},
+	iced::widget::Space,
+	iced::{alignment, Alignment, Command, Length},
+};
+
+/// Format dates are entered in
+const DATE_FORMAT: &str = "%Y-%m-%d";
+
+#[derive(Default)]
+pub struct StateContainer {
+	pub from_value: String,
+	pub to_value: String,
+	/// Why the dates in the form couldn't be used
+	form_error: Option<String>,
+	/// Where the last export went
+	exported: Option<String>,
+}
+
+impl StateContainer {
+	pub fn clear(&mut self) {
+		*self = Default::default();
+	}
+}
+
+#[derive(Debug, Clone)]
+pub enum LocalViewInteraction {
+	Back,
+	From(String),
+	To(String),
+	Export,
+}
+
+/// Optional date from the form, `Err` if something was entered that isn't a date
+fn parse_date(value: &str) -> std::result::Result<Option<NaiveDate>, ()> {
+	match value.trim() {
+		"" => Ok(None),
+		v => NaiveDate::parse_from_str(v, DATE_FORMAT)
+			.map(Some)
+			.map_err(|_| ()),
+	}
+}
+
+/// Write the transactions currently listed within the chosen dates to a file, in the format
+/// implied by its extension. Returns how many were written.
+pub fn export_to(
+	list_state: &tx_list_display::StateContainer,
+	from: Option<NaiveDate>,
+	to: Option<NaiveDate>,
+	path: &Path,
+) -> anyhow::Result<usize> {
+	let records = tx_export_records(
+		list_state.listed_txs().map(|t| &t.tx),
+		&list_state.tx_notes,
+		from,
+		to,
+	);
+	write_tx_export(path, &records, TxExportFormat::from_path(path))
+		.context("Failed to export transactions")?;
+	Ok(records.len())
+}
+
+pub fn handle_message<'a>(
+	grin_gui: &mut GrinGui,
+	message: LocalViewInteraction,
+) -> Result<Command<Message>> {
+	let state = &mut grin_gui.wallet_state.operation_state.tx_export_state;
+
+	match message {
+		LocalViewInteraction::Back => {
+			log::debug!("Interaction::WalletOperationTxExportViewInteraction(Back)");
+			state.form_error = None;
+			state.exported = None;
+			grin_gui.wallet_state.operation_state.mode =
+				crate::gui::element::wallet::operation::Mode::Home;
+		}
+		LocalViewInteraction::From(s) => state.from_value = s,
+		LocalViewInteraction::To(s) => state.to_value = s,
+		LocalViewInteraction::Export => {
+			log::debug!("Interaction::WalletOperationTxExportViewInteraction(Export)");
+			state.exported = None;
+			let (from, to) = match (parse_date(&state.from_value), parse_date(&state.to_value)) {
+				(Ok(from), Ok(to)) if from.is_none() || to.is_none() || from <= to => (from, to),
+				_ => {
+					state.form_error = Some(localized_string("tx-export-date-error"));
+					return Ok(Command::none());
+				}
+			};
+			state.form_error = None;
+
+			let mut file_dialog = FileDialog::new();
+			for format in TxExportFormat::ALL.iter() {
+				file_dialog = file_dialog.add_filter(&format.to_string(), &[format.file_ext()]);
+			}
+			let mut path = match file_dialog.show_save_single_file() {
+				Ok(Some(path)) => path,
+				Ok(None) => return Ok(Command::none()),
+				Err(e) => {
+					log::debug!("tx_export.rs::LocalViewInteraction::Export {}", e);
+					return Ok(Command::none());
+				}
+			};
+			if path.extension().is_none() {
+				path.set_extension(TxExportFormat::Csv.file_ext());
+			}
+
+			let list_state = &grin_gui
+				.wallet_state
+				.operation_state
+				.home_state
+				.tx_list_display_state;
+			match export_to(list_state, from, to, &path) {
+				Ok(count) => {
+					let mut vars = HashMap::new();
+					vars.insert("count".to_string(), count.to_string());
+					vars.insert("path".to_string(), path.display().to_string());
+					let fmt = localized_string("tx-export-done");
+					grin_gui
+						.wallet_state
+						.operation_state
+						.tx_export_state
+						.exported = Some(strfmt(&fmt, &vars).unwrap());
+				}
+				Err(e) => {
+					log_error(&e);
+					grin_gui.error = Some(e);
+				}
+			}
+		}
+	}
+
+	Ok(Command::none())
+}
+
+pub fn data_container<'a>(
+	config: &'a Config,
+	state: &'a StateContainer,
+	list_state: &'a tx_list_display::StateContainer,
+) -> Container<'a, Message> {
+	// Title row
+	let title = Text::new(localized_string("tx-export-title"))
+		.size(DEFAULT_HEADER_FONT_SIZE)
+		.horizontal_alignment(alignment::Horizontal::Center);
+
+	let title_container = Container::new(title)
+		.style(grin_gui_core::theme::ContainerStyle::BrightBackground)
+		.padding(iced::Padding::from([
+			2, // top
+			0, // right
+			2, // bottom
+			5, // left
+		]));
+
+	let header_row = Row::new().push(title_container);
+
+	let header_container = Container::new(header_row).padding(iced::Padding::from([
+		0,                      // top
+		0,                      // right
+		DEFAULT_PADDING as u16, // bottom
+		0,                      // left
+	]));
+
+	let unit_spacing = 15.0;
+	let row_spacing = 5.0;
+
+	let button_height = Length::Fixed(BUTTON_HEIGHT);
+	let button_width = Length::Fixed(BUTTON_WIDTH);
+
+	let mut vars = HashMap::new();
+	vars.insert("count".to_string(), list_state.listed_txs().count());
+	let fmt = localized_string("tx-export-instruction");
+	let instruction = Text::new(strfmt(&fmt, &vars).unwrap())
+		.size(SMALLER_FONT_SIZE)
+		.horizontal_alignment(alignment::Horizontal::Left);
+
+	let input = |label: &str, value: &str, on_input: fn(String) -> LocalViewInteraction| {
+		let input: Element<Interaction> = TextInput::new(DATE_FORMAT, value)
+			.on_input(move |s| Interaction::WalletOperationTxExportViewInteraction(on_input(s)))
+			.size(DEFAULT_FONT_SIZE)
+			.padding(6)
+			.width(Length::Fixed(200.0))
+			.style(grin_gui_core::theme::TextInputStyle::AddonsQuery)
+			.into();
+		Column::new()
+			.push(Text::new(localized_string(label)).size(SMALLER_FONT_SIZE))
+			.push(Space::new(Length::Fixed(0.0), Length::Fixed(row_spacing)))
+			.push(input.map(Message::Interaction))
+	};
+
+	let date_row = Row::new()
+		.push(input(
+			"tx-export-from",
+			&state.from_value,
+			LocalViewInteraction::From,
+		))
+		.push(Space::new(Length::Fixed(unit_spacing), Length::Fixed(0.0)))
+		.push(input(
+			"tx-export-to",
+			&state.to_value,
+			LocalViewInteraction::To,
+		));
+
+	let mut column = Column::new()
+		.push(
+			Container::new(instruction)
+				.style(grin_gui_core::theme::ContainerStyle::NormalBackground),
+		)
+		.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)))
+		.push(date_row)
+		.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)));
+
+	if let Some(e) = state.form_error.as_ref() {
+		column = column
+			.push(
+				Text::new(e)
+					.size(DEFAULT_FONT_SIZE)
+					.style(grin_gui_core::theme::text::TextStyle::Warning),
+			)
+			.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)));
+	}
+
+	if let Some(exported) = state.exported.as_ref() {
+		column = column
+			.push(Text::new(exported).size(DEFAULT_FONT_SIZE))
+			.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)));
+	}
+
+	let button = |label: &str, message: LocalViewInteraction| {
+		let label_container =
+			Container::new(Text::new(localized_string(label)).size(DEFAULT_FONT_SIZE))
+				.width(button_width)
+				.height(button_height)
+				.center_x()
+				.center_y()
+				.align_x(alignment::Horizontal::Center);
+
+		let button: Element<Interaction> = Button::new(label_container)
+			.style(grin_gui_core::theme::ButtonStyle::Primary)
+			.on_press(Interaction::WalletOperationTxExportViewInteraction(message))
+			.into();
+
+		let container = Container::new(button.map(Message::Interaction)).padding(1);
+		Container::new(container)
+			.style(grin_gui_core::theme::ContainerStyle::Segmented)
+			.padding(1)
+	};
+
+	let button_row = Row::new()
+		.push(button("tx-export", LocalViewInteraction::Export))
+		.push(Space::new(Length::Fixed(unit_spacing), Length::Fixed(0.0)))
+		.push(button("back", LocalViewInteraction::Back))
+		.align_items(Alignment::Center);
+
+	column = column.push(button_row);
+
+	let form_container = Container::new(column)
+		.width(Length::Fill)
+		.padding(iced::Padding::from([
+			0, // top
+			0, // right
+			0, // bottom
+			5, // left
+		]));
+
+	// form container should be scrollable in tiny windows
+	let scrollable = Scrollable::new(form_container)
+		.height(Length::Fill)
+		.style(grin_gui_core::theme::ScrollableStyle::Primary);
+
+	let content = Container::new(scrollable)
+		.width(Length::Fill)
+		.height(Length::Shrink)
+		.style(grin_gui_core::theme::ContainerStyle::NormalBackground);
+
+	let wrapper_column = Column::new()
+		.height(Length::Fill)
+		.push(header_container)
+		.push(content);
+
+	// Returns the final container.
+	Container::new(wrapper_column).padding(iced::Padding::from([
+		DEFAULT_PADDING, // top
+		DEFAULT_PADDING, // right
+		DEFAULT_PADDING, // bottom
+		DEFAULT_PADDING, // left
+	]))
+}
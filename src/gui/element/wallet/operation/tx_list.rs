@@ -6,15 +6,16 @@ use std::borrow::Borrow;
 use {
 	super::super::super::{BUTTON_WIDTH, DEFAULT_FONT_SIZE, DEFAULT_PADDING, SMALLER_FONT_SIZE},
 	crate::gui::{GrinGui, Interaction, Message},
-	crate::localization::localized_string,
+	crate::localization::{localized_number_format, localized_string},
 	crate::Result,
 	grin_gui_core::theme::{
 		Button, Column, Container, Element, PickList, Row, Scrollable, TableHeaderStyle, Text,
 		Theme,
 	},
 	grin_gui_core::{
-		config::Config,
+		config::{Config, TxNotes},
 		node::amount_to_hr_string,
+		price::PriceQuote,
 		theme::{ButtonStyle, ColorPalette, ContainerStyle, TableHeader, TableRow, TableRowStyle},
 		wallet::{TxLogEntry, TxLogEntryType},
 	},
@@ -75,6 +76,7 @@ pub enum ColumnKey {
 	PaymentProof,
 	Kernel,
 	TxData,
+	Label,
 	// Only used for sorting, not an actual visible column that can be shown
 	FuzzyScore,
 }
@@ -102,6 +104,7 @@ impl ColumnKey {
 			PaymentProof => localized_string("tx_payment_proof"),
 			Kernel => localized_string("tx_kernel"),
 			TxData => localized_string("tx_data"),
+			Label => localized_string("tx-label"),
 			FuzzyScore => unreachable!("fuzzy score not used as an actual column"),
 		}
 	}
@@ -128,6 +131,7 @@ impl ColumnKey {
 			PaymentProof => "tx_payment_proof",
 			Kernel => "tx_kernel",
 			TxData => "tx_data",
+			Label => "tx-label",
 			FuzzyScore => unreachable!("fuzzy score not used as an actual column"),
 		};
 
@@ -156,6 +160,7 @@ impl From<&str> for ColumnKey {
 			"tx_payment_proof" => ColumnKey::PaymentProof,
 			"tx_kernel" => ColumnKey::Kernel,
 			"tx_data" => ColumnKey::TxData,
+			"tx-label" => ColumnKey::Label,
 			_ => panic!("Unknown ColumnKey for {}", s),
 		}
 	}
@@ -179,11 +184,37 @@ impl SortDirection {
 #[derive(Debug, Clone)]
 pub struct TxLogEntryWrap {
 	pub tx: TxLogEntry,
+	/// How well the transaction matches the current search, `None` if it doesn't
+	pub fuzzy_score: Option<i64>,
 }
 
 impl TxLogEntryWrap {
 	pub fn new(tx: TxLogEntry) -> Self {
-		Self { tx }
+		Self {
+			tx,
+			fuzzy_score: None,
+		}
+	}
+}
+
+/// Sort transactions by a column, falling back to the transaction id for columns that can't be
+/// sorted by yet
+pub fn sort_txs(txs: &mut [TxLogEntryWrap], sort_direction: SortDirection, column_key: ColumnKey) {
+	match (column_key, sort_direction) {
+		(ColumnKey::FuzzyScore, SortDirection::Asc) => {
+			txs.sort_by(|a, b| a.fuzzy_score.cmp(&b.fuzzy_score))
+		}
+		(ColumnKey::FuzzyScore, SortDirection::Desc) => {
+			txs.sort_by(|a, b| b.fuzzy_score.cmp(&a.fuzzy_score))
+		}
+		(ColumnKey::CreationTime, SortDirection::Asc) => {
+			txs.sort_by(|a, b| a.tx.creation_ts.cmp(&b.tx.creation_ts))
+		}
+		(ColumnKey::CreationTime, SortDirection::Desc) => {
+			txs.sort_by(|a, b| b.tx.creation_ts.cmp(&a.tx.creation_ts))
+		}
+		(_, SortDirection::Asc) => txs.sort_by(|a, b| a.tx.id.cmp(&b.tx.id)),
+		(_, SortDirection::Desc) => txs.sort_by(|a, b| b.tx.id.cmp(&a.tx.id)),
 	}
 }
 
@@ -391,6 +422,12 @@ impl Default for HeaderState {
 					hidden: true,
 					order: 17,
 				},
+				ColumnState {
+					key: ColumnKey::Label,
+					width: Length::FillPortion(2),
+					hidden: false,
+					order: 18,
+				},
 			],
 		}
 	}
@@ -486,6 +523,10 @@ impl Default for ColumnSettings {
 					key: ColumnKey::TxData,
 					order: 17,
 				},
+				ColumnSettingState {
+					key: ColumnKey::Label,
+					order: 18,
+				},
 			],
 		}
 	}
@@ -751,6 +792,12 @@ impl Default for TxListHeaderState {
 					hidden: true,
 					order: 17,
 				},
+				TxListColumnState {
+					key: ColumnKey::Label,
+					width: Length::Fixed(105.0),
+					hidden: true,
+					order: 18,
+				},
 			],
 		}
 	}
@@ -836,6 +883,10 @@ impl Default for TxListColumnSettings {
 					key: ColumnKey::TxData,
 					order: 17,
 				},
+				TxListColumnSettingState {
+					key: ColumnKey::Label,
+					order: 18,
+				},
 			],
 		}
 	}
@@ -977,10 +1028,12 @@ pub fn data_row_container<'a, 'b>(
 	is_tx_expanded: bool,
 	expand_type: &'a ExpandType,
 	config: &Config,
+	tx_notes: &TxNotes,
 	column_config: &'b [(ColumnKey, Length, bool)],
 	is_odd: Option<bool>,
 	pending_confirmation: &Option<Confirm>,
 	node_synched: bool,
+	quote: Option<&PriceQuote>,
 ) -> Container<'a, Message> {
 	let default_height = Length::Fixed(40.0);
 	let mut default_row_height = 26;
@@ -1020,8 +1073,33 @@ pub fn data_row_container<'a, 'b>(
 		)
 	};
 
+	// The difference's value at the current price, shown next to it
+	let net_value = quote.map(|q| {
+		let tx = &tx_log_entry_wrap.tx;
+		let value = q.value(tx.amount_credited) - q.value(tx.amount_debited);
+		format!(
+			"≈ {}",
+			q.currency.format_value(value, &localized_number_format())
+		)
+	});
+
 	let status = create_tx_display_status(&tx_log_entry_wrap.tx);
 
+	let tx_note = tx_log_entry_wrap
+		.tx
+		.tx_slate_id
+		.as_ref()
+		.and_then(|id| tx_notes.get(id))
+		.cloned()
+		.unwrap_or_default();
+
+	// Legacy sends ask the recipient to sign a payment proof
+	let payment_proof = match &tx_log_entry_wrap.tx.payment_proof {
+		Some(p) if p.receiver_signature.is_some() => localized_string("yes"),
+		Some(_) => localized_string("tx-proof-pending"),
+		None => String::new(),
+	};
+
 	if let Some((idx, width)) = column_config
 		.iter()
 		.enumerate()
@@ -1101,6 +1179,15 @@ pub fn data_row_container<'a, 'b>(
 			.size(DEFAULT_FONT_SIZE)
 			.size(DEFAULT_FONT_SIZE);
 
+		let mut display_net_difference = Row::new()
+			.push(display_net_difference)
+			.spacing(5)
+			.align_items(Alignment::Center);
+		if let Some(value) = net_value {
+			display_net_difference =
+				display_net_difference.push(Text::new(value).size(SMALLER_FONT_SIZE));
+		}
+
 		let display_net_difference_container = Container::new(display_net_difference)
 			.width(*width)
 			.style(grin_gui_core::theme::ContainerStyle::HoverableForeground);
@@ -1151,6 +1238,46 @@ pub fn data_row_container<'a, 'b>(
 		row_containers.push((idx, display_tx_type_container));
 	}
 
+	if let Some((idx, width)) = column_config
+		.iter()
+		.enumerate()
+		.filter_map(|(idx, (key, width, hidden))| {
+			if *key == ColumnKey::PaymentProof && !hidden {
+				Some((idx, width))
+			} else {
+				None
+			}
+		})
+		.next()
+	{
+		let display_payment_proof = Text::new(payment_proof).size(DEFAULT_FONT_SIZE);
+		let display_payment_proof_container = Container::new(display_payment_proof)
+			.width(*width)
+			.style(grin_gui_core::theme::ContainerStyle::HoverableForeground);
+
+		row_containers.push((idx, display_payment_proof_container));
+	}
+
+	if let Some((idx, width)) = column_config
+		.iter()
+		.enumerate()
+		.filter_map(|(idx, (key, width, hidden))| {
+			if *key == ColumnKey::Label && !hidden {
+				Some((idx, width))
+			} else {
+				None
+			}
+		})
+		.next()
+	{
+		let display_label = Text::new(tx_note.label.clone()).size(DEFAULT_FONT_SIZE);
+		let display_label_container = Container::new(display_label)
+			.width(*width)
+			.style(grin_gui_core::theme::ContainerStyle::HoverableForeground);
+
+		row_containers.push((idx, display_label_container));
+	}
+
 	let left_spacer = Space::new(Length::Fixed(DEFAULT_PADDING), Length::Fixed(0.0));
 	let right_spacer = Space::new(Length::Fixed(DEFAULT_PADDING + 5.0), Length::Fixed(0.0));
 
@@ -1270,8 +1397,12 @@ pub fn data_row_container<'a, 'b>(
 					.push(tx_details_wrap)
 					.push(Space::with_width(Length::Fixed(DEFAULT_PADDING)));
 
-				// Invoice proof view/copy paste
-				if config.tx_method == TxMethod::Contracts {
+				// Invoice proof, or the payment proof of a legacy send, view/copy paste
+				let has_proof = match config.tx_method {
+					TxMethod::Contracts => true,
+					TxMethod::Legacy => tx_cloned_for_row.tx.payment_proof.is_some(),
+				};
+				if has_proof {
 					let tx_proof_container = Container::new(
 						Text::new(localized_string("tx-proof")).size(DEFAULT_FONT_SIZE),
 					)
@@ -1521,12 +1652,33 @@ pub fn data_row_container<'a, 'b>(
 				.push(Space::new(Length::Fixed(5.0), Length::Fixed(0.0)))
 				.push(delete_button.map(Message::Interaction))
 				.width(Length::Fill);*/
-				let column = Column::new()
+				let mut column = Column::new()
 					.push(id_row)
 					.push(Space::new(Length::Fixed(0.0), Length::Fixed(3.0)))
 					.push(uuid_row)
 					.push(Space::new(Length::Fixed(0.0), Length::Fixed(3.0)))
 					.push(type_row);
+
+				// Label and note, if the user has written any
+				for (title, value) in [("tx-label", tx_note.label), ("tx-note", tx_note.note)] {
+					if value.is_empty() {
+						continue;
+					}
+					let title_container = Container::new(
+						Text::new(format!("{}: ", localized_string(title))).size(DEFAULT_FONT_SIZE),
+					)
+					.style(grin_gui_core::theme::ContainerStyle::HoverableBrightForeground);
+					let value_container = Container::new(Text::new(value).size(DEFAULT_FONT_SIZE))
+						.style(grin_gui_core::theme::ContainerStyle::HoverableBrightForeground);
+					column = column
+						.push(Space::new(Length::Fixed(0.0), Length::Fixed(3.0)))
+						.push(
+							Row::new()
+								.push(title_container)
+								.push(Space::new(Length::Fixed(5.0), Length::Fixed(0.0)))
+								.push(value_container),
+						);
+				}
 				//.push(Space::new(Length::Fixed(0.0), Length::Fixed(3)))
 				/* .push(notes_title_container)
 				.push(Space::new(Length::Fixed(0.0), Length::Fixed(3)))
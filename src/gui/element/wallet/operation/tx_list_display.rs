@@ -1,9 +1,10 @@
-use super::tx_list::{self, ExpandType};
+use super::tx_list::{self, ColumnKey, ExpandType, SortDirection};
 use crate::log_error;
 use async_std::prelude::FutureExt;
 use chrono::DurationRound;
+use fuzzy_matcher::{skim::SkimMatcherV2, FuzzyMatcher};
 use grin_gui_core::{
-	config::Config,
+	config::{Config, TxNotes},
 	wallet::{TxLogEntry, TxLogEntryType},
 };
 use iced_aw::Card;
@@ -26,7 +27,7 @@ use {
 	},
 	grin_gui_core::wallet::{
 		InitTxArgs, RetrieveTxQueryArgs, RetrieveTxQuerySortOrder, Slate, StatusMessage,
-		WalletInfo, WalletInterface,
+		WalletInfo,
 	},
 	grin_gui_core::{
 		node::amount_to_hr_string,
@@ -48,6 +49,11 @@ pub struct StateContainer {
 
 	pub expanded_type: ExpandType,
 
+	// local labels and notes of transactions
+	pub tx_notes: TxNotes,
+	// search query the list is filtered by
+	pub query: Option<String>,
+
 	// balance history for wallet as (date, grin_balance)
 	pub balance_data: Vec<(chrono::DateTime<chrono::Utc>, f64)>,
 }
@@ -62,7 +68,58 @@ impl Default for StateContainer {
 			mode: Mode::NotInit,
 			balance_data: vec![],
 			confirmed_txns: vec![],
+			tx_notes: Default::default(),
+			query: None,
+		}
+	}
+}
+
+impl StateContainer {
+	/// Transactions to list, only those matching the search query if there is one
+	pub fn listed_txs(&self) -> impl Iterator<Item = &TxLogEntryWrap> {
+		self.wallet_txs
+			.txs
+			.iter()
+			.filter(move |tx| self.query.is_none() || tx.fuzzy_score.is_some())
+	}
+
+	/// Score transactions against the search query by their label, note and slate id, listing the
+	/// best matches first. Without a query the list goes back to newest first.
+	pub fn query_and_sort_txs(&mut self) {
+		let query = match self.query.as_ref().map(|q| q.trim().to_lowercase()) {
+			Some(q) if !q.is_empty() => q,
+			_ => {
+				for tx_wrap in self.wallet_txs.txs.iter_mut() {
+					tx_wrap.fuzzy_score = None;
+				}
+				tx_list::sort_txs(&mut self.wallet_txs.txs, SortDirection::Desc, ColumnKey::Id);
+				return;
+			}
+		};
+
+		let fuzzy_matcher = SkimMatcherV2::default();
+		for tx_wrap in self.wallet_txs.txs.iter_mut() {
+			let slate_id = tx_wrap.tx.tx_slate_id.map(|id| id.to_string());
+			let note = slate_id
+				.as_ref()
+				.and_then(|id| self.tx_notes.notes.get(id))
+				.cloned()
+				.unwrap_or_default();
+			let label_score = fuzzy_matcher.fuzzy_match(&note.label.to_lowercase(), &query);
+			let note_score = fuzzy_matcher
+				.fuzzy_match(&note.note.to_lowercase(), &query)
+				.map(|s| s / 2);
+			let slate_id_score = slate_id.and_then(|id| fuzzy_matcher.fuzzy_match(&id, &query));
+			tx_wrap.fuzzy_score = [label_score, note_score, slate_id_score]
+				.into_iter()
+				.flatten()
+				.max();
 		}
+		tx_list::sort_txs(
+			&mut self.wallet_txs.txs,
+			SortDirection::Desc,
+			ColumnKey::FuzzyScore,
+		);
 	}
 }
 
@@ -82,6 +139,9 @@ pub enum LocalViewInteraction {
 	RefreshList,
 	TxListUpdateSuccess(bool, Vec<TxLogEntry>),
 	TxListUpdateFailure(Arc<RwLock<Option<anyhow::Error>>>),
+	Query(String),
+	ShowExport,
+	ShowReport,
 }
 
 pub fn handle_message<'a>(
@@ -124,9 +184,9 @@ pub fn handle_message<'a>(
 		}
 
 		LocalViewInteraction::RefreshList => {
-			let w = grin_gui.wallet_interface.clone();
+			let w = grin_gui.wallet_backend.clone();
 
-			let fut = move || WalletInterface::get_txs(w, Some(state.query_args.clone()));
+			let fut = move || w.get_txs(Some(state.query_args.clone()));
 			return Ok(Command::perform(fut(), |tx_list_res| {
 				if tx_list_res.is_err() {
 					let e = tx_list_res
@@ -155,6 +215,7 @@ pub fn handle_message<'a>(
 				.map(|tx| TxLogEntryWrap::new(tx.clone()))
 				.collect();
 			state.wallet_txs = TxList { txs: tx_wrap_list };
+			state.query_and_sort_txs();
 
 			let confirmed_txns: Vec<&TxLogEntry> = txs.iter().filter(|tx| tx.confirmed).collect();
 
@@ -228,6 +289,24 @@ pub fn handle_message<'a>(
 				log_error(e);
 			}
 		}
+		LocalViewInteraction::Query(query) => {
+			state.query = Some(query).filter(|q| !q.is_empty());
+			state.query_and_sort_txs();
+		}
+		LocalViewInteraction::ShowExport => {
+			grin_gui.wallet_state.operation_state.mode =
+				crate::gui::element::wallet::operation::Mode::TxExport;
+		}
+		LocalViewInteraction::ShowReport => {
+			grin_gui.wallet_state.operation_state.mode =
+				crate::gui::element::wallet::operation::Mode::Accounting;
+			let fut = move || async {};
+			return Ok(Command::perform(fut(), |_| {
+				Message::Interaction(Interaction::WalletOperationAccountingViewInteraction(
+					super::accounting::LocalViewInteraction::Refresh,
+				))
+			}));
+		}
 	}
 
 	Ok(Command::none())
@@ -304,11 +383,68 @@ pub fn data_container<'a>(
 		.style(grin_gui_core::theme::ContainerStyle::Segmented)
 		.padding(1);
 
+	let query: Element<Interaction> = TextInput::new(
+		&localized_string("tx-search"),
+		state.query.as_deref().unwrap_or_default(),
+	)
+	.on_input(|s| {
+		Interaction::WalletOperationHomeTxListDisplayInteraction(LocalViewInteraction::Query(s))
+	})
+	.size(DEFAULT_FONT_SIZE)
+	.padding(6)
+	.width(Length::Fixed(200.0))
+	.style(grin_gui_core::theme::TextInputStyle::AddonsQuery)
+	.into();
+
+	let export_container =
+		Container::new(Text::new(localized_string("tx-export")).size(DEFAULT_FONT_SIZE))
+			.width(button_width)
+			.align_y(alignment::Vertical::Center)
+			.align_x(alignment::Horizontal::Center);
+
+	let export_button: Element<Interaction> = Button::new(export_container)
+		.width(button_width)
+		.style(grin_gui_core::theme::ButtonStyle::Primary)
+		.on_press(Interaction::WalletOperationHomeTxListDisplayInteraction(
+			LocalViewInteraction::ShowExport,
+		))
+		.into();
+
+	let export_container_wrap = Container::new(export_button.map(Message::Interaction)).padding(1);
+	let export_container_wrap = Container::new(export_container_wrap)
+		.style(grin_gui_core::theme::ContainerStyle::Segmented)
+		.padding(1);
+
+	let report_container =
+		Container::new(Text::new(localized_string("accounting")).size(DEFAULT_FONT_SIZE))
+			.width(button_width)
+			.align_y(alignment::Vertical::Center)
+			.align_x(alignment::Horizontal::Center);
+
+	let report_button: Element<Interaction> = Button::new(report_container)
+		.width(button_width)
+		.style(grin_gui_core::theme::ButtonStyle::Primary)
+		.on_press(Interaction::WalletOperationHomeTxListDisplayInteraction(
+			LocalViewInteraction::ShowReport,
+		))
+		.into();
+
+	let report_container_wrap = Container::new(report_button.map(Message::Interaction)).padding(1);
+	let report_container_wrap = Container::new(report_container_wrap)
+		.style(grin_gui_core::theme::ContainerStyle::Segmented)
+		.padding(1);
+
 	// add additional buttons here
 	let button_row = Row::new()
+		.push(query.map(Message::Interaction))
+		.push(Space::with_width(Length::Fixed(DEFAULT_PADDING)))
 		.push(latest_container_wrap)
 		.push(Space::with_width(Length::Fixed(DEFAULT_PADDING)))
-		.push(outstanding_container_wrap);
+		.push(outstanding_container_wrap)
+		.push(Space::with_width(Length::Fixed(DEFAULT_PADDING)))
+		.push(export_container_wrap)
+		.push(Space::with_width(Length::Fixed(DEFAULT_PADDING)))
+		.push(report_container_wrap);
 
 	/*let segmented_mode_container = Container::new(button_row).padding(1);
 	let segmented_mode_control_container = Container::new(segmented_mode_container)
@@ -330,6 +466,7 @@ pub fn data_container<'a>(
 
 	// TRANSACTION HEADER
 	let column_config = state.tx_header_state.column_config();
+	let quote = home_state.price_quote(config.currency);
 
 	// Tx row titles is a row of titles above the tx scrollable.
 	// This is to add titles above each section of the tx row, to let
@@ -359,7 +496,7 @@ pub fn data_container<'a>(
 	let mut has_txs = false;
 
 	// Loops though the txs.
-	for (idx, tx_wrap) in state.wallet_txs.txs.iter().enumerate() {
+	for (idx, tx_wrap) in state.listed_txs().enumerate() {
 		has_txs = true;
 		// If hiding ignored addons, we will skip it.
 		/*if addon.state == AddonState::Ignored && self.config.hide_ignored_addons {
@@ -390,10 +527,12 @@ pub fn data_container<'a>(
 			is_tx_expanded,
 			&state.expanded_type,
 			config,
+			&state.tx_notes,
 			&column_config,
 			is_odd,
 			&None,
 			home_state.node_synched,
+			quote.as_ref(),
 		);
 
 		// Adds the addon data cell to the scrollable.
@@ -443,3 +582,62 @@ pub fn data_container<'a>(
 		]))
 		.style(grin_gui_core::theme::ContainerStyle::PanelBordered)
 }
+
+#[cfg(test)]
+mod test {
+	use super::super::test_util::{funded_gui, start_send};
+	use super::{LocalViewInteraction as TI, *};
+	use grin_gui_core::config::TxNote;
+
+	#[test]
+	fn test_tx_notes_in_search() {
+		let (fake, mut grin_gui) = funded_gui();
+		let (rent, _) = start_send(&fake, 1_000_000_000);
+		start_send(&fake, 2_000_000_000);
+		let txs = fake.state().txs.clone();
+		let rent_id = rent.id;
+		grin_gui
+			.wallet_state
+			.operation_state
+			.home_state
+			.tx_list_display_state
+			.tx_notes
+			.set(
+				&rent_id,
+				TxNote {
+					label: "Rent".to_owned(),
+					note: "Paid to Alice in March".to_owned(),
+				},
+			);
+
+		handle_message(&mut grin_gui, TI::TxListUpdateSuccess(true, txs)).unwrap();
+		let list_state = &grin_gui
+			.wallet_state
+			.operation_state
+			.home_state
+			.tx_list_display_state;
+		assert_eq!(list_state.listed_txs().count(), 2);
+
+		// Matches the label, and the note
+		for query in ["rnt", "alice march"] {
+			handle_message(&mut grin_gui, TI::Query(query.to_owned())).unwrap();
+			let listed: Vec<_> = grin_gui
+				.wallet_state
+				.operation_state
+				.home_state
+				.tx_list_display_state
+				.listed_txs()
+				.map(|t| t.tx.tx_slate_id)
+				.collect();
+			assert_eq!(listed, vec![Some(rent_id)]);
+		}
+
+		handle_message(&mut grin_gui, TI::Query(String::new())).unwrap();
+		let list_state = &grin_gui
+			.wallet_state
+			.operation_state
+			.home_state
+			.tx_list_display_state;
+		assert_eq!(list_state.listed_txs().count(), 2);
+	}
+}
@@ -4,11 +4,12 @@ use async_std::prelude::FutureExt;
 use grin_gui_core::{
 	config::Config,
 	error::GrinWalletInterfaceError,
-	wallet::{InvoiceProof, SlatepackAddress, TxLogEntry, TxLogEntryType},
+	wallet::{InvoiceProof, PaymentProof, SlatepackAddress, TxLogEntry, TxLogEntryType},
 };
 use iced_aw::Card;
 use iced_core::Widget;
-use std::fs::File;
+use native_dialog::FileDialog;
+use std::fs::{self, File};
 use std::io::{Read, Write};
 use std::path::PathBuf;
 
@@ -41,6 +42,8 @@ pub struct StateContainer {
 	// Transaction that we're viewing
 	pub current_tx: Option<TxLogEntry>,
 	pub current_proof: Option<InvoiceProof>,
+	// Proof of a legacy send, requested from the recipient
+	pub current_payment_proof: Option<PaymentProof>,
 }
 
 impl Default for StateContainer {
@@ -48,6 +51,7 @@ impl Default for StateContainer {
 		Self {
 			current_tx: Default::default(),
 			current_proof: Default::default(),
+			current_payment_proof: Default::default(),
 		}
 	}
 }
@@ -58,13 +62,14 @@ pub enum Action {}
 #[derive(Debug, Clone)]
 pub enum LocalViewInteraction {
 	Back,
+	ExportProof,
 }
 
 pub fn handle_message<'a>(
 	grin_gui: &mut GrinGui,
 	message: LocalViewInteraction,
 ) -> Result<Command<Message>> {
-	let state = &mut grin_gui.wallet_state.operation_state.create_tx_state;
+	let state = &mut grin_gui.wallet_state.operation_state.tx_proof_state;
 
 	match message {
 		LocalViewInteraction::Back => {
@@ -72,11 +77,41 @@ pub fn handle_message<'a>(
 			grin_gui.wallet_state.operation_state.mode =
 				crate::gui::element::wallet::operation::Mode::Home;
 		}
+		LocalViewInteraction::ExportProof => {
+			let contents = match proof_json(state) {
+				Some(c) => c,
+				None => return Ok(Command::none()),
+			};
+			let file_dialog = FileDialog::new().add_filter("JSON", &["json"]);
+			match file_dialog.show_save_single_file() {
+				Ok(Some(path)) => {
+					if let Err(e) = fs::write(&path, contents)
+						.with_context(|| format!("Failed to write proof to {}", path.display()))
+					{
+						log_error(&e);
+						grin_gui.error = Some(e);
+					}
+				}
+				Ok(None) => {}
+				Err(e) => {
+					log::debug!("tx_proof.rs::LocalViewInteraction::ExportProof {}", e);
+				}
+			}
+		}
 	}
 
 	Ok(Command::none())
 }
 
+/// The proof being viewed, as it's written to a proof file
+fn proof_json(state: &StateContainer) -> Option<String> {
+	match (&state.current_proof, &state.current_payment_proof) {
+		(Some(proof), _) => serde_json::to_string_pretty(proof).ok(),
+		(None, Some(proof)) => serde_json::to_string_pretty(proof).ok(),
+		(None, None) => None,
+	}
+}
+
 pub fn data_container<'a>(config: &'a Config, state: &'a StateContainer) -> Container<'a, Message> {
 	// Title row
 	let title = Text::new(localized_string("tx-proof-title"))
@@ -183,9 +218,47 @@ pub fn data_container<'a>(config: &'a Config, state: &'a StateContainer) -> Cont
 			.push(pr_sender_address_row)
 			.push(Space::new(Length::Fixed(0.0), Length::Fixed(row_spacing)))
 			.push(Space::new(Length::Fixed(0.0), Length::Fixed(row_spacing)));
+	} else if let Some(ref proof) = state.current_payment_proof {
+		let proof_row = |label: &str, value: String| {
+			Row::new()
+				.push(
+					Container::new(
+						Text::new(format!("{}:  ", localized_string(label)))
+							.size(DEFAULT_FONT_SIZE)
+							.horizontal_alignment(alignment::Horizontal::Left),
+					)
+					.style(grin_gui_core::theme::ContainerStyle::NormalBackground),
+				)
+				.push(
+					Container::new(
+						Text::new(value)
+							.size(DEFAULT_FONT_SIZE)
+							.horizontal_alignment(alignment::Horizontal::Left),
+					)
+					.style(grin_gui_core::theme::ContainerStyle::NormalBackground),
+				)
+		};
 
-		let card_contents = format!("{}", serde_json::to_string_pretty(&proof).unwrap());
+		column = column
+			.push(proof_row(
+				"pr-amount",
+				amount_to_hr_string(proof.amount, true),
+			))
+			.push(Space::new(Length::Fixed(0.0), Length::Fixed(row_spacing)))
+			.push(proof_row(
+				"pr-sender-address",
+				proof.sender_address.to_string(),
+			))
+			.push(Space::new(Length::Fixed(0.0), Length::Fixed(row_spacing)))
+			.push(proof_row(
+				"pr-recipient-address",
+				proof.recipient_address.to_string(),
+			))
+			.push(Space::new(Length::Fixed(0.0), Length::Fixed(row_spacing)))
+			.push(Space::new(Length::Fixed(0.0), Length::Fixed(row_spacing)));
+	}
 
+	if let Some(card_contents) = proof_json(state) {
 		let json_proof_card = Card::new(
 			Text::new(localized_string("pr-json-proof")).size(DEFAULT_HEADER_FONT_SIZE),
 			Text::new(card_contents.clone()).size(DEFAULT_FONT_SIZE),
@@ -238,7 +311,29 @@ pub fn data_container<'a>(config: &'a Config, state: &'a StateContainer) -> Cont
 		.style(grin_gui_core::theme::ContainerStyle::Segmented)
 		.padding(1);
 
+	let export_button_label_container =
+		Container::new(Text::new(localized_string("pr-export")).size(DEFAULT_FONT_SIZE))
+			.width(button_width)
+			.height(button_height)
+			.center_x()
+			.center_y()
+			.align_x(alignment::Horizontal::Center);
+
+	let export_button: Element<Interaction> = Button::new(export_button_label_container)
+		.style(grin_gui_core::theme::ButtonStyle::Primary)
+		.on_press(Interaction::WalletOperationTxProofViewInteraction(
+			LocalViewInteraction::ExportProof,
+		))
+		.into();
+
+	let export_container = Container::new(export_button.map(Message::Interaction)).padding(1);
+	let export_container = Container::new(export_container)
+		.style(grin_gui_core::theme::ContainerStyle::Segmented)
+		.padding(1);
+
 	let button_row = Row::new()
+		.push(export_container)
+		.push(Space::new(Length::Fixed(unit_spacing), Length::Fixed(0.0)))
 		.push(cancel_container)
 		.push(Space::new(Length::Fixed(unit_spacing), Length::Fixed(0.0)));
 
@@ -0,0 +1,387 @@
+use crate::log_error;
+use grin_gui_core::config::Config;
+use native_dialog::FileDialog;
+use std::fs;
+use std::path::Path;
+
+use {
+	super::super::super::{
+		BUTTON_HEIGHT, BUTTON_WIDTH, DEFAULT_FONT_SIZE, DEFAULT_HEADER_FONT_SIZE, DEFAULT_PADDING,
+		SMALLER_FONT_SIZE,
+	},
+	crate::gui::{GrinGui, Interaction, Message},
+	crate::localization::localized_string,
+	crate::Result,
+	anyhow::Context,
+	grin_gui_core::node::amount_to_hr_string,
+	grin_gui_core::theme::{Button, Column, Container, Element, Row, Scrollable, Text},
+	grin_gui_core::wallet::PaymentProof,
+	iced::widget::Space,
+	iced::{alignment, Command, Length},
+	std::sync::{Arc, RwLock},
+};
+
+#[derive(Default)]
+pub struct StateContainer {
+	/// Proof loaded from a file, as shown to the user
+	pub proof: Option<PaymentProof>,
+	pub file_name: Option<String>,
+	pub is_verifying: bool,
+	/// Whether this wallet is the proof's (sender, recipient), once verified
+	pub verified: Option<(bool, bool)>,
+	/// Set when the loaded proof failed verification
+	pub invalid: bool,
+}
+
+impl StateContainer {
+	pub fn clear(&mut self) {
+		*self = Default::default();
+	}
+}
+
+#[derive(Debug, Clone)]
+pub enum LocalViewInteraction {
+	Back,
+	OpenProofFile,
+	ProofLoaded(String, PaymentProof),
+	ProofVerifiedOk((bool, bool)),
+	ProofVerifyError(Arc<RwLock<Option<anyhow::Error>>>),
+}
+
+/// Read a payment proof as exported by grin-wallet or this wallet
+pub fn read_proof_file(path: &Path) -> anyhow::Result<PaymentProof> {
+	let contents = fs::read_to_string(path)
+		.with_context(|| format!("Failed to read proof file {}", path.display()))?;
+	serde_json::from_str(&contents)
+		.with_context(|| format!("{} is not a valid payment proof", path.display()))
+}
+
+pub fn handle_message<'a>(
+	grin_gui: &mut GrinGui,
+	message: LocalViewInteraction,
+) -> Result<Command<Message>> {
+	let state = &mut grin_gui.wallet_state.operation_state.verify_proof_state;
+
+	match message {
+		LocalViewInteraction::Back => {
+			log::debug!("Interaction::WalletOperationVerifyProofViewInteraction(Back)");
+			state.clear();
+			grin_gui.wallet_state.operation_state.mode =
+				crate::gui::element::wallet::operation::Mode::Home;
+		}
+		LocalViewInteraction::OpenProofFile => {
+			let file_dialog = FileDialog::new().add_filter("JSON", &["json"]);
+			let path = match file_dialog.show_open_single_file() {
+				Ok(Some(path)) => path,
+				Ok(None) => return Ok(Command::none()),
+				Err(e) => {
+					log::debug!("verify_proof.rs::LocalViewInteraction::OpenProofFile {}", e);
+					return Ok(Command::none());
+				}
+			};
+			match read_proof_file(&path) {
+				Ok(proof) => {
+					let file_name = path
+						.file_name()
+						.map(|f| f.to_string_lossy().to_string())
+						.unwrap_or_default();
+					let fut = move || async {};
+					return Ok(Command::perform(fut(), move |_| {
+						Message::Interaction(
+							Interaction::WalletOperationVerifyProofViewInteraction(
+								LocalViewInteraction::ProofLoaded(file_name, proof),
+							),
+						)
+					}));
+				}
+				Err(e) => {
+					state.clear();
+					log_error(&e);
+					grin_gui.error = Some(e);
+				}
+			}
+		}
+		LocalViewInteraction::ProofLoaded(file_name, proof) => {
+			grin_gui.error.take();
+			state.clear();
+			state.file_name = Some(file_name);
+			state.proof = Some(proof.clone());
+			state.is_verifying = true;
+
+			let w = grin_gui.wallet_backend.clone();
+			let fut = move || w.verify_payment_proof(proof);
+			return Ok(Command::perform(fut(), |r| {
+				match r.context("Failed to verify payment proof") {
+					Ok(res) => Message::Interaction(
+						Interaction::WalletOperationVerifyProofViewInteraction(
+							LocalViewInteraction::ProofVerifiedOk(res),
+						),
+					),
+					Err(e) => Message::Interaction(
+						Interaction::WalletOperationVerifyProofViewInteraction(
+							LocalViewInteraction::ProofVerifyError(Arc::new(RwLock::new(Some(e)))),
+						),
+					),
+				}
+			}));
+		}
+		LocalViewInteraction::ProofVerifiedOk(res) => {
+			state.is_verifying = false;
+			state.verified = Some(res);
+		}
+		LocalViewInteraction::ProofVerifyError(err) => {
+			state.is_verifying = false;
+			state.invalid = true;
+			grin_gui.error = err.write().unwrap().take();
+			if let Some(e) = grin_gui.error.as_ref() {
+				log_error(e);
+			}
+		}
+	}
+
+	Ok(Command::none())
+}
+
+pub fn data_container<'a>(config: &'a Config, state: &'a StateContainer) -> Container<'a, Message> {
+	// Title row
+	let title = Text::new(localized_string("verify-proof-title"))
+		.size(DEFAULT_HEADER_FONT_SIZE)
+		.horizontal_alignment(alignment::Horizontal::Center);
+
+	let title_container = Container::new(title)
+		.style(grin_gui_core::theme::ContainerStyle::BrightBackground)
+		.padding(iced::Padding::from([
+			2, // top
+			0, // right
+			2, // bottom
+			5, // left
+		]));
+
+	let header_row = Row::new().push(title_container);
+
+	let header_container = Container::new(header_row).padding(iced::Padding::from([
+		0,                      // top
+		0,                      // right
+		DEFAULT_PADDING as u16, // bottom
+		0,                      // left
+	]));
+
+	let unit_spacing = 15.0;
+	let row_spacing = 5.0;
+
+	let button_height = Length::Fixed(BUTTON_HEIGHT);
+	let button_width = Length::Fixed(BUTTON_WIDTH);
+
+	let instruction = Text::new(localized_string("verify-proof-instruction"))
+		.size(SMALLER_FONT_SIZE)
+		.horizontal_alignment(alignment::Horizontal::Left);
+
+	let mut column = Column::new()
+		.push(
+			Container::new(instruction)
+				.style(grin_gui_core::theme::ContainerStyle::NormalBackground),
+		)
+		.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)));
+
+	let proof_row = |label: &str, value: String| {
+		Row::new()
+			.push(
+				Text::new(format!("{}:  ", localized_string(label)))
+					.size(DEFAULT_FONT_SIZE)
+					.horizontal_alignment(alignment::Horizontal::Left),
+			)
+			.push(
+				Text::new(value)
+					.size(DEFAULT_FONT_SIZE)
+					.horizontal_alignment(alignment::Horizontal::Left),
+			)
+	};
+
+	if let (Some(file_name), Some(proof)) = (state.file_name.as_ref(), state.proof.as_ref()) {
+		column = column
+			.push(proof_row("verify-proof-file", file_name.clone()))
+			.push(Space::new(Length::Fixed(0.0), Length::Fixed(row_spacing)))
+			.push(proof_row(
+				"pr-amount",
+				amount_to_hr_string(proof.amount, true),
+			))
+			.push(Space::new(Length::Fixed(0.0), Length::Fixed(row_spacing)))
+			.push(proof_row(
+				"pr-sender-address",
+				proof.sender_address.to_string(),
+			))
+			.push(Space::new(Length::Fixed(0.0), Length::Fixed(row_spacing)))
+			.push(proof_row(
+				"pr-recipient-address",
+				proof.recipient_address.to_string(),
+			))
+			.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)));
+
+		let result_text = if state.is_verifying {
+			localized_string("verify-proof-verifying")
+		} else if let Some((sender_is_mine, recipient_is_mine)) = state.verified {
+			let mut text = localized_string("verify-proof-valid");
+			if sender_is_mine {
+				text = format!("{} {}", text, localized_string("verify-proof-sent-by-me"));
+			}
+			if recipient_is_mine {
+				text = format!(
+					"{} {}",
+					text,
+					localized_string("verify-proof-received-by-me")
+				);
+			}
+			text
+		} else {
+			localized_string("verify-proof-invalid")
+		};
+
+		let mut result = Text::new(result_text).size(DEFAULT_FONT_SIZE);
+		if state.invalid {
+			result = result.style(grin_gui_core::theme::text::TextStyle::Warning);
+		}
+
+		column = column
+			.push(result)
+			.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)));
+	}
+
+	let open_button_label_container =
+		Container::new(Text::new(localized_string("verify-proof-open")).size(DEFAULT_FONT_SIZE))
+			.width(button_width)
+			.height(button_height)
+			.center_x()
+			.center_y()
+			.align_x(alignment::Horizontal::Center);
+
+	let mut open_button =
+		Button::new(open_button_label_container).style(grin_gui_core::theme::ButtonStyle::Primary);
+
+	if !state.is_verifying {
+		open_button = open_button.on_press(Interaction::WalletOperationVerifyProofViewInteraction(
+			LocalViewInteraction::OpenProofFile,
+		));
+	}
+
+	let open_button: Element<Interaction> = open_button.into();
+
+	let cancel_button_label_container =
+		Container::new(Text::new(localized_string("back")).size(DEFAULT_FONT_SIZE))
+			.width(button_width)
+			.height(button_height)
+			.center_x()
+			.center_y()
+			.align_x(alignment::Horizontal::Center);
+
+	let cancel_button: Element<Interaction> = Button::new(cancel_button_label_container)
+		.style(grin_gui_core::theme::ButtonStyle::Primary)
+		.on_press(Interaction::WalletOperationVerifyProofViewInteraction(
+			LocalViewInteraction::Back,
+		))
+		.into();
+
+	let open_container = Container::new(open_button.map(Message::Interaction)).padding(1);
+	let open_container = Container::new(open_container)
+		.style(grin_gui_core::theme::ContainerStyle::Segmented)
+		.padding(1);
+
+	let cancel_container = Container::new(cancel_button.map(Message::Interaction)).padding(1);
+	let cancel_container = Container::new(cancel_container)
+		.style(grin_gui_core::theme::ContainerStyle::Segmented)
+		.padding(1);
+
+	let button_row = Row::new()
+		.push(open_container)
+		.push(Space::new(Length::Fixed(unit_spacing), Length::Fixed(0.0)))
+		.push(cancel_container);
+
+	column = column.push(button_row);
+
+	let form_container = Container::new(column)
+		.width(Length::Fill)
+		.padding(iced::Padding::from([
+			0, // top
+			0, // right
+			0, // bottom
+			5, // left
+		]));
+
+	// form container should be scrollable in tiny windows
+	let scrollable = Scrollable::new(form_container)
+		.height(Length::Fill)
+		.style(grin_gui_core::theme::ScrollableStyle::Primary);
+
+	let content = Container::new(scrollable)
+		.width(Length::Fill)
+		.height(Length::Shrink)
+		.style(grin_gui_core::theme::ContainerStyle::NormalBackground);
+
+	let wrapper_column = Column::new()
+		.height(Length::Fill)
+		.push(header_container)
+		.push(content);
+
+	// Returns the final container.
+	Container::new(wrapper_column).padding(iced::Padding::from([
+		DEFAULT_PADDING, // top
+		DEFAULT_PADDING, // right
+		DEFAULT_PADDING, // bottom
+		DEFAULT_PADDING, // left
+	]))
+}
+
+#[cfg(test)]
+mod test {
+	use super::super::home;
+	use super::super::test_util::{gui_with_pending_send, home_interaction, run_command};
+	use super::super::tx_list::TxLogEntryWrap;
+	use super::{LocalViewInteraction as VP, *};
+	use grin_gui_core::config::TxMethod;
+
+	#[test]
+	fn test_legacy_payment_proof_export_and_verify() {
+		let (fake, mut grin_gui, _, _) = gui_with_pending_send();
+		grin_gui.config.tx_method = TxMethod::Legacy;
+		let tx = fake.state().txs[0].clone();
+
+		let cmd = home::handle_message(
+			&mut grin_gui,
+			home::LocalViewInteraction::TxProof(TxLogEntryWrap::new(tx)),
+		)
+		.unwrap();
+		let message = home_interaction(run_command(cmd).remove(0));
+		assert!(matches!(
+			message,
+			home::LocalViewInteraction::PaymentProofRetrievedOk(..)
+		));
+		home::handle_message(&mut grin_gui, message).unwrap();
+		let proof = grin_gui
+			.wallet_state
+			.operation_state
+			.tx_proof_state
+			.current_payment_proof
+			.clone()
+			.unwrap();
+
+		// Round trip through a proof file, then verify it as if it came from someone else
+		let path = std::env::temp_dir().join(format!("grin-gui-proof-{}.json", std::process::id()));
+		fs::write(&path, serde_json::to_string_pretty(&proof).unwrap()).unwrap();
+		let loaded = read_proof_file(&path).unwrap();
+		let _ = fs::remove_file(&path);
+		assert_eq!(loaded.amount, proof.amount);
+
+		let cmd = handle_message(
+			&mut grin_gui,
+			VP::ProofLoaded("proof.json".to_owned(), loaded),
+		)
+		.unwrap();
+		let message = match run_command(cmd).remove(0) {
+			Message::Interaction(Interaction::WalletOperationVerifyProofViewInteraction(l)) => l,
+			m => panic!("unexpected message {:?}", m),
+		};
+		handle_message(&mut grin_gui, message).unwrap();
+		let state = &grin_gui.wallet_state.operation_state.verify_proof_state;
+		assert_eq!(state.verified, Some((true, false)));
+		assert!(!state.invalid);
+	}
+}
@@ -2,6 +2,7 @@ pub mod init;
 pub mod wallet_import;
 pub mod wallet_import_success;
 pub mod wallet_list;
+pub mod wallet_remote;
 pub mod wallet_setup;
 pub mod wallet_success;
 
@@ -25,6 +26,7 @@ pub struct StateContainer {
 	pub setup_wallet_success_state: wallet_success::StateContainer,
 	pub import_wallet_success_state: wallet_import_success::StateContainer,
 	pub setup_wallet_list_state: wallet_list::StateContainer,
+	pub remote_wallet_state: wallet_remote::StateContainer,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -46,6 +48,7 @@ impl Default for StateContainer {
 			setup_wallet_success_state: Default::default(),
 			import_wallet_success_state: Default::default(),
 			setup_wallet_list_state: Default::default(),
+			remote_wallet_state: Default::default(),
 		}
 	}
 }
@@ -7,6 +7,7 @@ use {
 	crate::Result,
 	anyhow::Context,
 	grin_gui_core::config::Config,
+	grin_gui_core::fs::PersistentData,
 	grin_gui_core::theme::{
 		Button, Column, Container, Element, PickList, Row, Scrollable, TableHeader, TableRow,
 		TableRowStyle, Text, TextInput,
@@ -28,12 +29,18 @@ use crate::gui::element::DEFAULT_SUB_HEADER_FONT_SIZE;
 
 pub struct StateContainer {
 	selected_wallet_index: usize,
+	// Asking the user to confirm removal of the selected wallet
+	confirm_remove: bool,
+	// Whether to archive the wallet's data directory when removing it
+	archive_wallet_data: bool,
 }
 
 impl Default for StateContainer {
 	fn default() -> Self {
 		Self {
 			selected_wallet_index: 0,
+			confirm_remove: false,
+			archive_wallet_data: true,
 		}
 	}
 }
@@ -45,6 +52,14 @@ pub enum LocalViewInteraction {
 	LoadWallet(usize),
 	LocateWallet,
 	CreateWallet,
+	ConnectRemoteWallet,
+	RemoveWallet,
+	ArchiveWalletData(bool),
+	ConfirmRemoveWallet(usize),
+	CancelRemoveWallet,
+	WalletArchivedOk(usize, PathBuf),
+	WalletRemoved(usize),
+	WalletRemoveError(Arc<RwLock<Option<anyhow::Error>>>),
 	WalletImportError(Arc<RwLock<Option<anyhow::Error>>>),
 }
 
@@ -144,6 +159,114 @@ pub fn handle_message<'a>(
 			grin_gui.wallet_state.mode =
 				crate::gui::element::wallet::Mode::CreateWallet(wallet_display_name);
 		}
+		LocalViewInteraction::ConnectRemoteWallet => {
+			let state = &mut grin_gui.wallet_state.setup_state.remote_wallet_state;
+			state.init_wallet_name(&grin_gui.config);
+			grin_gui.wallet_state.mode = crate::gui::element::wallet::Mode::RemoteWallet;
+		}
+		LocalViewInteraction::RemoveWallet => {
+			grin_gui
+				.wallet_state
+				.setup_state
+				.setup_wallet_list_state
+				.confirm_remove = true;
+		}
+		LocalViewInteraction::ArchiveWalletData(archive) => {
+			grin_gui
+				.wallet_state
+				.setup_state
+				.setup_wallet_list_state
+				.archive_wallet_data = archive;
+		}
+		LocalViewInteraction::CancelRemoveWallet => {
+			grin_gui
+				.wallet_state
+				.setup_state
+				.setup_wallet_list_state
+				.confirm_remove = false;
+		}
+		LocalViewInteraction::ConfirmRemoveWallet(index) => {
+			if grin_gui.wallet_backend.wallet_is_open()
+				&& grin_gui.config.current_wallet_index == Some(index)
+			{
+				grin_gui.error = Some(anyhow::Error::msg("Close the wallet before removing it"));
+				if let Some(e) = grin_gui.error.as_ref() {
+					crate::log_error(e);
+				}
+				return Ok(Command::none());
+			}
+
+			let state = &grin_gui.wallet_state.setup_state.setup_wallet_list_state;
+			let wallet = match grin_gui.config.wallets.get(index) {
+				Some(w) => w,
+				None => return Ok(Command::none()),
+			};
+
+			// Only local wallets have a data directory to archive
+			let tld = match (&wallet.tld, wallet.is_remote()) {
+				(Some(tld), false) if state.archive_wallet_data => tld.clone(),
+				_ => {
+					let fut = move || async {};
+					return Ok(Command::perform(fut(), move |_| {
+						Message::Interaction(Interaction::WalletListWalletViewInteraction(
+							LocalViewInteraction::WalletRemoved(index),
+						))
+					}));
+				}
+			};
+
+			// Archive next to the wallet directory unless a backup directory is configured
+			let dest = match grin_gui.config.backup_directory.clone() {
+				Some(d) => d,
+				None => tld.parent().map(|p| p.to_owned()).unwrap_or_default(),
+			};
+			let compression = grin_gui.config.compression_format;
+			let zstd_level = grin_gui.config.zstd_compression_level;
+
+			let fut = move || {
+				grin_gui_core::backup::archive_wallet_folder(tld, dest, compression, zstd_level)
+			};
+			return Ok(Command::perform(fut(), move |r| {
+				match r.context("Failed to archive wallet data") {
+					Ok(path) => Message::Interaction(Interaction::WalletListWalletViewInteraction(
+						LocalViewInteraction::WalletArchivedOk(index, path),
+					)),
+					Err(e) => Message::Interaction(Interaction::WalletListWalletViewInteraction(
+						LocalViewInteraction::WalletRemoveError(Arc::new(RwLock::new(Some(e)))),
+					)),
+				}
+			}));
+		}
+		LocalViewInteraction::WalletArchivedOk(index, path) => {
+			log::info!("Wallet data archived to {:?}", path);
+			let fut = move || async {};
+			return Ok(Command::perform(fut(), move |_| {
+				Message::Interaction(Interaction::WalletListWalletViewInteraction(
+					LocalViewInteraction::WalletRemoved(index),
+				))
+			}));
+		}
+		LocalViewInteraction::WalletRemoved(index) => {
+			if let Some(wallet) = grin_gui.config.remove_wallet(index) {
+				log::debug!("Removed wallet {}", wallet.display_name);
+				let _ = grin_gui.config.save();
+			}
+			let state = &mut grin_gui.wallet_state.setup_state.setup_wallet_list_state;
+			state.confirm_remove = false;
+			state.selected_wallet_index = 0;
+		}
+		LocalViewInteraction::WalletRemoveError(err) => {
+			grin_gui
+				.wallet_state
+				.setup_state
+				.setup_wallet_list_state
+				.confirm_remove = false;
+
+			grin_gui.error = err.write().unwrap().take();
+			if let Some(e) = grin_gui.error.as_ref() {
+				crate::log_error(e);
+			}
+		}
 		LocalViewInteraction::WalletImportError(err) => {
 			grin_gui.error = err.write().unwrap().take();
 			if let Some(e) = grin_gui.error.as_ref() {
@@ -186,8 +309,23 @@ pub fn data_container<'a>(state: &'a StateContainer, config: &Config) -> Contain
 		))
 		.into();
 
+	let remote_wallet_container =
+		Container::new(Text::new(localized_string("connect-remote")).size(DEFAULT_FONT_SIZE))
+			.align_y(alignment::Vertical::Center)
+			.align_x(alignment::Horizontal::Center);
+
+	let remote_wallet_button: Element<Interaction> = Button::new(remote_wallet_container)
+		.style(grin_gui_core::theme::ButtonStyle::Primary)
+		.on_press(Interaction::WalletListWalletViewInteraction(
+			LocalViewInteraction::ConnectRemoteWallet,
+		))
+		.into();
+
 	// add additional buttons here
-	let button_row = Row::new().push(new_wallet_button.map(Message::Interaction));
+	let button_row = Row::new()
+		.push(new_wallet_button.map(Message::Interaction))
+		.push(Space::with_width(Length::Fixed(1.0)))
+		.push(remote_wallet_button.map(Message::Interaction));
 
 	let segmented_mode_container = Container::new(button_row).padding(1);
 	let segmented_mode_control_container = Container::new(segmented_mode_container)
@@ -269,9 +407,10 @@ pub fn data_container<'a>(state: &'a StateContainer, config: &Config) -> Contain
 			.style(grin_gui_core::theme::ContainerStyle::HoverableForeground)
 			.width(Length::FillPortion(1));
 
-		let tld_string = match &w.tld {
-			Some(path_buf) => path_buf.display().to_string(),
-			None => String::from("Unknown"),
+		let tld_string = match (&w.owner_api_url, &w.tld) {
+			(Some(url), _) => url.clone(),
+			(None, Some(path_buf)) => path_buf.display().to_string(),
+			(None, None) => String::from("Unknown"),
 		};
 		let wallet_directory = Text::new(tld_string).size(DEFAULT_FONT_SIZE);
 
@@ -360,6 +499,24 @@ pub fn data_container<'a>(state: &'a StateContainer, config: &Config) -> Contain
 		))
 		.into();
 
+	let remove_wallet_button_container =
+		Container::new(Text::new(localized_string("remove-wallet")).size(DEFAULT_FONT_SIZE))
+			.width(button_width)
+			.height(button_height)
+			.align_y(alignment::Vertical::Center)
+			.align_x(alignment::Horizontal::Center);
+
+	let mut remove_wallet_button = Button::new(remove_wallet_button_container)
+		.style(grin_gui_core::theme::ButtonStyle::Primary);
+
+	if !config.wallets.is_empty() && !state.confirm_remove {
+		remove_wallet_button = remove_wallet_button.on_press(
+			Interaction::WalletListWalletViewInteraction(LocalViewInteraction::RemoveWallet),
+		)
+	}
+
+	let remove_wallet_button: Element<Interaction> = remove_wallet_button.into();
+
 	// button lipstick
 	let load_container = Container::new(load_wallet_button.map(Message::Interaction)).padding(1);
 	let load_container = Container::new(load_container)
@@ -371,12 +528,106 @@ pub fn data_container<'a>(state: &'a StateContainer, config: &Config) -> Contain
 		.style(grin_gui_core::theme::ContainerStyle::Segmented)
 		.padding(1);
 
+	let remove_container =
+		Container::new(remove_wallet_button.map(Message::Interaction)).padding(1);
+	let remove_container = Container::new(remove_container)
+		.style(grin_gui_core::theme::ContainerStyle::Segmented)
+		.padding(1);
+
 	let button_row = Row::new()
 		.push(load_container)
 		.push(Space::with_width(Length::Fixed(DEFAULT_PADDING)))
 		.push(select_container)
+		.push(Space::with_width(Length::Fixed(DEFAULT_PADDING)))
+		.push(remove_container)
 		.height(Length::Shrink);
 
+	// Confirmation for removing the selected wallet
+	let mut confirm_column = Column::new();
+	if let Some(wallet) = config
+		.wallets
+		.get(state.selected_wallet_index)
+		.filter(|_| state.confirm_remove)
+	{
+		let confirm_text = Text::new(format!(
+			"{} {}?",
+			localized_string("remove-wallet-confirm"),
+			wallet.display_name
+		))
+		.size(DEFAULT_FONT_SIZE);
+
+		let mut confirm_row = Row::new()
+			.push(confirm_text)
+			.push(Space::with_width(Length::Fixed(DEFAULT_PADDING)))
+			.align_items(Alignment::Center);
+
+		// Remote wallets have no local data to archive
+		if !wallet.is_remote() && wallet.tld.is_some() {
+			let archive_checkbox: Element<Interaction> = Checkbox::new(
+				localized_string("remove-wallet-archive-data"),
+				state.archive_wallet_data,
+			)
+			.on_toggle(|b| {
+				Interaction::WalletListWalletViewInteraction(
+					LocalViewInteraction::ArchiveWalletData(b),
+				)
+			})
+			.style(grin_gui_core::theme::CheckboxStyle::Normal)
+			.text_size(DEFAULT_FONT_SIZE)
+			.spacing(10)
+			.into();
+			confirm_row = confirm_row.push(archive_checkbox.map(Message::Interaction));
+		}
+
+		let yes_button_container =
+			Container::new(Text::new(localized_string("yes")).size(DEFAULT_FONT_SIZE))
+				.width(button_width)
+				.height(button_height)
+				.align_y(alignment::Vertical::Center)
+				.align_x(alignment::Horizontal::Center);
+
+		let yes_button: Element<Interaction> = Button::new(yes_button_container)
+			.style(grin_gui_core::theme::ButtonStyle::Primary)
+			.on_press(Interaction::WalletListWalletViewInteraction(
+				LocalViewInteraction::ConfirmRemoveWallet(state.selected_wallet_index),
+			))
+			.into();
+
+		let no_button_container =
+			Container::new(Text::new(localized_string("no")).size(DEFAULT_FONT_SIZE))
+				.width(button_width)
+				.height(button_height)
+				.align_y(alignment::Vertical::Center)
+				.align_x(alignment::Horizontal::Center);
+
+		let no_button: Element<Interaction> = Button::new(no_button_container)
+			.style(grin_gui_core::theme::ButtonStyle::Primary)
+			.on_press(Interaction::WalletListWalletViewInteraction(
+				LocalViewInteraction::CancelRemoveWallet,
+			))
+			.into();
+
+		let yes_container = Container::new(yes_button.map(Message::Interaction)).padding(1);
+		let yes_container = Container::new(yes_container)
+			.style(grin_gui_core::theme::ContainerStyle::Segmented)
+			.padding(1);
+
+		let no_container = Container::new(no_button.map(Message::Interaction)).padding(1);
+		let no_container = Container::new(no_container)
+			.style(grin_gui_core::theme::ContainerStyle::Segmented)
+			.padding(1);
+
+		confirm_row = confirm_row
+			.push(Space::with_width(Length::Fill))
+			.push(yes_container)
+			.push(Space::with_width(Length::Fixed(DEFAULT_PADDING)))
+			.push(no_container);
+
+		confirm_column = confirm_column
+			.push(Space::with_height(Length::Fixed(DEFAULT_PADDING)))
+			.push(confirm_row);
+	}
+
 	let scrollable =
 		Scrollable::new(wallet_column).style(grin_gui_core::theme::ScrollableStyle::Primary);
 
@@ -390,7 +641,8 @@ pub fn data_container<'a>(state: &'a StateContainer, config: &Config) -> Contain
 		Column::new()
 			.push(table_container)
 			.push(Space::with_height(Length::Fixed(DEFAULT_PADDING)))
-			.push(button_row),
+			.push(button_row)
+			.push(confirm_column),
 	);
 
 	let content = Container::new(row)
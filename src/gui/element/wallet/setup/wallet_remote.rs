@@ -0,0 +1,386 @@
+use crate::{gui::element, log_error};
+use native_dialog::FileDialog;
+use std::path::PathBuf;
+
+use {
+	super::super::super::{
+		BUTTON_HEIGHT, BUTTON_WIDTH, DEFAULT_FONT_SIZE, DEFAULT_HEADER_FONT_SIZE, DEFAULT_PADDING,
+	},
+	crate::gui::{GrinGui, Interaction, Message},
+	crate::localization::localized_string,
+	crate::Result,
+	grin_gui_core::theme::{Button, Column, Container, Element, Row, Scrollable, Text, TextInput},
+	grin_gui_core::{
+		config::{Config, Wallet},
+		fs::PersistentData,
+		node::ChainTypes::{Mainnet, Testnet},
+		wallet::DEFAULT_OWNER_API_URL,
+	},
+	iced::widget::{Checkbox, Space},
+	iced::{alignment, Alignment, Command, Length},
+};
+
+pub struct StateContainer {
+	pub display_name_value: String,
+	pub owner_api_url_value: String,
+	pub owner_api_secret_path: Option<PathBuf>,
+	pub foreign_api_url_value: String,
+	pub is_testnet: bool,
+}
+
+impl Default for StateContainer {
+	fn default() -> Self {
+		Self {
+			display_name_value: Default::default(),
+			owner_api_url_value: DEFAULT_OWNER_API_URL.to_owned(),
+			owner_api_secret_path: None,
+			foreign_api_url_value: Default::default(),
+			is_testnet: false,
+		}
+	}
+}
+
+impl StateContainer {
+	pub fn init_wallet_name(&mut self, config: &Config) {
+		let mut wallet_name = "remote".to_string();
+		let mut i = 1;
+		while config.wallets.iter().any(|w| w.display_name == wallet_name) {
+			i += 1;
+			wallet_name = format!("remote{}", i);
+		}
+		self.display_name_value = wallet_name;
+	}
+}
+
+#[derive(Debug, Clone)]
+pub enum LocalViewInteraction {
+	Back,
+	DisplayName(String),
+	OwnerApiUrl(String),
+	ForeignApiUrl(String),
+	LocateOwnerApiSecret,
+	ToggleIsTestnet(bool),
+	AddRemoteWallet,
+}
+
+pub fn handle_message<'a>(
+	grin_gui: &mut GrinGui,
+	message: LocalViewInteraction,
+) -> Result<Command<Message>> {
+	let state = &mut grin_gui.wallet_state.setup_state.remote_wallet_state;
+	match message {
+		LocalViewInteraction::Back => {
+			grin_gui.wallet_state.setup_state.remote_wallet_state = Default::default();
+			grin_gui.wallet_state.mode = element::wallet::Mode::Init;
+			grin_gui.wallet_state.setup_state.mode = super::Mode::ListWallets;
+		}
+		LocalViewInteraction::DisplayName(display_name_value) => {
+			state.display_name_value = display_name_value;
+		}
+		LocalViewInteraction::OwnerApiUrl(value) => {
+			state.owner_api_url_value = value;
+		}
+		LocalViewInteraction::ForeignApiUrl(value) => {
+			state.foreign_api_url_value = value;
+		}
+		LocalViewInteraction::ToggleIsTestnet(_) => {
+			state.is_testnet = !state.is_testnet;
+		}
+		LocalViewInteraction::LocateOwnerApiSecret => {
+			let file_dialogue = FileDialog::new();
+			match file_dialogue.show_open_single_file() {
+				Ok(Some(path)) => state.owner_api_secret_path = Some(path),
+				Ok(None) => {}
+				Err(e) => {
+					log::debug!(
+						"wallet_remote.rs::LocalViewInteraction::LocateOwnerApiSecret {}",
+						e
+					);
+				}
+			}
+		}
+		LocalViewInteraction::AddRemoteWallet => {
+			grin_gui.error.take();
+
+			let display_name = state.display_name_value.trim().to_owned();
+			if grin_gui
+				.config
+				.wallets
+				.iter()
+				.any(|w| w.display_name == display_name)
+			{
+				grin_gui.error = Some(anyhow::Error::msg(format!(
+					"Wallet with name \"{}\" already exists",
+					display_name
+				)));
+			} else if !state.owner_api_url_value.starts_with("http://")
+				&& !state.owner_api_url_value.starts_with("https://")
+			{
+				grin_gui.error = Some(anyhow::Error::msg(format!(
+					"Invalid Owner API URL: {}",
+					state.owner_api_url_value
+				)));
+			}
+			if let Some(e) = grin_gui.error.as_ref() {
+				log_error(e);
+				return Ok(Command::none());
+			}
+
+			let chain_type = if state.is_testnet { Testnet } else { Mainnet };
+			let mut saved_wallet = Wallet::new(None, display_name.clone(), chain_type);
+			saved_wallet.use_embedded_node = false;
+			saved_wallet.owner_api_url = Some(state.owner_api_url_value.trim().to_owned());
+			saved_wallet.owner_api_secret_path = state.owner_api_secret_path.clone();
+			let foreign_api_url = state.foreign_api_url_value.trim();
+			if !foreign_api_url.is_empty() {
+				saved_wallet.foreign_api_url = Some(foreign_api_url.to_owned());
+			}
+
+			let index = grin_gui.config.add_wallet(saved_wallet);
+			grin_gui.config.current_wallet_index = Some(index);
+			grin_gui.wallet_state.clear_config_missing();
+			let _ = grin_gui.config.save();
+
+			debug!("Remote wallet added: {}", display_name);
+
+			// reset user input values and return to the list, where it can be loaded
+			grin_gui.wallet_state.setup_state.remote_wallet_state = Default::default();
+			grin_gui.wallet_state.mode = element::wallet::Mode::Init;
+			grin_gui.wallet_state.setup_state.mode = super::Mode::ListWallets;
+		}
+	}
+
+	Ok(Command::none())
+}
+
+fn labelled_input<'a>(
+	label: String,
+	value: &str,
+	on_input: fn(String) -> LocalViewInteraction,
+) -> Column<'a, Message> {
+	let label = Text::new(label)
+		.size(DEFAULT_FONT_SIZE)
+		.horizontal_alignment(alignment::Horizontal::Left);
+	let label_container =
+		Container::new(label).style(grin_gui_core::theme::ContainerStyle::NormalBackground);
+
+	let input = TextInput::new("", value)
+		.on_input(move |s| Interaction::WalletSetupRemoteWalletViewInteraction(on_input(s)))
+		.size(DEFAULT_FONT_SIZE)
+		.padding(6)
+		.width(Length::Fixed(400.0))
+		.style(grin_gui_core::theme::TextInputStyle::AddonsQuery);
+	let input: Element<Interaction> = input.into();
+
+	Column::new()
+		.push(label_container)
+		.push(Space::new(Length::Fixed(0.0), Length::Fixed(5.0)))
+		.push(input.map(Message::Interaction))
+		.align_items(Alignment::Start)
+}
+
+pub fn data_container<'a>(config: &'a Config, state: &'a StateContainer) -> Container<'a, Message> {
+	let title = Text::new(localized_string("connect-remote-wallet-title"))
+		.size(DEFAULT_HEADER_FONT_SIZE)
+		.horizontal_alignment(alignment::Horizontal::Center);
+
+	// same padding as wallet_import so the title doesn't shift between views
+	let title_container = Container::new(title)
+		.style(grin_gui_core::theme::ContainerStyle::BrightBackground)
+		.padding(iced::Padding::from([
+			2, // top
+			0, // right
+			2, // bottom
+			5, // left
+		]));
+
+	let header_row = Row::new().push(title_container);
+
+	let header_container = Container::new(header_row).padding(iced::Padding::from([
+		0,                      // top
+		0,                      // right
+		DEFAULT_PADDING as u16, // bottom
+		0,                      // left
+	]));
+
+	let description = Text::new(localized_string("connect-remote-wallet-description"))
+		.size(DEFAULT_FONT_SIZE)
+		.horizontal_alignment(alignment::Horizontal::Center);
+	let description_container =
+		Container::new(description).style(grin_gui_core::theme::ContainerStyle::NormalBackground);
+
+	let display_name_col = labelled_input(
+		localized_string("display-name"),
+		&state.display_name_value,
+		LocalViewInteraction::DisplayName,
+	);
+
+	let owner_api_url_col = labelled_input(
+		localized_string("owner-api-url"),
+		&state.owner_api_url_value,
+		LocalViewInteraction::OwnerApiUrl,
+	);
+
+	let foreign_api_url_col = labelled_input(
+		localized_string("foreign-api-url"),
+		&state.foreign_api_url_value,
+		LocalViewInteraction::ForeignApiUrl,
+	);
+
+	let owner_api_secret_col = {
+		let label = Text::new(localized_string("owner-api-secret"))
+			.size(DEFAULT_FONT_SIZE)
+			.horizontal_alignment(alignment::Horizontal::Left);
+		let label_container =
+			Container::new(label).style(grin_gui_core::theme::ContainerStyle::NormalBackground);
+
+		let path_string = match &state.owner_api_secret_path {
+			Some(p) => p.display().to_string(),
+			None => localized_string("owner-api-secret-none"),
+		};
+		let path_text = Text::new(path_string).size(DEFAULT_FONT_SIZE);
+
+		let locate_button_container =
+			Container::new(Text::new(localized_string("locate-file")).size(DEFAULT_FONT_SIZE))
+				.align_y(alignment::Vertical::Center)
+				.align_x(alignment::Horizontal::Center);
+		let locate_button: Element<Interaction> = Button::new(locate_button_container)
+			.style(grin_gui_core::theme::ButtonStyle::Primary)
+			.on_press(Interaction::WalletSetupRemoteWalletViewInteraction(
+				LocalViewInteraction::LocateOwnerApiSecret,
+			))
+			.into();
+		let locate_container = Container::new(locate_button.map(Message::Interaction)).padding(1);
+		let locate_container = Container::new(locate_container)
+			.style(grin_gui_core::theme::ContainerStyle::Segmented)
+			.padding(1);
+
+		let row = Row::new()
+			.push(locate_container)
+			.push(Space::new(Length::Fixed(DEFAULT_PADDING), Length::Fixed(0.0)))
+			.push(path_text)
+			.align_items(Alignment::Center);
+
+		Column::new()
+			.push(label_container)
+			.push(Space::new(Length::Fixed(0.0), Length::Fixed(5.0)))
+			.push(row)
+			.align_items(Alignment::Start)
+	};
+
+	let is_testnet_checkbox = Checkbox::new(localized_string("use-testnet"), state.is_testnet)
+		.on_toggle(|b| {
+			Interaction::WalletSetupRemoteWalletViewInteraction(
+				LocalViewInteraction::ToggleIsTestnet(b),
+			)
+		})
+		.style(grin_gui_core::theme::CheckboxStyle::Normal)
+		.text_size(DEFAULT_FONT_SIZE)
+		.spacing(10);
+	let is_testnet_checkbox: Element<Interaction> = is_testnet_checkbox.into();
+
+	let button_height = Length::Fixed(BUTTON_HEIGHT);
+	let button_width = Length::Fixed(BUTTON_WIDTH);
+
+	let submit_button_label_container =
+		Container::new(Text::new(localized_string("connect-remote")).size(DEFAULT_FONT_SIZE))
+			.width(button_width)
+			.height(button_height)
+			.center_x()
+			.center_y()
+			.align_x(alignment::Horizontal::Center);
+
+	let mut submit_button = Button::new(submit_button_label_container)
+		.style(grin_gui_core::theme::ButtonStyle::Primary);
+
+	if !state.display_name_value.is_empty() && !state.owner_api_url_value.is_empty() {
+		submit_button = submit_button.on_press(Interaction::WalletSetupRemoteWalletViewInteraction(
+			LocalViewInteraction::AddRemoteWallet,
+		));
+	}
+
+	let submit_button: Element<Interaction> = submit_button.into();
+
+	let cancel_button_label_container =
+		Container::new(Text::new(localized_string("cancel")).size(DEFAULT_FONT_SIZE))
+			.width(button_width)
+			.height(button_height)
+			.center_x()
+			.center_y()
+			.align_x(alignment::Horizontal::Center);
+
+	let cancel_button: Element<Interaction> = Button::new(cancel_button_label_container)
+		.style(grin_gui_core::theme::ButtonStyle::Primary)
+		.on_press(Interaction::WalletSetupRemoteWalletViewInteraction(
+			LocalViewInteraction::Back,
+		))
+		.into();
+
+	let submit_container = Container::new(submit_button.map(Message::Interaction)).padding(1);
+	let submit_container = Container::new(submit_container)
+		.style(grin_gui_core::theme::ContainerStyle::Segmented)
+		.padding(1);
+
+	let cancel_container = Container::new(cancel_button.map(Message::Interaction)).padding(1);
+	let cancel_container = Container::new(cancel_container)
+		.style(grin_gui_core::theme::ContainerStyle::Segmented)
+		.padding(1);
+
+	let unit_spacing = 15.0;
+	let button_row = Row::new()
+		.push(submit_container)
+		.push(Space::new(Length::Fixed(unit_spacing), Length::Fixed(0.0)))
+		.push(cancel_container);
+
+	let column = Column::new()
+		.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)))
+		.push(description_container)
+		.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)))
+		.push(display_name_col)
+		.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)))
+		.push(owner_api_url_col)
+		.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)))
+		.push(owner_api_secret_col)
+		.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)))
+		.push(foreign_api_url_col)
+		.push(Space::new(Length::Fixed(0.0), Length::Fixed(unit_spacing)))
+		.push(is_testnet_checkbox.map(Message::Interaction))
+		.push(Space::new(
+			Length::Fixed(0.0),
+			Length::Fixed(unit_spacing + 10.0),
+		))
+		.push(button_row)
+		.align_items(Alignment::Start);
+
+	let form_container = Container::new(column)
+		.width(Length::Fill)
+		.padding(iced::Padding::from([
+			0, // top
+			0, // right
+			0, // bottom
+			5, // left
+		]));
+
+	// form container should be scrollable in tiny windows
+	let scrollable = Scrollable::new(form_container)
+		.height(Length::Fill)
+		.style(grin_gui_core::theme::ScrollableStyle::Primary);
+
+	let content = Container::new(scrollable)
+		.width(Length::Fill)
+		.height(Length::Shrink)
+		.style(grin_gui_core::theme::ContainerStyle::NormalBackground);
+
+	let wrapper_column = Column::new()
+		.height(Length::Fill)
+		.push(header_container)
+		.push(content);
+
+	// Returns the final container.
+	Container::new(wrapper_column).padding(iced::Padding::from([
+		DEFAULT_PADDING, // top
+		DEFAULT_PADDING, // right
+		DEFAULT_PADDING, // bottom
+		DEFAULT_PADDING, // left
+	]))
+}
@@ -9,8 +9,9 @@ use crate::gui::element::{DEFAULT_FONT_SIZE, SMALLER_FONT_SIZE};
 use crate::localization::{localized_string, LANG};
 use grin_gui_core::theme::Element;
 use grin_gui_core::{
-	config::Config,
+	config::{Config, Contacts, HandledSlatepacks, TxNotes},
 	fs::PersistentData,
+	price::{CoinGeckoProvider, PriceCache, PriceProvider},
 	node::{
 		subscriber::{self, UIMessage},
 		ChainTypes, NodeInterface,
@@ -18,7 +19,10 @@ use grin_gui_core::{
 	theme::{
 		Button, ColorPalette, Column, Container, Modal, PickList, Row, Scrollable, Text, Theme,
 	},
-	wallet::{get_grin_wallet_default_path, global, HTTPNodeClient, WalletInterfaceHttpNodeClient},
+	wallet::{
+		get_grin_wallet_default_path, global, HTTPNodeClient, WalletBackend,
+		WalletInterfaceHttpNodeClient,
+	},
 };
 
 use iced::widget::{button, pick_list, scrollable, text_input, Checkbox, Space, TextInput};
@@ -42,6 +46,12 @@ pub struct GrinGui {
 	/// Wallet Interface
 	wallet_interface: Arc<RwLock<WalletInterfaceHttpNodeClient>>,
 
+	/// Wallet operations, backed by `wallet_interface` outside of tests
+	wallet_backend: Arc<dyn WalletBackend>,
+
+	/// Where prices of grin come from
+	price_provider: Arc<dyn PriceProvider>,
+
 	/// Node Interface
 	node_interface: Arc<RwLock<NodeInterface>>,
 
@@ -100,10 +110,14 @@ impl GrinGui {
 		let name = config.theme.clone().unwrap_or("Alliance".to_string());
 		let theme = Theme::all().iter().find(|t| t.0 == name).unwrap().1.clone();
 
+		let wallet_interface = Arc::new(RwLock::new(WalletInterfaceHttpNodeClient::new(
+			node_client,
+		)));
+
 		Self {
-			wallet_interface: Arc::new(RwLock::new(WalletInterfaceHttpNodeClient::new(
-				node_client,
-			))),
+			wallet_backend: Arc::new(wallet_interface.clone()),
+			price_provider: Arc::new(CoinGeckoProvider::new(config.price_endpoint.clone())),
+			wallet_interface,
 			node_interface: Arc::new(RwLock::new(NodeInterface::new())),
 			error: None,
 			mode: Mode::Catalog,
@@ -153,6 +167,28 @@ impl Application for GrinGui {
 
 	fn new(config: Config) -> (Self, Command<Message>) {
 		let mut grin_gui = GrinGui::from_config(&config);
+		grin_gui
+			.wallet_state
+			.operation_state
+			.contacts_state
+			.contacts = Contacts::load_or_default().unwrap_or_default();
+		grin_gui
+			.wallet_state
+			.operation_state
+			.home_state
+			.tx_list_display_state
+			.tx_notes = TxNotes::load_or_default().unwrap_or_default();
+		grin_gui
+			.wallet_state
+			.operation_state
+			.home_state
+			.inbox_state
+			.handled = HandledSlatepacks::load_or_default().unwrap_or_default();
+		grin_gui
+			.wallet_state
+			.operation_state
+			.home_state
+			.price_cache = PriceCache::load_or_default().unwrap_or_default();
 
 		// default Mainnet
 		global::set_local_chain_type(ChainTypes::Mainnet);
@@ -439,6 +475,9 @@ pub enum Interaction {
 	WalletSetupImportWalletViewInteraction(
 		element::wallet::setup::wallet_import::LocalViewInteraction,
 	),
+	WalletSetupRemoteWalletViewInteraction(
+		element::wallet::setup::wallet_remote::LocalViewInteraction,
+	),
 	WalletListWalletViewInteraction(element::wallet::setup::wallet_list::LocalViewInteraction),
 	WalletSetupWalletSuccessViewInteraction(
 		element::wallet::setup::wallet_success::LocalViewInteraction,
@@ -455,6 +494,8 @@ pub enum Interaction {
 	WalletOperationHomeActionMenuViewInteraction(
 		element::wallet::operation::action_menu::LocalViewInteraction,
 	),
+	WalletOperationHomeExpiryInteraction(element::wallet::operation::expiry::LocalViewInteraction),
+	WalletOperationHomeInboxInteraction(element::wallet::operation::inbox::LocalViewInteraction),
 	WalletOperationCreateTxViewInteraction(
 		element::wallet::operation::create_tx::LocalViewInteraction,
 	),
@@ -474,6 +515,34 @@ pub enum Interaction {
 		element::wallet::operation::tx_proof::LocalViewInteraction,
 	),
 	WalletOperationTxDoneViewInteraction(element::wallet::operation::tx_done::LocalViewInteraction),
+	WalletOperationOutputsViewInteraction(
+		element::wallet::operation::outputs::LocalViewInteraction,
+	),
+	WalletOperationScanViewInteraction(element::wallet::operation::scan::LocalViewInteraction),
+	WalletOperationRecoveryPhraseViewInteraction(
+		element::wallet::operation::recovery_phrase::LocalViewInteraction,
+	),
+	WalletOperationChangePasswordViewInteraction(
+		element::wallet::operation::change_password::LocalViewInteraction,
+	),
+	WalletOperationIssueInvoiceViewInteraction(
+		element::wallet::operation::issue_invoice::LocalViewInteraction,
+	),
+	WalletOperationVerifyProofViewInteraction(
+		element::wallet::operation::verify_proof::LocalViewInteraction,
+	),
+	WalletOperationContactsViewInteraction(
+		element::wallet::operation::contacts::LocalViewInteraction,
+	),
+	WalletOperationListenerViewInteraction(
+		element::wallet::operation::listener::LocalViewInteraction,
+	),
+	WalletOperationTxExportViewInteraction(
+		element::wallet::operation::tx_export::LocalViewInteraction,
+	),
+	WalletOperationAccountingViewInteraction(
+		element::wallet::operation::accounting::LocalViewInteraction,
+	),
 	WalletOperationCreateTxContractsViewInteraction(
 		element::wallet::operation::create_tx_contracts::LocalViewInteraction,
 	),
@@ -24,7 +24,9 @@ use std::sync::atomic::Ordering;
 pub fn handle_message(grin_gui: &mut GrinGui, message: Message) -> Result<Command<Message>> {
 	if let Some(index) = grin_gui.config.current_wallet_index {
 		// Take opportunity to check if we don't have a wallet config file for some reason
-		if !grin_gui.wallet_state.config_missing() {
+		// Remote wallets have no local config, the owner API is the only thing that matters
+		let is_remote = grin_gui.config.wallets[index].is_remote();
+		if !grin_gui.wallet_state.config_missing() && !is_remote {
 			match &grin_gui.config.wallets[index].tld {
 				Some(t) => {
 					let wallet_interface = grin_gui.wallet_interface.clone();
@@ -45,16 +47,12 @@ pub fn handle_message(grin_gui: &mut GrinGui, message: Message) -> Result<Comman
 		if !grin_gui.wallet_state.config_missing()
 			&& !grin_gui.wallet_state.operation_state.wallet_not_open()
 		{
-			let wallet_interface = grin_gui.wallet_interface.clone();
-			let r = wallet_interface.read();
-			if let Ok(w) = r {
-				if !w.wallet_is_open() {
-					grin_gui.wallet_state.operation_state.set_wallet_not_open()
-				}
+			if !grin_gui.wallet_backend.wallet_is_open() {
+				grin_gui.wallet_state.operation_state.set_wallet_not_open()
 			}
 		}
 		// Check if embedded node needs starting
-		if grin_gui.config.wallets[index].use_embedded_node {
+		if grin_gui.config.wallets[index].use_embedded_node && !is_remote {
 			let (node_started, has_ui_sender) = {
 				let n = grin_gui.node_interface.read().unwrap();
 				(n.node_started, n.ui_sender.is_some())
@@ -115,7 +113,14 @@ pub fn handle_message(grin_gui: &mut GrinGui, message: Message) -> Result<Comman
 		// Ticks, for stuff that happens frequently, like checking wallet status
 		Message::Tick(time) => {
 			// Call all views 'registered' for ticks
-			return element::wallet::operation::home::handle_tick(grin_gui, time);
+			element::wallet::operation::recovery_phrase::handle_tick(grin_gui, time);
+			let inbox = element::wallet::operation::inbox::handle_tick(grin_gui, time);
+			element::wallet::operation::show_slatepack::handle_tick(grin_gui, time);
+			let expiry = element::wallet::operation::expiry::handle_tick(grin_gui, time);
+			let listener = element::wallet::operation::listener::handle_tick(grin_gui, time);
+			let prices = element::wallet::operation::home::handle_price_tick(grin_gui);
+			let home = element::wallet::operation::home::handle_tick(grin_gui, time)?;
+			return Ok(Command::batch(vec![inbox, expiry, listener, prices, home]));
 		}
 		// Update from embedded node server
 		Message::SendNodeMessage((_id, msg, sender)) => match sender {
@@ -190,6 +195,10 @@ pub fn handle_message(grin_gui: &mut GrinGui, message: Message) -> Result<Comman
 		Message::Interaction(Interaction::WalletSetupImportWalletViewInteraction(l)) => {
 			return element::wallet::setup::wallet_import::handle_message(grin_gui, l);
 		}
+		// Setup -> Remote Wallet
+		Message::Interaction(Interaction::WalletSetupRemoteWalletViewInteraction(l)) => {
+			return element::wallet::setup::wallet_remote::handle_message(grin_gui, l);
+		}
 		// Setup -> Wallet List
 		Message::Interaction(Interaction::WalletListWalletViewInteraction(l)) => {
 			return element::wallet::setup::wallet_list::handle_message(grin_gui, l);
@@ -214,6 +223,14 @@ pub fn handle_message(grin_gui: &mut GrinGui, message: Message) -> Result<Comman
 		Message::Interaction(Interaction::WalletOperationHomeTxListDisplayInteraction(l)) => {
 			return element::wallet::operation::tx_list_display::handle_message(grin_gui, l);
 		}
+		// Wallet -> Operation -> Home -> Expiry
+		Message::Interaction(Interaction::WalletOperationHomeExpiryInteraction(l)) => {
+			return element::wallet::operation::expiry::handle_message(grin_gui, l);
+		}
+		// Wallet -> Operation -> Home -> Inbox
+		Message::Interaction(Interaction::WalletOperationHomeInboxInteraction(l)) => {
+			return element::wallet::operation::inbox::handle_message(grin_gui, l);
+		}
 		// Wallet -> Operation -> TxList
 		Message::Interaction(Interaction::WalletOperationTxListInteraction(l)) => {
 			return element::wallet::operation::tx_list::handle_message(grin_gui, l);
@@ -250,6 +267,46 @@ pub fn handle_message(grin_gui: &mut GrinGui, message: Message) -> Result<Comman
 		Message::Interaction(Interaction::WalletOperationTxDoneViewInteraction(l)) => {
 			return element::wallet::operation::tx_done::handle_message(grin_gui, l);
 		}
+		// Wallet -> Operation -> Outputs
+		Message::Interaction(Interaction::WalletOperationOutputsViewInteraction(l)) => {
+			return element::wallet::operation::outputs::handle_message(grin_gui, l);
+		}
+		// Wallet -> Operation -> ChangePassword
+		Message::Interaction(Interaction::WalletOperationChangePasswordViewInteraction(l)) => {
+			return element::wallet::operation::change_password::handle_message(grin_gui, l);
+		}
+		// Wallet -> Operation -> IssueInvoice
+		Message::Interaction(Interaction::WalletOperationIssueInvoiceViewInteraction(l)) => {
+			return element::wallet::operation::issue_invoice::handle_message(grin_gui, l);
+		}
+		// Wallet -> Operation -> VerifyProof
+		Message::Interaction(Interaction::WalletOperationVerifyProofViewInteraction(l)) => {
+			return element::wallet::operation::verify_proof::handle_message(grin_gui, l);
+		}
+		// Wallet -> Operation -> Contacts
+		Message::Interaction(Interaction::WalletOperationContactsViewInteraction(l)) => {
+			return element::wallet::operation::contacts::handle_message(grin_gui, l);
+		}
+		// Wallet -> Operation -> Listener
+		Message::Interaction(Interaction::WalletOperationListenerViewInteraction(l)) => {
+			return element::wallet::operation::listener::handle_message(grin_gui, l);
+		}
+		// Wallet -> Operation -> TxExport
+		Message::Interaction(Interaction::WalletOperationTxExportViewInteraction(l)) => {
+			return element::wallet::operation::tx_export::handle_message(grin_gui, l);
+		}
+		// Wallet -> Operation -> Accounting
+		Message::Interaction(Interaction::WalletOperationAccountingViewInteraction(l)) => {
+			return element::wallet::operation::accounting::handle_message(grin_gui, l);
+		}
+		// Wallet -> Operation -> RecoveryPhrase
+		Message::Interaction(Interaction::WalletOperationRecoveryPhraseViewInteraction(l)) => {
+			return element::wallet::operation::recovery_phrase::handle_message(grin_gui, l);
+		}
+		// Wallet -> Operation -> Scan
+		Message::Interaction(Interaction::WalletOperationScanViewInteraction(l)) => {
+			return element::wallet::operation::scan::handle_message(grin_gui, l);
+		}
 		// Wallet -> Operation -> CreateTxContract
 		Message::Interaction(Interaction::WalletOperationCreateTxContractsViewInteraction(l)) => {
 			return element::wallet::operation::create_tx_contracts::handle_message(grin_gui, l);
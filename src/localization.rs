@@ -1,3 +1,4 @@
+use grin_gui_core::config::{Language, NumberFormat};
 use json_gettext::{get_text, static_json_gettext_build, JSONGetText};
 use once_cell::sync::{Lazy, OnceCell};
 
@@ -45,3 +46,13 @@ pub fn localized_timeago_formatter() -> timeago::Formatter<Box<dyn timeago::Lang
 		timeago::Formatter::with_language(Box::new(timeago::English))
 	}
 }
+
+/// Returns how numbers and amounts of money are written in the chosen language.
+pub fn localized_number_format() -> NumberFormat {
+	let lang = LANG.get().expect("LANG not set").read().unwrap();
+	Language::ALL
+		.iter()
+		.find(|l| l.language_code() == *lang)
+		.map(|l| l.number_format())
+		.unwrap_or_default()
+}